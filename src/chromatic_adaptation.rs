@@ -0,0 +1,188 @@
+//! Chromatic adaptation between reference whites.
+//!
+//! A von Kries-style transform takes XYZ into a cone-like response space, scales each
+//! response by the ratio of the destination white's to the source white's, and returns to
+//! XYZ. The response space is chosen with `AdaptationMethod`: Bradford is what ICC
+//! profiles use for D50 and D65 work, CAT02 and CAT16 are the transforms inside CIECAM02
+//! and CAM16, and Von Kries in Hunt-Pointer-Estevez cone fundamentals is the classic
+//! form, kept for comparison with older work. Whites are XYZ; they are normalized to
+//! Y = 1, so only their chromaticity matters.
+//!
+//! The degree of adaptation D blends each gain with 1, as in CIECAM02: D = 1 adapts
+//! fully to the destination white and D = 0 leaves XYZ unchanged.
+//!
+//! `Color` is D65 relative, so `ChromaticAdaptation::adapt` on a `Color` treats its XYZ as
+//! seen under the source white, gives the corresponding XYZ under the destination white,
+//! and reads that back as a D65 `Color`. `corresponding_color` works on `Xyz` instead,
+//! taking the source white from the value's own reference white.
+
+use crate::colorspaces::cam16::{M16, M16_INV};
+use crate::colorspaces::ciecam02::{M_CAT02, M_CAT02_INV, M_HPE, M_HPE_INV};
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::xyz::Xyz;
+use crate::math::{Mat3, Vec3};
+use crate::pipeline::Transform;
+use crate::white_point::{Observer, WhitePoint};
+use serde::{Deserialize, Serialize};
+
+pub(crate) const M_BRADFORD: Mat3 = Mat3([
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+]);
+
+pub(crate) const M_BRADFORD_INV: Mat3 = Mat3([
+    [0.9869929054667121, -0.1470542564209901, 0.15996265166373125],
+    [0.4323052697233945, 0.5183602715367776, 0.049291228212855615],
+    [-0.008528664575177331, 0.04004282165408486, 0.96848669578755],
+]);
+
+/// Cone-like response space a chromatic adaptation scales in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AdaptationMethod {
+    /// Bradford (Lam 1985), as used by ICC profiles
+    #[default]
+    Bradford,
+    /// CAT02, from CIECAM02
+    Cat02,
+    /// CAT16, from CAM16
+    Cat16,
+    /// Von Kries scaling of Hunt-Pointer-Estevez cone fundamentals
+    VonKries,
+}
+
+impl AdaptationMethod {
+    /// The XYZ to response matrix and its inverse.
+    fn matrices(&self) -> (Mat3, Mat3) {
+        match self {
+            AdaptationMethod::Bradford => (M_BRADFORD, M_BRADFORD_INV),
+            AdaptationMethod::Cat02 => (M_CAT02, M_CAT02_INV),
+            AdaptationMethod::Cat16 => (M16, M16_INV),
+            // Normalized to illuminant E; the normalization cancels in the adaptation
+            AdaptationMethod::VonKries => (M_HPE, M_HPE_INV),
+        }
+    }
+
+    /// Row-major matrix from XYZ to this method's response space.
+    pub fn response_matrix(&self) -> [[f64; 3]; 3] {
+        self.matrices().0.0
+    }
+}
+
+/// Adaptation from the white `src` to the white `dst` with degree `degree`, as an XYZ
+/// matrix.
+fn adaptation(method: AdaptationMethod, src: Vec3, dst: Vec3, degree: f64) -> Mat3 {
+    let (m, m_inv) = method.matrices();
+    let s = m.apply(src.map(|v| v / src[1]));
+    let d = m.apply(dst.map(|v| v / dst[1]));
+    let gains = [0, 1, 2].map(|k| degree * d[k] / s[k] + 1.0 - degree);
+    m_inv.mul(&Mat3::diagonal(gains).mul(&m))
+}
+
+/// Bradford adaptation from the white `src` to the white `dst`, as an XYZ matrix.
+pub(crate) fn bradford(src: Vec3, dst: Vec3) -> Mat3 {
+    adaptation(AdaptationMethod::Bradford, src, dst, 1.0)
+}
+
+/// Row-major XYZ matrix of the Bradford adaptation from `src_white` to `dst_white`.
+pub fn bradford_matrix(src_white: [f64; 3], dst_white: [f64; 3]) -> [[f64; 3]; 3] {
+    bradford(src_white, dst_white).0
+}
+
+/// Row-major XYZ matrix adapting from `src_white` to `dst_white` with `method` and
+/// degree of adaptation `degree` (clamped to [0, 1]).
+pub fn adaptation_matrix(
+    method: AdaptationMethod,
+    src_white: [f64; 3],
+    dst_white: [f64; 3],
+    degree: f64,
+) -> [[f64; 3]; 3] {
+    adaptation(method, src_white, dst_white, degree.clamp(0.0, 1.0)).0
+}
+
+/// A chromatic adaptation from one white to another, as a linear map on XYZ.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChromaticAdaptation {
+    matrix: Mat3,
+}
+
+impl ChromaticAdaptation {
+    /// Full adaptation from `src_white` to `dst_white` with `method`.
+    pub fn new(method: AdaptationMethod, src_white: [f64; 3], dst_white: [f64; 3]) -> Self {
+        Self::with_degree(method, src_white, dst_white, 1.0)
+    }
+
+    /// Adaptation from `src_white` to `dst_white` with `method` and degree of adaptation
+    /// `degree` (clamped to [0, 1]), such as `ViewingConditions::degree_of_adaptation`.
+    pub fn with_degree(
+        method: AdaptationMethod,
+        src_white: [f64; 3],
+        dst_white: [f64; 3],
+        degree: f64,
+    ) -> Self {
+        Self {
+            matrix: adaptation(method, src_white, dst_white, degree.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Bradford adaptation from `src_white` to `dst_white`.
+    pub fn bradford(src_white: [f64; 3], dst_white: [f64; 3]) -> Self {
+        Self::new(AdaptationMethod::Bradford, src_white, dst_white)
+    }
+
+    /// Row-major XYZ matrix of the adaptation.
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        self.matrix.0
+    }
+
+    /// The adaptation in the opposite direction.
+    pub fn inverse(&self) -> Self {
+        Self {
+            matrix: self.matrix.inverse(),
+        }
+    }
+
+    /// XYZ under the destination white corresponding to `xyz` under the source white.
+    pub fn adapt_xyz(&self, xyz: &Xyz) -> Xyz {
+        let [x, y, z] = self.matrix.apply([xyz.x, xyz.y, xyz.z]);
+        Xyz::with_alpha(x, y, z, xyz.alpha)
+    }
+
+    /// Apply the adaptation to a color's XYZ; alpha is kept.
+    pub fn adapt(&self, color: &Color) -> Color {
+        self.adapt_xyz(&Xyz::from_color(color)).to_color()
+    }
+}
+
+impl Transform for ChromaticAdaptation {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let c = self.adapt(&Color::opaque(rgb[0], rgb[1], rgb[2]));
+        [c.r, c.g, c.b]
+    }
+}
+
+/// Corresponding color: the XYZ that, seen under `dst`, matches the appearance of `xyz`
+/// seen under its own reference white, predicted with `method` and degree of adaptation
+/// `degree` (clamped to [0, 1]).
+///
+/// The result is relative to `dst`. With `dst` D65, `to_color` on it is the color a
+/// display should show; for other whites `to_color` adapts it on to D65 with Bradford, as
+/// for any `Xyz`. Whites are taken for the 2° observer.
+pub fn corresponding_color(
+    xyz: &Xyz,
+    dst: WhitePoint,
+    method: AdaptationMethod,
+    degree: f64,
+) -> Xyz {
+    let adaptation = ChromaticAdaptation::with_degree(
+        method,
+        xyz.white.xyz(Observer::Cie1931),
+        dst.xyz(Observer::Cie1931),
+        degree,
+    );
+    Xyz {
+        white: dst,
+        ..adaptation.adapt_xyz(xyz)
+    }
+}
@@ -0,0 +1,431 @@
+//! Reading and applying Academy/ASC Common LUT Format (CLF) files.
+//!
+//! Supported process nodes are `Matrix`, `LUT1D`, `LUT3D` and `Range`. Bit depths are
+//! resolved while parsing, so every node operates on normalized values (1.0 = full
+//! scale of the file's bit depth) and a `ProcessList` can be dropped straight into a
+//! `Pipeline`. Other node types, and half-float indexed 1D LUTs, are rejected with
+//! `ClfError::Unsupported` rather than silently skipped.
+
+use crate::pipeline::Transform;
+use std::fmt;
+use std::path::Path;
+
+/// Errors raised while reading a CLF document.
+#[derive(Debug)]
+pub enum ClfError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The document is not well-formed XML
+    Xml(String),
+    /// The document is XML but not a valid `ProcessList`
+    Invalid(String),
+    /// A node or attribute this reader does not implement
+    Unsupported(String),
+}
+
+impl fmt::Display for ClfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClfError::Io(e) => write!(f, "failed to read CLF file: {e}"),
+            ClfError::Xml(e) => write!(f, "malformed CLF XML: {e}"),
+            ClfError::Invalid(e) => write!(f, "invalid CLF document: {e}"),
+            ClfError::Unsupported(e) => write!(f, "unsupported CLF feature: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ClfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClfError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ClfError {
+    fn from(e: std::io::Error) -> Self {
+        ClfError::Io(e)
+    }
+}
+
+/// Interpolation used between the lattice points of a 3D LUT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lut3dInterpolation {
+    #[default]
+    Trilinear,
+    Tetrahedral,
+}
+
+/// A single process node with values normalized to the 0-1 range of its bit depths.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClfNode {
+    /// `out = matrix * in + offset`
+    Matrix {
+        matrix: [[f64; 3]; 3],
+        offset: [f64; 3],
+    },
+    /// Per-channel 1D LUT with linear interpolation over [0, 1]
+    Lut1d { table: Vec<[f64; 3]> },
+    /// 3D LUT of `size`³ entries, blue index varying fastest
+    Lut3d {
+        size: usize,
+        table: Vec<[f64; 3]>,
+        interpolation: Lut3dInterpolation,
+    },
+    /// Linear rescale of an input range to an output range, optionally clamped. The
+    /// bounds are finite, and the output range may be inverted
+    Range {
+        min_in: Option<f64>,
+        max_in: Option<f64>,
+        min_out: Option<f64>,
+        max_out: Option<f64>,
+        clamp: bool,
+    },
+}
+
+/// A parsed CLF `ProcessList`: its nodes applied in document order.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProcessList {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub nodes: Vec<ClfNode>,
+}
+
+/// Full-scale code value for a CLF bit depth string.
+fn bit_depth_scale(depth: &str) -> Result<f64, ClfError> {
+    match depth {
+        "8i" => Ok(255.0),
+        "10i" => Ok(1023.0),
+        "12i" => Ok(4095.0),
+        "16i" => Ok(65535.0),
+        "16f" | "32f" => Ok(1.0),
+        other => Err(ClfError::Invalid(format!("unknown bit depth `{other}`"))),
+    }
+}
+
+fn depth_scales(node: roxmltree::Node) -> Result<(f64, f64), ClfError> {
+    let depth = |name: &str| {
+        node.attribute(name)
+            .ok_or_else(|| {
+                ClfError::Invalid(format!("`{}` is missing {name}", node.tag_name().name()))
+            })
+            .and_then(bit_depth_scale)
+    };
+    Ok((depth("inBitDepth")?, depth("outBitDepth")?))
+}
+
+fn child<'a, 'i>(node: roxmltree::Node<'a, 'i>, name: &str) -> Option<roxmltree::Node<'a, 'i>> {
+    node.children()
+        .find(|c| c.is_element() && c.tag_name().name() == name)
+}
+
+/// Read a node's `Array` child as (dimensions, values).
+fn array(node: roxmltree::Node) -> Result<(Vec<usize>, Vec<f64>), ClfError> {
+    let element = child(node, "Array")
+        .ok_or_else(|| ClfError::Invalid(format!("`{}` has no Array", node.tag_name().name())))?;
+    let dim = element
+        .attribute("dim")
+        .ok_or_else(|| ClfError::Invalid("Array is missing dim".into()))?
+        .split_whitespace()
+        .map(|d| d.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| ClfError::Invalid("Array dim is not a list of integers".into()))?;
+    let values = element
+        .text()
+        .unwrap_or("")
+        .split_whitespace()
+        .map(|v| v.parse::<f64>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| ClfError::Invalid("Array contains a non-numeric value".into()))?;
+    let expected: usize = dim.iter().product();
+    if dim.is_empty() || values.len() != expected {
+        return Err(ClfError::Invalid(format!(
+            "Array dim {dim:?} expects {expected} values, found {}",
+            values.len()
+        )));
+    }
+    Ok((dim, values))
+}
+
+fn parse_matrix(node: roxmltree::Node) -> Result<ClfNode, ClfError> {
+    let (in_scale, out_scale) = depth_scales(node)?;
+    let (dim, values) = array(node)?;
+    // CLF v3 uses "3 3" / "3 4"; v2 files write a trailing component count ("3 3 3")
+    let cols = match dim.as_slice() {
+        [3, c] | [3, c, 3] if *c == 3 || *c == 4 => *c,
+        _ => return Err(ClfError::Unsupported(format!("Matrix with dim {dim:?}"))),
+    };
+    let mut matrix = [[0.0; 3]; 3];
+    let mut offset = [0.0; 3];
+    for row in 0..3 {
+        for col in 0..3 {
+            matrix[row][col] = values[row * cols + col] * in_scale / out_scale;
+        }
+        if cols == 4 {
+            offset[row] = values[row * cols + 3] / out_scale;
+        }
+    }
+    Ok(ClfNode::Matrix { matrix, offset })
+}
+
+fn parse_lut1d(node: roxmltree::Node) -> Result<ClfNode, ClfError> {
+    if node.attribute("halfDomain").is_some() || node.attribute("rawHalfs").is_some() {
+        return Err(ClfError::Unsupported("half-float LUT1D".into()));
+    }
+    if let Some(interp) = node.attribute("interpolation")
+        && interp != "linear"
+    {
+        return Err(ClfError::Unsupported(format!(
+            "LUT1D interpolation `{interp}`"
+        )));
+    }
+    let (_, out_scale) = depth_scales(node)?;
+    let (dim, values) = array(node)?;
+    let table: Vec<[f64; 3]> = match dim.as_slice() {
+        [n, 1] if *n >= 2 => values.iter().map(|v| [v / out_scale; 3]).collect(),
+        [n, 3] if *n >= 2 => values
+            .chunks_exact(3)
+            .map(|c| [c[0] / out_scale, c[1] / out_scale, c[2] / out_scale])
+            .collect(),
+        _ => return Err(ClfError::Invalid(format!("LUT1D with dim {dim:?}"))),
+    };
+    Ok(ClfNode::Lut1d { table })
+}
+
+fn parse_lut3d(node: roxmltree::Node) -> Result<ClfNode, ClfError> {
+    let interpolation = match node.attribute("interpolation") {
+        None | Some("trilinear") => Lut3dInterpolation::Trilinear,
+        Some("tetrahedral") => Lut3dInterpolation::Tetrahedral,
+        Some(other) => {
+            return Err(ClfError::Unsupported(format!(
+                "LUT3D interpolation `{other}`"
+            )));
+        }
+    };
+    let (_, out_scale) = depth_scales(node)?;
+    let (dim, values) = array(node)?;
+    let size = match dim.as_slice() {
+        [r, g, b, 3] if r == g && g == b && *r >= 2 => *r,
+        _ => return Err(ClfError::Invalid(format!("LUT3D with dim {dim:?}"))),
+    };
+    let table = values
+        .chunks_exact(3)
+        .map(|c| [c[0] / out_scale, c[1] / out_scale, c[2] / out_scale])
+        .collect();
+    Ok(ClfNode::Lut3d {
+        size,
+        table,
+        interpolation,
+    })
+}
+
+fn parse_range(node: roxmltree::Node) -> Result<ClfNode, ClfError> {
+    let (in_scale, out_scale) = depth_scales(node)?;
+    let value = |name: &str, scale: f64| -> Result<Option<f64>, ClfError> {
+        child(node, name)
+            .map(|c| {
+                c.text()
+                    .unwrap_or("")
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|v| v.is_finite())
+                    .map(|v| v / scale)
+                    .ok_or_else(|| ClfError::Invalid(format!("{name} is not a finite number")))
+            })
+            .transpose()
+    };
+    let min_in = value("minInValue", in_scale)?;
+    let max_in = value("maxInValue", in_scale)?;
+    let min_out = value("minOutValue", out_scale)?;
+    let max_out = value("maxOutValue", out_scale)?;
+    let clamp = match node.attribute("style") {
+        None | Some("clamp") => true,
+        Some("noClamp") => false,
+        Some(other) => return Err(ClfError::Invalid(format!("Range style `{other}`"))),
+    };
+
+    let has_min = min_in.is_some() && min_out.is_some();
+    let has_max = max_in.is_some() && max_out.is_some();
+    if min_in.is_some() != min_out.is_some() || max_in.is_some() != max_out.is_some() {
+        return Err(ClfError::Invalid(
+            "Range values must come in in/out pairs".into(),
+        ));
+    }
+    if !has_min && !has_max {
+        return Err(ClfError::Invalid("Range has no values".into()));
+    }
+    if !(clamp || has_min && has_max) {
+        return Err(ClfError::Invalid(
+            "noClamp Range needs all four values".into(),
+        ));
+    }
+    if has_min && has_max && min_in == max_in {
+        return Err(ClfError::Invalid(
+            "Range minInValue and maxInValue are equal".into(),
+        ));
+    }
+    Ok(ClfNode::Range {
+        min_in,
+        max_in,
+        min_out,
+        max_out,
+        clamp,
+    })
+}
+
+impl ProcessList {
+    /// Parse a CLF document from a string.
+    pub fn parse(xml: &str) -> Result<Self, ClfError> {
+        let doc = roxmltree::Document::parse(xml).map_err(|e| ClfError::Xml(e.to_string()))?;
+        let root = doc.root_element();
+        if root.tag_name().name() != "ProcessList" {
+            return Err(ClfError::Invalid("root element is not ProcessList".into()));
+        }
+
+        let mut nodes = Vec::new();
+        for node in root.children().filter(|n| n.is_element()) {
+            match node.tag_name().name() {
+                "Matrix" => nodes.push(parse_matrix(node)?),
+                "LUT1D" => nodes.push(parse_lut1d(node)?),
+                "LUT3D" => nodes.push(parse_lut3d(node)?),
+                "Range" => nodes.push(parse_range(node)?),
+                // Metadata elements carry no processing
+                "Description" | "InputDescriptor" | "OutputDescriptor" | "Info" => {}
+                other => return Err(ClfError::Unsupported(format!("`{other}` node"))),
+            }
+        }
+
+        Ok(ProcessList {
+            id: root.attribute("id").map(str::to_string),
+            name: root.attribute("name").map(str::to_string),
+            nodes,
+        })
+    }
+
+    /// Read and parse a `.clf` file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ClfError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+}
+
+fn lerp(a: [f64; 3], b: [f64; 3], t: f64) -> [f64; 3] {
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+    ]
+}
+
+/// Split a normalized coordinate into a lattice index and fraction.
+fn lattice(x: f64, size: usize) -> (usize, f64) {
+    let max = (size - 1) as f64;
+    let pos = (x * max).clamp(0.0, max);
+    let i = (pos.floor() as usize).min(size - 2);
+    (i, pos - i as f64)
+}
+
+fn sample_1d(table: &[[f64; 3]], rgb: [f64; 3]) -> [f64; 3] {
+    let mut out = [0.0; 3];
+    for ch in 0..3 {
+        let (i, t) = lattice(rgb[ch], table.len());
+        out[ch] = table[i][ch] + (table[i + 1][ch] - table[i][ch]) * t;
+    }
+    out
+}
+
+fn sample_3d(
+    size: usize,
+    table: &[[f64; 3]],
+    interpolation: Lut3dInterpolation,
+    rgb: [f64; 3],
+) -> [f64; 3] {
+    let (ri, fr) = lattice(rgb[0], size);
+    let (gi, fg) = lattice(rgb[1], size);
+    let (bi, fb) = lattice(rgb[2], size);
+    let at = |r: usize, g: usize, b: usize| table[((ri + r) * size + gi + g) * size + bi + b];
+
+    match interpolation {
+        Lut3dInterpolation::Trilinear => {
+            let c00 = lerp(at(0, 0, 0), at(1, 0, 0), fr);
+            let c01 = lerp(at(0, 0, 1), at(1, 0, 1), fr);
+            let c10 = lerp(at(0, 1, 0), at(1, 1, 0), fr);
+            let c11 = lerp(at(0, 1, 1), at(1, 1, 1), fr);
+            lerp(lerp(c00, c10, fg), lerp(c01, c11, fg), fb)
+        }
+        Lut3dInterpolation::Tetrahedral => {
+            let c000 = at(0, 0, 0);
+            let c111 = at(1, 1, 1);
+            // Walk from c000 to c111 through the two corners of the containing tetrahedron
+            let (w1, p1, w2, p2, w3) = if fr > fg {
+                if fg > fb {
+                    (fr, at(1, 0, 0), fg, at(1, 1, 0), fb)
+                } else if fr > fb {
+                    (fr, at(1, 0, 0), fb, at(1, 0, 1), fg)
+                } else {
+                    (fb, at(0, 0, 1), fr, at(1, 0, 1), fg)
+                }
+            } else if fb > fg {
+                (fb, at(0, 0, 1), fg, at(0, 1, 1), fr)
+            } else if fb > fr {
+                (fg, at(0, 1, 0), fb, at(0, 1, 1), fr)
+            } else {
+                (fg, at(0, 1, 0), fr, at(1, 1, 0), fb)
+            };
+            let mut out = [0.0; 3];
+            for ch in 0..3 {
+                out[ch] = c000[ch]
+                    + w1 * (p1[ch] - c000[ch])
+                    + w2 * (p2[ch] - p1[ch])
+                    + w3 * (c111[ch] - p2[ch]);
+            }
+            out
+        }
+    }
+}
+
+impl Transform for ClfNode {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        match self {
+            ClfNode::Matrix { matrix: m, offset } => [
+                m[0][0] * rgb[0] + m[0][1] * rgb[1] + m[0][2] * rgb[2] + offset[0],
+                m[1][0] * rgb[0] + m[1][1] * rgb[1] + m[1][2] * rgb[2] + offset[1],
+                m[2][0] * rgb[0] + m[2][1] * rgb[1] + m[2][2] * rgb[2] + offset[2],
+            ],
+            ClfNode::Lut1d { table } => sample_1d(table, rgb),
+            ClfNode::Lut3d {
+                size,
+                table,
+                interpolation,
+            } => sample_3d(*size, table, *interpolation, rgb),
+            ClfNode::Range {
+                min_in,
+                max_in,
+                min_out,
+                max_out,
+                clamp,
+            } => rgb.map(|x| match (*min_in, *max_in, *min_out, *max_out) {
+                (Some(lo_in), Some(hi_in), Some(lo_out), Some(hi_out)) => {
+                    let scale = (hi_out - lo_out) / (hi_in - lo_in);
+                    let y = lo_out + (x - lo_in) * scale;
+                    // An inverted output range is valid; `f64::clamp` would panic on it
+                    if *clamp {
+                        y.max(lo_out.min(hi_out)).min(lo_out.max(hi_out))
+                    } else {
+                        y
+                    }
+                }
+                (Some(lo_in), _, Some(lo_out), _) => (x - lo_in + lo_out).max(lo_out),
+                (_, Some(hi_in), _, Some(hi_out)) => (x - hi_in + hi_out).min(hi_out),
+                _ => x,
+            }),
+        }
+    }
+}
+
+impl Transform for ProcessList {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        self.nodes.iter().fold(rgb, |rgb, node| node.apply(rgb))
+    }
+}
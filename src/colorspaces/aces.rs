@@ -0,0 +1,246 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The ACES white (x = 0.32168, y = 0.33767) is not D65. The matrices below are the
+// official AP0 / AP1 -> XYZ matrices from SMPTE S-2065-1 / ACES TB-2014-004 followed by
+// a Bradford adaptation to D65, so ACES white maps exactly to sRGB white. Values are
+// scene-referred and never clamped; AP0 covers the whole spectrum locus, so most of its
+// code values lie far outside sRGB and come back as negative or > 1 components.
+
+/// ACEScg: linear AP1 primaries, the ACES working space for rendering and compositing
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct AcesCg {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(AcesCg { r, g, b }, a);
+
+/// ACES2065-1: linear AP0 primaries, the ACES archival and interchange encoding
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Aces2065_1 {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(Aces2065_1 { r, g, b }, a);
+
+/// ACEScc: AP1 with a pure log encoding (S-2014-003), for grading
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct AcesCc {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(AcesCc { r, g, b }, a);
+
+/// ACEScct: AP1 with a log encoding and a linear toe (S-2016-001), for grading
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct AcesCct {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(AcesCct { r, g, b }, a);
+
+const AP1_TO_SRGB: Mat3 = Mat3([
+    [
+        1.7050509925451642,
+        -0.6217921205453596,
+        -0.08325887199980467,
+    ],
+    [
+        -0.13025641746046468,
+        1.1408047365405636,
+        -0.010548319080098973,
+    ],
+    [
+        -0.024003356818960455,
+        -0.1289689760965908,
+        1.1529723329155512,
+    ],
+]);
+
+const SRGB_TO_AP1: Mat3 = Mat3([
+    [0.613097402430469, 0.33952314615205864, 0.04737945141747237],
+    [0.0701937224503146, 0.9163538790691708, 0.013452398480514657],
+    [0.020615592889411213, 0.1095697729637777, 0.869814634146811],
+]);
+
+const AP0_TO_SRGB: Mat3 = Mat3([
+    [2.521686186731984, -1.1341309880983834, -0.38755519863360044],
+    [
+        -0.27647991412962547,
+        1.3727190875675368,
+        -0.09623917343791144,
+    ],
+    [
+        -0.01537806497971215,
+        -0.15297533585788511,
+        1.1683534008375973,
+    ],
+]);
+
+const SRGB_TO_AP0: Mat3 = Mat3([
+    [0.439632981904686, 0.3829886981254835, 0.17737831996983053],
+    [0.08977644293035071, 0.8134394287754372, 0.09678412829421214],
+    [0.017541170383600625, 0.11154655330297038, 0.870912276313429],
+]);
+
+// Log curve shared by ACEScc and ACEScct: (log2(x) + 9.72) / 17.52
+const LOG_OFFSET: f64 = 9.72;
+const LOG_SCALE: f64 = 17.52;
+/// Largest half-float value; decoding saturates here as the specifications require
+const HALF_MAX: f64 = 65504.0;
+
+// ACEScct toe: linear segment below X_BRK, meeting the log curve at (X_BRK, Y_BRK)
+const CCT_X_BRK: f64 = 0.0078125;
+const CCT_Y_BRK: f64 = 0.155251141552511;
+const CCT_A: f64 = 10.5402377416545;
+const CCT_B: f64 = 0.0729055341958355;
+
+fn log_encode(lin: f64) -> f64 {
+    (lin.log2() + LOG_OFFSET) / LOG_SCALE
+}
+
+fn log_decode(log: f64) -> f64 {
+    if log >= log_encode(HALF_MAX) {
+        HALF_MAX
+    } else {
+        (log * LOG_SCALE - LOG_OFFSET).exp2()
+    }
+}
+
+// NOTE: ACEScc has no representation for zero or negative values; they all encode to
+// the value of 2^-16 and decode back as 0.0.
+fn cc_encode(lin: f64) -> f64 {
+    if lin <= 0.0 {
+        log_encode(2f64.powi(-16))
+    } else if lin < 2f64.powi(-15) {
+        log_encode(2f64.powi(-16) + lin * 0.5)
+    } else {
+        log_encode(lin)
+    }
+}
+
+fn cc_decode(cc: f64) -> f64 {
+    if cc < (LOG_OFFSET - 15.0) / LOG_SCALE {
+        (log_decode(cc) - 2f64.powi(-16)) * 2.0
+    } else {
+        log_decode(cc)
+    }
+}
+
+fn cct_encode(lin: f64) -> f64 {
+    if lin <= CCT_X_BRK {
+        CCT_A * lin + CCT_B
+    } else {
+        log_encode(lin)
+    }
+}
+
+fn cct_decode(cct: f64) -> f64 {
+    if cct <= CCT_Y_BRK {
+        (cct - CCT_B) / CCT_A
+    } else {
+        log_decode(cct)
+    }
+}
+
+impl From<AcesCg> for AcesCc {
+    fn from(lin: AcesCg) -> Self {
+        AcesCc {
+            r: cc_encode(lin.r),
+            g: cc_encode(lin.g),
+            b: cc_encode(lin.b),
+            a: lin.a,
+        }
+    }
+}
+
+impl From<AcesCc> for AcesCg {
+    fn from(cc: AcesCc) -> Self {
+        AcesCg {
+            r: cc_decode(cc.r),
+            g: cc_decode(cc.g),
+            b: cc_decode(cc.b),
+            a: cc.a,
+        }
+    }
+}
+
+impl From<AcesCg> for AcesCct {
+    fn from(lin: AcesCg) -> Self {
+        AcesCct {
+            r: cct_encode(lin.r),
+            g: cct_encode(lin.g),
+            b: cct_encode(lin.b),
+            a: lin.a,
+        }
+    }
+}
+
+impl From<AcesCct> for AcesCg {
+    fn from(cct: AcesCct) -> Self {
+        AcesCg {
+            r: cct_decode(cct.r),
+            g: cct_decode(cct.g),
+            b: cct_decode(cct.b),
+            a: cct.a,
+        }
+    }
+}
+
+impl ColorSpace for AcesCg {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = AP1_TO_SRGB.apply([self.r, self.g, self.b]);
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [r, g, b] = SRGB_TO_AP1.apply([c.r, c.g, c.b]);
+        AcesCg { r, g, b, a: c.a }
+    }
+}
+
+impl ColorSpace for Aces2065_1 {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = AP0_TO_SRGB.apply([self.r, self.g, self.b]);
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [r, g, b] = SRGB_TO_AP0.apply([c.r, c.g, c.b]);
+        Aces2065_1 { r, g, b, a: c.a }
+    }
+}
+
+impl ColorSpace for AcesCc {
+    fn to_color(&self) -> Color {
+        AcesCg::from(*self).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        AcesCg::from_color(c).into()
+    }
+}
+
+impl ColorSpace for AcesCct {
+    fn to_color(&self) -> Color {
+        AcesCg::from(*self).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        AcesCg::from_color(c).into()
+    }
+}
@@ -0,0 +1,271 @@
+use crate::colorspaces::ciecam02::{
+    UCS_C1, UCS_C2, achromatic, adapt, eccentricity, post_adaptation_rgb, unadapt,
+};
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::viewing_conditions::ViewingConditions;
+use crate::colorspaces::xyz::Xyz;
+use crate::math::Mat3;
+use crate::white_point::WhitePoint;
+use serde::{Deserialize, Serialize};
+
+// NOTE: CAM16 (Li et al. 2017) is CIECAM02 with the CAT02 and Hunt-Pointer-Estevez
+// matrices replaced by the single M16 matrix, so the compression, correlates and their
+// inverse are shared with `ciecam02`. XYZ is rescaled to Y = 100 internally.
+
+/// CAM16 appearance correlates: lightness J, chroma C, hue angle h (degrees).
+///
+/// The `ColorSpace` impl uses `ViewingConditions::default()`; use `from_color_with`
+/// and `to_color_with` for other environments.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Cam16 {
+    /// Lightness J [0, 100]
+    pub j: f64,
+    /// Chroma C [0, ~120]
+    pub c: f64,
+    /// Hue angle h in degrees [0, 360)
+    pub h: f64,
+    /// Alpha [0, 1]
+    pub alpha: f64,
+}
+
+impl_constructors!(Cam16 { j, c, h }, alpha);
+
+const EPSILON: f64 = 1e-10;
+
+pub(crate) const M16: Mat3 = Mat3([
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+]);
+
+pub(crate) const M16_INV: Mat3 = Mat3([
+    [1.8620678550872327, -1.0112546305316843, 0.14918677544445172],
+    [
+        0.38752654323613717,
+        0.6214474419314754,
+        -0.008973985167612518,
+    ],
+    [
+        -0.015841498849333856,
+        -0.03412293802851556,
+        1.0499644368778493,
+    ],
+]);
+
+/// Values derived from the viewing conditions, shared by the forward and inverse model.
+struct Params {
+    d_rgb: [f64; 3],
+    fl: f64,
+    n: f64,
+    z: f64,
+    nbb: f64,
+    c: f64,
+    nc: f64,
+    aw: f64,
+}
+
+impl Params {
+    fn new(vc: &ViewingConditions) -> Self {
+        let (_, c, nc) = vc.surround.parameters();
+        let white = vc.white.map(|v| v * 100.0);
+        let rgb_w = M16.apply(white);
+        let d = vc.degree_of_adaptation();
+        let d_rgb = rgb_w.map(|w| white[1] * d / w + 1.0 - d);
+
+        let la = vc.adapting_luminance;
+        let k = 1.0 / (5.0 * la + 1.0);
+        let k4 = k.powi(4);
+        let fl = 0.2 * k4 * (5.0 * la) + 0.1 * (1.0 - k4).powi(2) * (5.0 * la).cbrt();
+
+        let n = vc.background_luminance / white[1];
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 * (1.0 / n).powf(0.2);
+
+        let rgb_aw = [0, 1, 2].map(|i| adapt(rgb_w[i] * d_rgb[i], fl));
+        let aw = achromatic(rgb_aw, nbb);
+
+        Params {
+            d_rgb,
+            fl,
+            n,
+            z,
+            nbb,
+            c,
+            nc,
+            aw,
+        }
+    }
+
+    fn chroma_scale(&self) -> f64 {
+        (1.64 - 0.29f64.powf(self.n)).powf(0.73)
+    }
+}
+
+impl Cam16 {
+    /// Forward model from XYZ (Y = 1.0) under the given viewing conditions. XYZ with a
+    /// white other than D65 is adapted to D65 first, the frame the viewing conditions'
+    /// `white` is given in.
+    pub fn from_xyz(xyz: &Xyz, vc: &ViewingConditions) -> Self {
+        let p = Params::new(vc);
+        let xyz = xyz.to_white(WhitePoint::D65);
+        let rgb = M16.apply([xyz.x * 100.0, xyz.y * 100.0, xyz.z * 100.0]);
+        let rgb_a = [0, 1, 2].map(|i| adapt(rgb[i] * p.d_rgb[i], p.fl));
+
+        let a = rgb_a[0] - 12.0 * rgb_a[1] / 11.0 + rgb_a[2] / 11.0;
+        let b = (rgb_a[0] + rgb_a[1] - 2.0 * rgb_a[2]) / 9.0;
+        let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+
+        let achroma = achromatic(rgb_a, p.nbb);
+        let j = if achroma <= 0.0 {
+            0.0
+        } else {
+            100.0 * (achroma / p.aw).powf(p.c * p.z)
+        };
+
+        let et = eccentricity(h);
+        let denom = rgb_a[0] + rgb_a[1] + 21.0 / 20.0 * rgb_a[2];
+        let t = if denom.abs() < EPSILON {
+            0.0
+        } else {
+            (50000.0 / 13.0 * p.nc * p.nbb * et * (a * a + b * b).sqrt()) / denom
+        };
+        let c = t.powf(0.9) * (j / 100.0).sqrt() * p.chroma_scale();
+
+        Cam16 {
+            j,
+            c,
+            h: if c < EPSILON { 0.0 } else { h },
+            alpha: xyz.alpha,
+        }
+    }
+
+    /// Inverse model to XYZ (Y = 1.0) under the given viewing conditions.
+    pub fn to_xyz(&self, vc: &ViewingConditions) -> Xyz {
+        let p = Params::new(vc);
+        let j = self.j.max(0.0);
+        let t = if j < EPSILON {
+            0.0
+        } else {
+            (self.c.max(0.0) / ((j / 100.0).sqrt() * p.chroma_scale())).powf(1.0 / 0.9)
+        };
+        let et = eccentricity(self.h);
+        let achroma = p.aw * (j / 100.0).powf(1.0 / (p.c * p.z));
+
+        let rgb_a = post_adaptation_rgb(achroma / p.nbb + 0.305, t, self.h, et * p.nc * p.nbb);
+        let rgb = [0, 1, 2].map(|i| unadapt(rgb_a[i], p.fl) / p.d_rgb[i]);
+        let [x, y, z] = M16_INV.apply(rgb);
+        Xyz::with_alpha(x / 100.0, y / 100.0, z / 100.0, self.alpha)
+    }
+
+    /// Forward model from linear RGB under the given viewing conditions.
+    pub fn from_color_with(color: &Color, vc: &ViewingConditions) -> Self {
+        Self::from_xyz(&Xyz::from_color(color), vc)
+    }
+
+    /// Inverse model to linear RGB under the given viewing conditions.
+    pub fn to_color_with(&self, vc: &ViewingConditions) -> Color {
+        self.to_xyz(vc).to_color()
+    }
+
+    /// Brightness Q under the given viewing conditions.
+    pub fn brightness(&self, vc: &ViewingConditions) -> f64 {
+        let p = Params::new(vc);
+        (4.0 / p.c) * (self.j / 100.0).sqrt() * (p.aw + 4.0) * p.fl.powf(0.25)
+    }
+
+    /// Colorfulness M under the given viewing conditions.
+    pub fn colorfulness(&self, vc: &ViewingConditions) -> f64 {
+        self.c * Params::new(vc).fl.powf(0.25)
+    }
+
+    /// Saturation s under the given viewing conditions.
+    pub fn saturation(&self, vc: &ViewingConditions) -> f64 {
+        let q = self.brightness(vc);
+        if q < EPSILON {
+            0.0
+        } else {
+            100.0 * (self.colorfulness(vc) / q).sqrt()
+        }
+    }
+}
+
+impl ColorSpace for Cam16 {
+    fn to_color(&self) -> Color {
+        self.to_color_with(&ViewingConditions::default())
+    }
+
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, &ViewingConditions::default())
+    }
+}
+
+/// CAM16-UCS (Li et al. 2017): uniform J'a'b' coordinates derived from CAM16.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Cam16Ucs {
+    /// Lightness J'
+    pub j: f64,
+    /// Red-green a'
+    pub a: f64,
+    /// Yellow-blue b'
+    pub b: f64,
+    /// Alpha [0, 1]
+    pub alpha: f64,
+}
+
+impl_constructors!(Cam16Ucs { j, a, b }, alpha);
+
+impl Cam16Ucs {
+    /// Convert CAM16 correlates to CAM16-UCS under the given viewing conditions.
+    pub fn from_cam16(cam: &Cam16, vc: &ViewingConditions) -> Self {
+        let m = cam.colorfulness(vc);
+        let j = (1.0 + 100.0 * UCS_C1) * cam.j / (1.0 + UCS_C1 * cam.j);
+        let m_prime = (1.0 + UCS_C2 * m).ln() / UCS_C2;
+        let (sin_h, cos_h) = cam.h.to_radians().sin_cos();
+        Cam16Ucs {
+            j,
+            a: m_prime * cos_h,
+            b: m_prime * sin_h,
+            alpha: cam.alpha,
+        }
+    }
+
+    /// Convert back to CAM16 correlates under the given viewing conditions.
+    pub fn to_cam16(&self, vc: &ViewingConditions) -> Cam16 {
+        let j = self.j / (1.0 + 100.0 * UCS_C1 - UCS_C1 * self.j);
+        let m_prime = (self.a * self.a + self.b * self.b).sqrt();
+        let m = ((m_prime * UCS_C2).exp() - 1.0) / UCS_C2;
+        let h = if m_prime < EPSILON {
+            0.0
+        } else {
+            self.b.atan2(self.a).to_degrees().rem_euclid(360.0)
+        };
+        let fl = Params::new(vc).fl;
+        Cam16 {
+            j,
+            c: m / fl.powf(0.25),
+            h,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Color difference ΔE' in CAM16-UCS (Euclidean distance).
+    pub fn delta_e(&self, other: &Self) -> f64 {
+        let dj = self.j - other.j;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        (dj * dj + da * da + db * db).sqrt()
+    }
+}
+
+impl ColorSpace for Cam16Ucs {
+    fn to_color(&self) -> Color {
+        let vc = ViewingConditions::default();
+        self.to_cam16(&vc).to_color_with(&vc)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let vc = ViewingConditions::default();
+        Self::from_cam16(&Cam16::from_color_with(color, &vc), &vc)
+    }
+}
@@ -0,0 +1,218 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::uv::Uv1960;
+use crate::colorspaces::xyz::Xyz;
+use crate::spectral::Illuminant;
+use serde::{Deserialize, Serialize};
+
+// NOTE: From 1667 K up, the Planckian locus follows Robertson's (1968) table of locus
+// points and isotemperature-line slopes, joined by cubic Hermite interpolation in mireds
+// with tangents normal to the isotemperature lines; D65 comes out at 6504 K. Below the
+// table, the locus is integrated from Planck's law against the 10 nm CIE 1931 observer in
+// `spectral` (c2 = 1.438777e-2 m·K) and shifted to meet the table's last point. The 10 nm
+// integration alone is about 5e-5 off in uv, which put D65 at 6509 K. Both follow the
+// current temperature scale; temperatures are clamped to 1000-100000 K. Duv is the signed distance from the
+// locus along its normal in the 1960 uv diagram, positive above it (towards green) as in
+// ANSI C78.377. Far from the locus (|Duv| beyond about 0.05) CCT is poorly defined, but
+// the conversion still round-trips.
+
+const EPSILON: f64 = 1e-12;
+
+const MIN_KELVIN: f64 = 1000.0;
+const MAX_KELVIN: f64 = 100000.0;
+
+/// Number of coarse steps when searching the locus for the nearest temperature
+const SEARCH_STEPS: usize = 256;
+const REFINE_ITERATIONS: usize = 100;
+/// Mired step for the finite-difference tangent of the locus
+const TANGENT_STEP: f64 = 1e-3;
+
+/// Correlated color temperature, distance from the Planckian locus, and luminance
+///
+/// # Fields
+/// - kelvin: correlated color temperature (1000.0-100000.0)
+/// - duv: signed distance from the Planckian locus in the 1960 uv diagram (~-0.05-0.05)
+/// - luminance: relative luminance Y (0.0-1.0, white = 1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Cct {
+    pub kelvin: f64,
+    pub duv: f64,
+    pub luminance: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(
+    Cct {
+        kelvin,
+        duv,
+        luminance
+    },
+    alpha
+);
+
+/// Robertson's (1968) table: reciprocal temperature (mired), the 1960 u and v of the
+/// Planckian locus there, and the slope dv/du of the isotemperature line through it
+const ROBERTSON: [[f64; 4]; 31] = [
+    [0.0, 0.18006, 0.26352, -0.24341],
+    [10.0, 0.18066, 0.26589, -0.25479],
+    [20.0, 0.18133, 0.26846, -0.26876],
+    [30.0, 0.18208, 0.27119, -0.28539],
+    [40.0, 0.18293, 0.27407, -0.30470],
+    [50.0, 0.18388, 0.27709, -0.32675],
+    [60.0, 0.18494, 0.28021, -0.35156],
+    [70.0, 0.18611, 0.28342, -0.37915],
+    [80.0, 0.18740, 0.28668, -0.40955],
+    [90.0, 0.18880, 0.28997, -0.44278],
+    [100.0, 0.19032, 0.29326, -0.47888],
+    [125.0, 0.19462, 0.30141, -0.58204],
+    [150.0, 0.19962, 0.30921, -0.70471],
+    [175.0, 0.20525, 0.31647, -0.84901],
+    [200.0, 0.21142, 0.32312, -1.0182],
+    [225.0, 0.21807, 0.32909, -1.2168],
+    [250.0, 0.22511, 0.33439, -1.4512],
+    [275.0, 0.23247, 0.33904, -1.7298],
+    [300.0, 0.24010, 0.34308, -2.0637],
+    [325.0, 0.24792, 0.34655, -2.4681],
+    [350.0, 0.25591, 0.34951, -2.9641],
+    [375.0, 0.26400, 0.35200, -3.5814],
+    [400.0, 0.27218, 0.35407, -4.3633],
+    [425.0, 0.28039, 0.35577, -5.3762],
+    [450.0, 0.28863, 0.35714, -6.7262],
+    [475.0, 0.29685, 0.35823, -8.5955],
+    [500.0, 0.30505, 0.35907, -11.324],
+    [525.0, 0.31320, 0.35968, -15.628],
+    [550.0, 0.32129, 0.36011, -23.325],
+    [575.0, 0.32931, 0.36038, -40.770],
+    [600.0, 0.33724, 0.36051, -116.45],
+];
+
+/// Reciprocal temperature of the last `ROBERTSON` row (about 1667 K)
+const ROBERTSON_END: f64 = 600.0;
+
+/// d(u, v)/d(mired) of the locus at row `i` of `ROBERTSON`: normal to the
+/// isotemperature line, as fast as the chord between the neighbouring rows.
+fn robertson_tangent(i: usize) -> [f64; 2] {
+    let slope = ROBERTSON[i][3];
+    let len = slope.hypot(1.0);
+    let dir = [-slope / len, 1.0 / len];
+    let a = &ROBERTSON[i.saturating_sub(1)];
+    let b = &ROBERTSON[(i + 1).min(ROBERTSON.len() - 1)];
+    let speed = ((b[1] - a[1]) * dir[0] + (b[2] - a[2]) * dir[1]) / (b[0] - a[0]);
+    [dir[0] * speed, dir[1] * speed]
+}
+
+/// 1960 uv of the locus at `mired` (0 to `ROBERTSON_END`), interpolated in `ROBERTSON`.
+fn robertson_uv(mired: f64) -> [f64; 2] {
+    let i = ROBERTSON
+        .partition_point(|row| row[0] <= mired)
+        .clamp(1, ROBERTSON.len() - 1)
+        - 1;
+    let (a, b) = (&ROBERTSON[i], &ROBERTSON[i + 1]);
+    let h = b[0] - a[0];
+    let s = (mired - a[0]) / h;
+    let (ta, tb) = (robertson_tangent(i), robertson_tangent(i + 1));
+    // Cubic Hermite basis
+    let (s2, s3) = (s * s, s * s * s);
+    let (h00, h10) = (2.0 * s3 - 3.0 * s2 + 1.0, s3 - 2.0 * s2 + s);
+    let (h01, h11) = (3.0 * s2 - 2.0 * s3, s3 - s2);
+    [0, 1].map(|k| h00 * a[k + 1] + h10 * h * ta[k] + h01 * b[k + 1] + h11 * h * tb[k])
+}
+
+/// 1960 uv of the Planckian radiator at `mired`, integrated against the 10 nm observer.
+fn integrated_uv(mired: f64) -> [f64; 2] {
+    let [x, y, z] = Illuminant::Blackbody(1e6 / mired).white();
+    let uv = Uv1960::from(Xyz::new(x, y, z));
+    [uv.u, uv.v]
+}
+
+/// 1960 uv of the Planckian radiator at the given reciprocal temperature.
+fn locus_uv(mired: f64) -> [f64; 2] {
+    let mired = mired.clamp(1e6 / MAX_KELVIN, 1e6 / MIN_KELVIN);
+    if mired <= ROBERTSON_END {
+        return robertson_uv(mired);
+    }
+    let [u, v] = integrated_uv(mired);
+    let [u0, v0] = integrated_uv(ROBERTSON_END);
+    let [u1, v1] = robertson_uv(ROBERTSON_END);
+    [u + u1 - u0, v + v1 - v0]
+}
+
+/// 1960 uv of the Planckian radiator at `kelvin`, and the unit normal pointing above the
+/// locus.
+fn planckian_uv(kelvin: f64) -> ([f64; 2], [f64; 2]) {
+    let mired = 1e6 / kelvin.clamp(MIN_KELVIN, MAX_KELVIN);
+    let [u0, v0] = locus_uv(mired - TANGENT_STEP);
+    let [u1, v1] = locus_uv(mired + TANGENT_STEP);
+    let (du, dv) = (u1 - u0, v1 - v0);
+    let len = du.hypot(dv).max(EPSILON);
+    // Rotate the tangent a quarter turn, onto the side with increasing v
+    let sign = if du > 0.0 { -1.0 } else { 1.0 };
+    (locus_uv(mired), [sign * dv / len, -sign * du / len])
+}
+
+fn distance_sq([u, v]: [f64; 2], mired: f64) -> f64 {
+    let [pu, pv] = locus_uv(mired);
+    (u - pu).powi(2) + (v - pv).powi(2)
+}
+
+/// Temperature of the locus point nearest `uv`, searched in mireds.
+fn nearest_kelvin(uv: [f64; 2]) -> f64 {
+    let (lo, hi) = (1e6 / MAX_KELVIN, 1e6 / MIN_KELVIN);
+    let step = (hi - lo) / SEARCH_STEPS as f64;
+    let best = (0..=SEARCH_STEPS)
+        .map(|i| lo + step * i as f64)
+        .min_by(|a, b| distance_sq(uv, *a).total_cmp(&distance_sq(uv, *b)))
+        .unwrap_or(lo);
+
+    // Bisect on the projection of the offset onto the tangent, which changes sign at the
+    // nearest point
+    let slope = |mired: f64| {
+        let ([pu, pv], [nu, nv]) = planckian_uv(1e6 / mired);
+        (uv[0] - pu) * nv - (uv[1] - pv) * nu
+    };
+    let (mut a, mut b) = ((best - step).max(lo), (best + step).min(hi));
+    let mut slope_a = slope(a);
+    if slope_a * slope(b) > 0.0 {
+        return 1e6 / best;
+    }
+    for _ in 0..REFINE_ITERATIONS {
+        let mid = 0.5 * (a + b);
+        if b - a < EPSILON * mid {
+            break;
+        }
+        let slope_mid = slope(mid);
+        if slope_mid * slope_a > 0.0 {
+            (a, slope_a) = (mid, slope_mid);
+        } else {
+            b = mid;
+        }
+    }
+    1e6 / (0.5 * (a + b))
+}
+
+impl From<Uv1960> for Cct {
+    fn from(uv: Uv1960) -> Self {
+        let kelvin = nearest_kelvin([uv.u, uv.v]);
+        let ([pu, pv], [nu, nv]) = planckian_uv(kelvin);
+        let duv = (uv.u - pu) * nu + (uv.v - pv) * nv;
+        Cct::with_alpha(kelvin, duv, uv.luminance, uv.alpha)
+    }
+}
+
+impl From<Cct> for Uv1960 {
+    fn from(cct: Cct) -> Self {
+        let ([u, v], [nu, nv]) = planckian_uv(cct.kelvin);
+        Uv1960::with_alpha(u + cct.duv * nu, v + cct.duv * nv, cct.luminance, cct.alpha)
+    }
+}
+
+impl ColorSpace for Cct {
+    fn to_color(&self) -> Color {
+        Xyz::from(Uv1960::from(*self)).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Uv1960::from(Xyz::from_color(c)).into()
+    }
+}
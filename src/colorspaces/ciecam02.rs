@@ -0,0 +1,380 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::viewing_conditions::ViewingConditions;
+use crate::colorspaces::xyz::Xyz;
+use crate::math::Mat3;
+use crate::white_point::WhitePoint;
+use serde::{Deserialize, Serialize};
+
+// NOTE: CIECAM02 works on XYZ scaled to Y = 100; `Xyz` uses Y = 1.0, so values are
+// rescaled on the way in and out. The post-adaptation compression is applied to the
+// absolute value and the sign restored, so negative cone responses stay finite.
+
+/// CIECAM02 appearance correlates: lightness J, chroma C, hue angle h (degrees).
+///
+/// Chromatic adaptation uses CAT02 followed by the Hunt-Pointer-Estevez cone space. Kept
+/// alongside [`Cam16`](crate::Cam16) for standards that still specify CIECAM02.
+///
+/// The `ColorSpace` impl uses `ViewingConditions::default()`; use `from_color_with`
+/// and `to_color_with` for other environments.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ciecam02 {
+    /// Lightness J [0, 100]
+    pub j: f64,
+    /// Chroma C [0, ~120]
+    pub c: f64,
+    /// Hue angle h in degrees [0, 360)
+    pub h: f64,
+    /// Alpha [0, 1]
+    pub alpha: f64,
+}
+
+impl_constructors!(Ciecam02 { j, c, h }, alpha);
+
+const EPSILON: f64 = 1e-10;
+
+pub(crate) const M_CAT02: Mat3 = Mat3([
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.0030, 0.0136, 0.9834],
+]);
+
+pub(crate) const M_CAT02_INV: Mat3 = Mat3([
+    [1.0961238208355142, -0.27886900021828726, 0.1827451793827731],
+    [0.45436904197535916, 0.4735331543074117, 0.07209780371722913],
+    [
+        -0.009627608738429355,
+        -0.005698031216113421,
+        1.0153256399545427,
+    ],
+]);
+
+pub(crate) const M_HPE: Mat3 = Mat3([
+    [0.38971, 0.68898, -0.07868],
+    [-0.22981, 1.18340, 0.04641],
+    [0.0, 0.0, 1.0],
+]);
+
+pub(crate) const M_HPE_INV: Mat3 = Mat3([
+    [1.9101968340520348, -1.1121238927878747, 0.20190795676749937],
+    [
+        0.37095008824868864,
+        0.6290542573926132,
+        -8.055142184358517e-06,
+    ],
+    [0.0, 0.0, 1.0],
+]);
+
+/// Values derived from the viewing conditions, shared by the forward and inverse model.
+struct Params {
+    d_rgb: [f64; 3],
+    fl: f64,
+    n: f64,
+    z: f64,
+    nbb: f64,
+    c: f64,
+    nc: f64,
+    aw: f64,
+}
+
+pub(crate) fn adapt(component: f64, fl: f64) -> f64 {
+    let x = (fl * component.abs() / 100.0).powf(0.42);
+    component.signum() * 400.0 * x / (x + 27.13) + 0.1
+}
+
+pub(crate) fn unadapt(component: f64, fl: f64) -> f64 {
+    let c = component - 0.1;
+    let abs = c.abs().min(400.0 - EPSILON);
+    c.signum() * (100.0 / fl) * ((27.13 * abs) / (400.0 - abs)).powf(1.0 / 0.42)
+}
+
+pub(crate) fn achromatic(rgb_a: [f64; 3], nbb: f64) -> f64 {
+    (2.0 * rgb_a[0] + rgb_a[1] + rgb_a[2] / 20.0 - 0.305) * nbb
+}
+
+pub(crate) fn eccentricity(h: f64) -> f64 {
+    0.25 * ((h.to_radians() + 2.0).cos() + 3.8)
+}
+
+/// Post-adaptation cone responses from the achromatic term `p2` = A / Nbb + 0.305, the
+/// chroma term `t`, hue `h` in degrees and `k` = e_t · Nc · Nbb. Shared with CAM16.
+pub(crate) fn post_adaptation_rgb(p2: f64, t: f64, h: f64, k: f64) -> [f64; 3] {
+    let p3 = 21.0 / 20.0;
+    let (sin_h, cos_h) = h.to_radians().sin_cos();
+    let (a, b) = if t < EPSILON {
+        (0.0, 0.0)
+    } else {
+        let p1 = (50000.0 / 13.0) * k / t;
+        if sin_h.abs() >= cos_h.abs() {
+            let p4 = p1 / sin_h;
+            let b = p2 * (2.0 + p3) * (460.0 / 1403.0)
+                / (p4 + (2.0 + p3) * (220.0 / 1403.0) * (cos_h / sin_h) - 27.0 / 1403.0
+                    + p3 * (6300.0 / 1403.0));
+            (b * cos_h / sin_h, b)
+        } else {
+            let p5 = p1 / cos_h;
+            let a = p2 * (2.0 + p3) * (460.0 / 1403.0)
+                / (p5 + (2.0 + p3) * (220.0 / 1403.0)
+                    - (27.0 / 1403.0 - p3 * (6300.0 / 1403.0)) * (sin_h / cos_h));
+            (a, a * sin_h / cos_h)
+        }
+    };
+    [
+        (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0,
+        (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0,
+        (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0,
+    ]
+}
+
+impl Params {
+    fn new(vc: &ViewingConditions) -> Self {
+        let (_, c, nc) = vc.surround.parameters();
+        let white = [
+            vc.white[0] * 100.0,
+            vc.white[1] * 100.0,
+            vc.white[2] * 100.0,
+        ];
+        let rgb_w = M_CAT02.apply(white);
+        let d = vc.degree_of_adaptation();
+        let d_rgb = [
+            white[1] * d / rgb_w[0] + 1.0 - d,
+            white[1] * d / rgb_w[1] + 1.0 - d,
+            white[1] * d / rgb_w[2] + 1.0 - d,
+        ];
+
+        let la = vc.adapting_luminance;
+        let k = 1.0 / (5.0 * la + 1.0);
+        let k4 = k.powi(4);
+        let fl = 0.2 * k4 * (5.0 * la) + 0.1 * (1.0 - k4).powi(2) * (5.0 * la).cbrt();
+
+        let n = vc.background_luminance / white[1];
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 * (1.0 / n).powf(0.2);
+
+        let rgb_cw = [
+            rgb_w[0] * d_rgb[0],
+            rgb_w[1] * d_rgb[1],
+            rgb_w[2] * d_rgb[2],
+        ];
+        let hpe_w = M_HPE.apply(M_CAT02_INV.apply(rgb_cw));
+        let rgb_aw = [
+            adapt(hpe_w[0], fl),
+            adapt(hpe_w[1], fl),
+            adapt(hpe_w[2], fl),
+        ];
+        let aw = achromatic(rgb_aw, nbb);
+
+        Params {
+            d_rgb,
+            fl,
+            n,
+            z,
+            nbb,
+            c,
+            nc,
+            aw,
+        }
+    }
+
+    fn chroma_scale(&self) -> f64 {
+        (1.64 - 0.29f64.powf(self.n)).powf(0.73)
+    }
+}
+
+impl Ciecam02 {
+    /// Forward model from XYZ (Y = 1.0) under the given viewing conditions. XYZ with a
+    /// white other than D65 is adapted to D65 first, the frame the viewing conditions'
+    /// `white` is given in.
+    pub fn from_xyz(xyz: &Xyz, vc: &ViewingConditions) -> Self {
+        let p = Params::new(vc);
+        let xyz = xyz.to_white(WhitePoint::D65);
+        let rgb = M_CAT02.apply([xyz.x * 100.0, xyz.y * 100.0, xyz.z * 100.0]);
+        let rgb_c = [
+            rgb[0] * p.d_rgb[0],
+            rgb[1] * p.d_rgb[1],
+            rgb[2] * p.d_rgb[2],
+        ];
+        let hpe = M_HPE.apply(M_CAT02_INV.apply(rgb_c));
+        let rgb_a = [
+            adapt(hpe[0], p.fl),
+            adapt(hpe[1], p.fl),
+            adapt(hpe[2], p.fl),
+        ];
+
+        let a = rgb_a[0] - 12.0 * rgb_a[1] / 11.0 + rgb_a[2] / 11.0;
+        let b = (rgb_a[0] + rgb_a[1] - 2.0 * rgb_a[2]) / 9.0;
+        let mut h = b.atan2(a).to_degrees();
+        if h < 0.0 {
+            h += 360.0;
+        }
+
+        let achroma = achromatic(rgb_a, p.nbb);
+        let j = if achroma <= 0.0 {
+            0.0
+        } else {
+            100.0 * (achroma / p.aw).powf(p.c * p.z)
+        };
+
+        let et = eccentricity(h);
+        let denom = rgb_a[0] + rgb_a[1] + 21.0 / 20.0 * rgb_a[2];
+        let t = if denom.abs() < EPSILON {
+            0.0
+        } else {
+            (50000.0 / 13.0 * p.nc * p.nbb * et * (a * a + b * b).sqrt()) / denom
+        };
+        let c = t.powf(0.9) * (j / 100.0).sqrt() * p.chroma_scale();
+
+        Ciecam02 {
+            j,
+            c,
+            h: if c < EPSILON { 0.0 } else { h },
+            alpha: xyz.alpha,
+        }
+    }
+
+    /// Inverse model to XYZ (Y = 1.0) under the given viewing conditions.
+    pub fn to_xyz(&self, vc: &ViewingConditions) -> Xyz {
+        let p = Params::new(vc);
+        let j = self.j.max(0.0);
+        let t = if j < EPSILON {
+            0.0
+        } else {
+            (self.c.max(0.0) / ((j / 100.0).sqrt() * p.chroma_scale())).powf(1.0 / 0.9)
+        };
+        let et = eccentricity(self.h);
+        let achroma = p.aw * (j / 100.0).powf(1.0 / (p.c * p.z));
+
+        let rgb_a = post_adaptation_rgb(achroma / p.nbb + 0.305, t, self.h, et * p.nc * p.nbb);
+        let hpe = [
+            unadapt(rgb_a[0], p.fl),
+            unadapt(rgb_a[1], p.fl),
+            unadapt(rgb_a[2], p.fl),
+        ];
+        let rgb_c = M_CAT02.apply(M_HPE_INV.apply(hpe));
+        let rgb = [
+            rgb_c[0] / p.d_rgb[0],
+            rgb_c[1] / p.d_rgb[1],
+            rgb_c[2] / p.d_rgb[2],
+        ];
+        let xyz = M_CAT02_INV.apply(rgb);
+
+        Xyz::with_alpha(xyz[0] / 100.0, xyz[1] / 100.0, xyz[2] / 100.0, self.alpha)
+    }
+
+    /// Forward model from linear RGB under the given viewing conditions.
+    pub fn from_color_with(color: &Color, vc: &ViewingConditions) -> Self {
+        Self::from_xyz(&Xyz::from_color(color), vc)
+    }
+
+    /// Inverse model to linear RGB under the given viewing conditions.
+    pub fn to_color_with(&self, vc: &ViewingConditions) -> Color {
+        self.to_xyz(vc).to_color()
+    }
+
+    /// Brightness Q under the given viewing conditions.
+    pub fn brightness(&self, vc: &ViewingConditions) -> f64 {
+        let p = Params::new(vc);
+        (4.0 / p.c) * (self.j / 100.0).sqrt() * (p.aw + 4.0) * p.fl.powf(0.25)
+    }
+
+    /// Colorfulness M under the given viewing conditions.
+    pub fn colorfulness(&self, vc: &ViewingConditions) -> f64 {
+        self.c * Params::new(vc).fl.powf(0.25)
+    }
+
+    /// Saturation s under the given viewing conditions.
+    pub fn saturation(&self, vc: &ViewingConditions) -> f64 {
+        let q = self.brightness(vc);
+        if q < EPSILON {
+            0.0
+        } else {
+            100.0 * (self.colorfulness(vc) / q).sqrt()
+        }
+    }
+}
+
+impl ColorSpace for Ciecam02 {
+    fn to_color(&self) -> Color {
+        self.to_color_with(&ViewingConditions::default())
+    }
+
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, &ViewingConditions::default())
+    }
+}
+
+/// CAM02-UCS (Luo et al. 2006): uniform J'a'b' coordinates derived from CIECAM02.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Cam02Ucs {
+    /// Lightness J'
+    pub j: f64,
+    /// Red-green a'
+    pub a: f64,
+    /// Yellow-blue b'
+    pub b: f64,
+    /// Alpha [0, 1]
+    pub alpha: f64,
+}
+
+impl_constructors!(Cam02Ucs { j, a, b }, alpha);
+
+pub(crate) const UCS_C1: f64 = 0.007;
+pub(crate) const UCS_C2: f64 = 0.0228;
+
+impl Cam02Ucs {
+    /// Convert CIECAM02 correlates to CAM02-UCS under the given viewing conditions.
+    pub fn from_ciecam02(cam: &Ciecam02, vc: &ViewingConditions) -> Self {
+        let m = cam.colorfulness(vc);
+        let j = (1.0 + 100.0 * UCS_C1) * cam.j / (1.0 + UCS_C1 * cam.j);
+        let m_prime = (1.0 + UCS_C2 * m).ln() / UCS_C2;
+        let (sin_h, cos_h) = cam.h.to_radians().sin_cos();
+        Cam02Ucs {
+            j,
+            a: m_prime * cos_h,
+            b: m_prime * sin_h,
+            alpha: cam.alpha,
+        }
+    }
+
+    /// Convert back to CIECAM02 correlates under the given viewing conditions.
+    pub fn to_ciecam02(&self, vc: &ViewingConditions) -> Ciecam02 {
+        let j = self.j / (1.0 + 100.0 * UCS_C1 - UCS_C1 * self.j);
+        let m_prime = (self.a * self.a + self.b * self.b).sqrt();
+        let m = ((m_prime * UCS_C2).exp() - 1.0) / UCS_C2;
+        let mut h = if m_prime < EPSILON {
+            0.0
+        } else {
+            self.b.atan2(self.a).to_degrees()
+        };
+        if h < 0.0 {
+            h += 360.0;
+        }
+        let fl = Params::new(vc).fl;
+        Ciecam02 {
+            j,
+            c: m / fl.powf(0.25),
+            h,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Color difference ΔE' in CAM02-UCS (Euclidean distance, K_L = 1).
+    pub fn delta_e(&self, other: &Self) -> f64 {
+        let dj = self.j - other.j;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        (dj * dj + da * da + db * db).sqrt()
+    }
+}
+
+impl ColorSpace for Cam02Ucs {
+    fn to_color(&self) -> Color {
+        let vc = ViewingConditions::default();
+        self.to_ciecam02(&vc).to_color_with(&vc)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let vc = ViewingConditions::default();
+        Self::from_ciecam02(&Ciecam02::from_color_with(color, &vc), &vc)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::colorspaces::cmyk::Cmyk;
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut};
+use crate::colorspaces::srgb::Srgb;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Like `Cmyk`, this is the naive complement of sRGB-encoded values rather than an
+// ink model. Values are not clamped.
+
+/// CMY: subtractive cyan, magenta and yellow, the complement of sRGB
+///
+/// # Fields
+/// - c, m, y: ink coverage (0.0-1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Cmy {
+    pub c: f64,
+    pub m: f64,
+    pub y: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Cmy { c, m, y }, alpha);
+
+impl From<Cmyk> for Cmy {
+    fn from(cmyk: Cmyk) -> Self {
+        let ink = |v: f64| v * (1.0 - cmyk.k) + cmyk.k;
+        Cmy::with_alpha(ink(cmyk.c), ink(cmyk.m), ink(cmyk.y), cmyk.alpha)
+    }
+}
+
+impl ColorSpace for Cmy {
+    fn to_color(&self) -> Color {
+        Srgb::with_alpha(1.0 - self.c, 1.0 - self.m, 1.0 - self.y, self.alpha).to_color()
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let Srgb { r, g, b, a } = Srgb::from_color(color);
+        Cmy::with_alpha(1.0 - r, 1.0 - g, 1.0 - b, a)
+    }
+}
+
+impl Gamut for Cmy {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        [c.r, c.g, c.b]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = rgb;
+        Color::new(r, g, b, alpha)
+    }
+}
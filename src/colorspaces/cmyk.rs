@@ -0,0 +1,58 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut};
+use crate::colorspaces::srgb::Srgb;
+use serde::{Deserialize, Serialize};
+
+// NOTE: This is the naive device-independent formula on sRGB-encoded values, not an ICC
+// profile conversion: there is no ink model, dot gain or total ink limit, so it is only
+// suitable for previews and for displaying CMYK numbers. Black is extracted at the
+// maximum (K = 1 - max(R, G, B)); inputs outside [0, 1] are clamped.
+
+const EPSILON: f64 = 1e-10;
+
+/// Naive CMYK: cyan, magenta, yellow and key (black) ink coverage over sRGB
+///
+/// # Fields
+/// - c, m, y: ink coverage (0.0-1.0)
+/// - k: black coverage (0.0-1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Cmyk {
+    pub c: f64,
+    pub m: f64,
+    pub y: f64,
+    pub k: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Cmyk { c, m, y, k }, alpha);
+
+impl ColorSpace for Cmyk {
+    fn to_color(&self) -> Color {
+        let k = self.k.clamp(0.0, 1.0);
+        let ink = |v: f64| (1.0 - v.clamp(0.0, 1.0)) * (1.0 - k);
+        Srgb::with_alpha(ink(self.c), ink(self.m), ink(self.y), self.alpha).to_color()
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let Srgb { r, g, b, a } = Srgb::from_color(color);
+        let [r, g, b] = [r, g, b].map(|v| v.clamp(0.0, 1.0));
+        let k = 1.0 - r.max(g).max(b);
+        if 1.0 - k < EPSILON {
+            return Cmyk::with_alpha(0.0, 0.0, 0.0, 1.0, a);
+        }
+        let ink = |v: f64| (1.0 - v - k) / (1.0 - k);
+        Cmyk::with_alpha(ink(r), ink(g), ink(b), k, a)
+    }
+}
+
+impl Gamut for Cmyk {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        [c.r, c.g, c.b]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = rgb;
+        Color::new(r, g, b, alpha)
+    }
+}
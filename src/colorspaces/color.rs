@@ -1,3 +1,8 @@
+use crate::colorspaces::colorspace::{ColorSpace, Gamut};
+use crate::colorspaces::lab::Lab;
+use crate::colorspaces::oklab::Oklab;
+use crate::colorspaces::xyz::SRGB_TO_XYZ;
+use crate::math::dot;
 use serde::{Deserialize, Serialize};
 
 /// The main color struct, supporting HDR and multiple color spaces.
@@ -15,6 +20,10 @@ pub struct Color {
 }
 
 impl Color {
+    /// Relative luminance below which white text contrasts more than black (by the WCAG
+    /// ratio), about 0.179
+    pub const DARK_THRESHOLD: f64 = 0.17912878474779204;
+
     /// Construct a new color from linear RGBA components.
     pub fn new(r: f64, g: f64, b: f64, a: f64) -> Self {
         Self { r, g, b, a }
@@ -24,4 +33,61 @@ impl Color {
     pub fn opaque(r: f64, g: f64, b: f64) -> Self {
         Self { r, g, b, a: 1.0 }
     }
+
+    /// Whether the color can be represented in `T` without clipping, as
+    /// `color.fits::<Srgb>()`.
+    pub fn fits<T: Gamut>(&self) -> bool {
+        T::is_in_gamut(self)
+    }
+
+    /// Whether the color fits in `T`, allowing each linear component `epsilon` outside
+    /// [0, 1].
+    pub fn fits_with<T: Gamut>(&self, epsilon: f64) -> bool {
+        T::is_in_gamut_with(self, epsilon)
+    }
+
+    /// Relative luminance Y (0.0-1.0 for SDR, D65 white = 1.0).
+    ///
+    /// This is a linear light quantity, not perceived brightness; use one of the
+    /// lightness accessors to compare how light colors look.
+    pub fn luminance(&self) -> f64 {
+        dot(SRGB_TO_XYZ.0[1], [self.r, self.g, self.b])
+    }
+
+    /// WCAG relative luminance (0.0-1.0): BT.709 weights on linear sRGB, with each
+    /// component clipped to [0, 1] first as a display would show it.
+    pub fn relative_luminance(&self) -> f64 {
+        let [r, g, b] = [self.r, self.g, self.b].map(|v| v.clamp(0.0, 1.0));
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Whether the color is dark, by `DARK_THRESHOLD`.
+    pub fn is_dark(&self) -> bool {
+        self.is_dark_with(Self::DARK_THRESHOLD)
+    }
+
+    /// Whether the color is light, by `DARK_THRESHOLD`.
+    pub fn is_light(&self) -> bool {
+        !self.is_dark()
+    }
+
+    /// Whether the relative luminance is below `threshold`.
+    pub fn is_dark_with(&self, threshold: f64) -> bool {
+        self.relative_luminance() < threshold
+    }
+
+    /// Whether the relative luminance is at or above `threshold`.
+    pub fn is_light_with(&self, threshold: f64) -> bool {
+        !self.is_dark_with(threshold)
+    }
+
+    /// CIE L* (0-100), the perceived lightness of CIELAB.
+    pub fn lightness_lstar(&self) -> f64 {
+        Lab::from_color(self).l
+    }
+
+    /// Oklab L (0.0-1.0), perceived lightness in Oklab.
+    pub fn lightness_oklab(&self) -> f64 {
+        Oklab::from_color(self).l
+    }
 }
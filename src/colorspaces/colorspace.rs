@@ -2,9 +2,61 @@
 /// Types implementing this trait can convert to and from the central `Color` type.
 use crate::colorspaces::color::Color;
 
+/// Default tolerance for a component to count as inside its range, which absorbs the
+/// rounding of a round trip through the hub
+pub const GAMUT_EPSILON: f64 = 1e-9;
+
 pub trait ColorSpace: Sized {
     /// Convert from this color space to the central `Color` type.
     fn to_color(&self) -> Color;
     /// Convert from the central `Color` type to this color space.
     fn from_color(color: &Color) -> Self;
 }
+
+/// RGB-like color spaces with a bounded gamut: a color is representable when its linear
+/// components in the space's primaries lie in [0, 1]. Outside that `from_color` gives
+/// values out of range (or clamps them).
+pub trait Gamut: ColorSpace {
+    /// Linear components of `color` in this space's primaries.
+    fn linear_rgb(color: &Color) -> [f64; 3];
+
+    /// The color with the given linear components in this space's primaries.
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color;
+
+    /// Whether `color` can be represented, allowing each linear component `epsilon`
+    /// outside [0, 1].
+    fn is_in_gamut_with(color: &Color, epsilon: f64) -> bool {
+        Self::linear_rgb(color)
+            .iter()
+            .all(|c| (-epsilon..=1.0 + epsilon).contains(c))
+    }
+
+    /// Whether `color` can be represented, within `GAMUT_EPSILON`.
+    fn is_in_gamut(color: &Color) -> bool {
+        Self::is_in_gamut_with(color, GAMUT_EPSILON)
+    }
+
+    /// Clamp each linear component of `color` to [0, 1]. NaN components become 0.
+    fn clip(color: &Color) -> Color {
+        let rgb = Self::linear_rgb(color).map(|c| if c.is_nan() { 0.0 } else { c.clamp(0.0, 1.0) });
+        Self::from_linear_rgb(rgb, color.a)
+    }
+}
+
+/// Color spaces whose components can be interpolated, for `mix` and gradients.
+pub trait Interpolate: ColorSpace {
+    /// Index of the hue angle in degrees among `components`, for cylindrical spaces.
+    const HUE: Option<usize> = None;
+
+    /// The three components, in field order.
+    fn components(&self) -> [f64; 3];
+
+    /// The value with the given components and alpha.
+    fn from_components(components: [f64; 3], alpha: f64) -> Self;
+
+    /// Whether the hue carries no information, as for a gray; when mixing, the other
+    /// color's hue is used instead.
+    fn hue_is_powerless(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,175 @@
+use crate::chromatic_adaptation::bradford;
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::gamma_rgb::xy_to_xyz;
+use crate::colorspaces::rec709::{oetf, oetf_inverse};
+use crate::colorspaces::srgb::{linear_to_srgb, srgb_to_linear};
+use crate::colorspaces::xyz::XYZ_TO_SRGB;
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The matrices are computed once, when the space is built, from the primaries and
+// white point; a white other than D65 is Bradford-adapted to the hub's D65 so that RGB
+// white maps to sRGB white. Collinear primaries give a singular matrix and non-finite
+// results. Power curves are mirrored around zero, so negative components round-trip.
+
+/// Hub white, D65 as the sRGB matrices define it
+const D65: [f64; 2] = [0.3127, 0.3290];
+
+/// Transfer function of a `CustomRgbSpace`, mapping encoded values to linear light.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TransferFunction {
+    /// No encoding; components are linear
+    Linear,
+    /// The sRGB piecewise curve (IEC 61966-2-1)
+    #[default]
+    Srgb,
+    /// The BT.709 / BT.2020 camera curve (OETF)
+    Rec709,
+    /// Pure power law: linear = encoded^gamma
+    Gamma(f64),
+    /// ICC parametric curve (function type 3): linear = (a·V + b)^gamma for V >= d, and
+    /// c·V below
+    Parametric {
+        gamma: f64,
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+    },
+}
+
+impl TransferFunction {
+    /// Encoded value to linear light.
+    pub fn decode(&self, v: f64) -> f64 {
+        match *self {
+            TransferFunction::Linear => v,
+            TransferFunction::Srgb => srgb_to_linear(v),
+            TransferFunction::Rec709 => oetf_inverse(v),
+            TransferFunction::Gamma(gamma) => v.abs().powf(gamma).copysign(v),
+            TransferFunction::Parametric { gamma, a, b, c, d } => {
+                let x = v.abs();
+                let l = if x >= d {
+                    (a * x + b).max(0.0).powf(gamma)
+                } else {
+                    c * x
+                };
+                l.copysign(v)
+            }
+        }
+    }
+
+    /// Linear light to encoded value; the inverse of `decode`.
+    pub fn encode(&self, l: f64) -> f64 {
+        match *self {
+            TransferFunction::Linear => l,
+            TransferFunction::Srgb => linear_to_srgb(l),
+            TransferFunction::Rec709 => oetf(l),
+            TransferFunction::Gamma(gamma) => l.abs().powf(1.0 / gamma).copysign(l),
+            TransferFunction::Parametric { gamma, a, b, c, d } => {
+                let y = l.abs();
+                let v = if y >= c * d {
+                    (y.powf(1.0 / gamma) - b) / a
+                } else {
+                    y / c
+                };
+                v.copysign(l)
+            }
+        }
+    }
+}
+
+/// An RGB space defined at runtime by its primaries, white point and transfer function
+///
+/// `Default` is sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CustomRgbSpace {
+    to_srgb: [[f64; 3]; 3],
+    from_srgb: [[f64; 3]; 3],
+    transfer: TransferFunction,
+}
+
+impl Default for CustomRgbSpace {
+    fn default() -> Self {
+        let identity = Mat3::diagonal([1.0; 3]).0;
+        CustomRgbSpace {
+            to_srgb: identity,
+            from_srgb: identity,
+            transfer: TransferFunction::Srgb,
+        }
+    }
+}
+
+impl CustomRgbSpace {
+    /// Build a space from the xy chromaticities of its primaries and white point.
+    pub fn new(
+        red: [f64; 2],
+        green: [f64; 2],
+        blue: [f64; 2],
+        white: [f64; 2],
+        transfer: TransferFunction,
+    ) -> Self {
+        let [r, g, b] = [xy_to_xyz(red), xy_to_xyz(green), xy_to_xyz(blue)];
+        let p = Mat3([[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]]);
+        // Scale the primaries so RGB white lands on the white point
+        let white = xy_to_xyz(white);
+        let to_xyz = p.mul(&Mat3::diagonal(p.inverse().apply(white)));
+        let to_srgb = XYZ_TO_SRGB.mul(&bradford(white, xy_to_xyz(D65)).mul(&to_xyz));
+        CustomRgbSpace {
+            to_srgb: to_srgb.0,
+            from_srgb: to_srgb.inverse().0,
+            transfer,
+        }
+    }
+
+    /// Row-major matrix from linear RGB in this space to linear sRGB.
+    pub fn to_srgb_matrix(&self) -> [[f64; 3]; 3] {
+        self.to_srgb
+    }
+
+    /// The transfer function of this space.
+    pub fn transfer(&self) -> TransferFunction {
+        self.transfer
+    }
+}
+
+/// RGB in a `CustomRgbSpace`
+///
+/// The `ColorSpace` impl converts into the default (sRGB) space; use `from_color_with`
+/// for others.
+///
+/// # Fields
+/// - r, g, b: encoded components (0.0-1.0 in gamut)
+/// - a: opacity (0.0-1.0)
+/// - space: the space the components are expressed in
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CustomRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+    pub space: CustomRgbSpace,
+}
+
+impl_constructors!(CustomRgb { r, g, b }, a; space: CustomRgbSpace = CustomRgbSpace::default());
+
+impl CustomRgb {
+    /// Encode a color in the given space.
+    pub fn from_color_with(color: &Color, space: &CustomRgbSpace) -> Self {
+        let linear = Mat3(space.from_srgb).apply([color.r, color.g, color.b]);
+        let [r, g, b] = linear.map(|v| space.transfer.encode(v));
+        CustomRgb::with_alpha(r, g, b, color.a).space(*space)
+    }
+}
+
+impl ColorSpace for CustomRgb {
+    fn to_color(&self) -> Color {
+        let linear = [self.r, self.g, self.b].map(|v| self.space.transfer.decode(v));
+        let [r, g, b] = Mat3(self.space.to_srgb).apply(linear);
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, &CustomRgbSpace::default())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut};
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The DCI white (x = 0.314, y = 0.351, roughly 6300 K) is not D65. The matrices
+// below include a Bradford adaptation to D65, so DCI white maps exactly to sRGB white.
+// The pure 2.6 power is mirrored for negative components and not clamped.
+
+/// DCI-P3 (SMPTE RP 431-2): theatrical P3 primaries, DCI white, γ 2.6
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DciP3 {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(DciP3 { r, g, b }, a);
+
+const GAMMA: f64 = 2.6;
+
+// Derived at full precision from the primaries, DCI white -> D65 by Bradford
+const DCI_P3_TO_SRGB: Mat3 = Mat3([
+    [
+        1.1575164061997587,
+        -0.15496237807385776,
+        -0.0025540281259009583,
+    ],
+    [
+        -0.04150007153068597,
+        1.0455679230796993,
+        -0.004067851549013284,
+    ],
+    [
+        -0.01805003895625396,
+        -0.07857827265302907,
+        1.096628311609283,
+    ],
+]);
+
+const SRGB_TO_DCI_P3: Mat3 = Mat3([
+    [
+        0.8685797397161324,
+        0.12891913846084704,
+        0.0025011218230205435,
+    ],
+    [0.03454041025431944, 0.96181138636192, 0.0036482033837605824],
+    [0.01677142904145027, 0.07103999778688584, 0.9121885731716639],
+]);
+
+fn decode(c: f64) -> f64 {
+    c.abs().powf(GAMMA).copysign(c)
+}
+
+fn encode(c: f64) -> f64 {
+    c.abs().powf(1.0 / GAMMA).copysign(c)
+}
+
+impl ColorSpace for DciP3 {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = DCI_P3_TO_SRGB.apply([self.r, self.g, self.b].map(decode));
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [r, g, b] = SRGB_TO_DCI_P3.apply([c.r, c.g, c.b]).map(encode);
+        DciP3 { r, g, b, a: c.a }
+    }
+}
+
+impl Gamut for DciP3 {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        SRGB_TO_DCI_P3.apply([c.r, c.g, c.b])
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = DCI_P3_TO_SRGB.apply(rgb);
+        Color::new(r, g, b, alpha)
+    }
+}
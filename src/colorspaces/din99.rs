@@ -0,0 +1,92 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::lab::Lab;
+use serde::{Deserialize, Serialize};
+
+// NOTE: This is the original DIN 6176 DIN99 formula on CIELAB (D65 here, as `Lab` is),
+// not the later DIN99o revision. The weighting factors kE and kCH scale lightness and
+// chroma, so values from different factors are not comparable; `From<Lab>` and the
+// `ColorSpace` impl use kE = kCH = 1.
+
+/// Rotation of the a*b* plane before the chroma compression
+const ROTATION: f64 = 16.0;
+const L_SCALE: f64 = 105.51;
+const L_COMPRESSION: f64 = 0.0158;
+/// Scale of the rotated b* axis
+const F_SCALE: f64 = 0.7;
+const C_COMPRESSION: f64 = 0.045;
+
+/// DIN99 (DIN 6176): CIELAB with logarithmic lightness and chroma compression, so that
+/// Euclidean distance is a usable color difference
+///
+/// # Fields
+/// - l: lightness L99 (0.0-100.0)
+/// - a: red-green axis a99 (~-40.0-40.0)
+/// - b: yellow-blue axis b99 (~-40.0-40.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Din99 {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Din99 { l, a, b }, alpha);
+
+impl Din99 {
+    /// Convert CIELAB with the weighting factors kE (lightness) and kCH (chroma and hue).
+    pub fn from_lab_with(lab: &Lab, ke: f64, kch: f64) -> Self {
+        let (sin, cos) = ROTATION.to_radians().sin_cos();
+        let l = L_SCALE / ke * (1.0 + L_COMPRESSION * lab.l).ln();
+        let e = lab.a * cos + lab.b * sin;
+        let f = F_SCALE * (lab.b * cos - lab.a * sin);
+        let g = e.hypot(f);
+        let c = (1.0 + C_COMPRESSION * g).ln() / (C_COMPRESSION * kch * ke);
+        // Scale (e, f) to the compressed chroma, keeping the DIN99 hue
+        let scale = if g > 0.0 { c / g } else { 0.0 };
+        Din99::with_alpha(l, e * scale, f * scale, lab.alpha)
+    }
+
+    /// Convert to CIELAB, given the weighting factors the value was made with.
+    pub fn to_lab_with(&self, ke: f64, kch: f64) -> Lab {
+        let (sin, cos) = ROTATION.to_radians().sin_cos();
+        let l = ((self.l * ke / L_SCALE).exp() - 1.0) / L_COMPRESSION;
+        let c = self.a.hypot(self.b);
+        let g = ((C_COMPRESSION * kch * ke * c).exp() - 1.0) / C_COMPRESSION;
+        let scale = if c > 0.0 { g / c } else { 0.0 };
+        let e = self.a * scale;
+        let f = self.b * scale / F_SCALE;
+        Lab::with_alpha(l, e * cos - f * sin, e * sin + f * cos, self.alpha)
+    }
+
+    /// Color difference ΔE99: Euclidean distance between values with the same weights.
+    pub fn delta_e(&self, other: &Self) -> f64 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl From<Lab> for Din99 {
+    fn from(lab: Lab) -> Self {
+        Din99::from_lab_with(&lab, 1.0, 1.0)
+    }
+}
+
+impl From<Din99> for Lab {
+    fn from(din: Din99) -> Self {
+        din.to_lab_with(1.0, 1.0)
+    }
+}
+
+impl ColorSpace for Din99 {
+    fn to_color(&self) -> Color {
+        Lab::from(*self).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Lab::from_color(c).into()
+    }
+}
@@ -0,0 +1,160 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::linear_adobe_rgb::LinearAdobeRgb;
+use crate::colorspaces::linear_display_p3::LinearDisplayP3;
+use crate::colorspaces::linear_rec2020::LinearRec2020;
+use crate::colorspaces::xyz::XYZ_TO_SRGB;
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The pure power curve is mirrored around zero (sign-preserving), so negative
+// out-of-gamut components round-trip instead of producing NaN. A non-positive gamma
+// is not meaningful and is not guarded against.
+
+/// Primaries a `GammaRgb` value is expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum RgbPrimaries {
+    /// sRGB / Rec.709 primaries
+    #[default]
+    Srgb,
+    /// DCI-P3 primaries with D65 white
+    DisplayP3,
+    /// Rec.2020 primaries
+    Rec2020,
+    /// Adobe RGB (1998) primaries
+    AdobeRgb,
+    /// Custom primaries, as row-major matrices from linear RGB to linear sRGB and back;
+    /// build with `from_chromaticities` or `from_matrix`
+    Custom {
+        to_srgb: [[f64; 3]; 3],
+        from_srgb: [[f64; 3]; 3],
+    },
+}
+
+pub(crate) fn xy_to_xyz([x, y]: [f64; 2]) -> [f64; 3] {
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+impl RgbPrimaries {
+    /// Primaries from xy chromaticities of the red, green and blue primaries and white.
+    ///
+    /// No chromatic adaptation is applied; the result maps RGB white to the given white
+    /// point expressed in D65 XYZ.
+    pub fn from_chromaticities(
+        red: [f64; 2],
+        green: [f64; 2],
+        blue: [f64; 2],
+        white: [f64; 2],
+    ) -> Self {
+        let [r, g, b] = [xy_to_xyz(red), xy_to_xyz(green), xy_to_xyz(blue)];
+        let p = Mat3([[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]]);
+        // Scale the primaries so RGB white lands on the white point
+        let scale = p.inverse().apply(xy_to_xyz(white));
+        Self::from_matrix(XYZ_TO_SRGB.mul(&p.mul(&Mat3::diagonal(scale))).0)
+    }
+
+    /// Primaries from a row-major matrix taking linear RGB to linear sRGB.
+    ///
+    /// The inverse is computed here, once, rather than on every conversion.
+    pub fn from_matrix(to_srgb: [[f64; 3]; 3]) -> Self {
+        RgbPrimaries::Custom {
+            to_srgb,
+            from_srgb: Mat3(to_srgb).inverse().0,
+        }
+    }
+
+    /// Convert linear RGB in these primaries to the linear sRGB hub.
+    pub(crate) fn linear_to_color(&self, r: f64, g: f64, b: f64, a: f64) -> Color {
+        match self {
+            RgbPrimaries::Srgb => Color::new(r, g, b, a),
+            RgbPrimaries::DisplayP3 => LinearDisplayP3 { r, g, b, a }.to_color(),
+            RgbPrimaries::Rec2020 => LinearRec2020 { r, g, b, a }.to_color(),
+            RgbPrimaries::AdobeRgb => LinearAdobeRgb { r, g, b, a }.to_color(),
+            RgbPrimaries::Custom { to_srgb, .. } => {
+                let [r, g, b] = Mat3(*to_srgb).apply([r, g, b]);
+                Color::new(r, g, b, a)
+            }
+        }
+    }
+
+    /// Convert the linear sRGB hub to linear RGB in these primaries.
+    pub(crate) fn color_to_linear(&self, c: &Color) -> (f64, f64, f64) {
+        match self {
+            RgbPrimaries::Srgb => (c.r, c.g, c.b),
+            RgbPrimaries::DisplayP3 => {
+                let LinearDisplayP3 { r, g, b, .. } = LinearDisplayP3::from_color(c);
+                (r, g, b)
+            }
+            RgbPrimaries::Rec2020 => {
+                let LinearRec2020 { r, g, b, .. } = LinearRec2020::from_color(c);
+                (r, g, b)
+            }
+            RgbPrimaries::AdobeRgb => {
+                let LinearAdobeRgb { r, g, b, .. } = LinearAdobeRgb::from_color(c);
+                (r, g, b)
+            }
+            RgbPrimaries::Custom { from_srgb, .. } => {
+                let [r, g, b] = Mat3(*from_srgb).apply([c.r, c.g, c.b]);
+                (r, g, b)
+            }
+        }
+    }
+}
+
+/// RGB encoded with a pure power-law transfer (e.g. legacy γ 2.2 or Mac γ 1.8)
+///
+/// The `ColorSpace` impl encodes with γ 2.2 over sRGB primaries; use
+/// `from_color_with` for other gammas or primaries.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GammaRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+    /// Decoding exponent (linear = encoded^gamma)
+    pub gamma: f64,
+    /// Primaries the components are expressed in
+    pub primaries: RgbPrimaries,
+}
+
+impl_constructors!(GammaRgb { r, g, b }, a; gamma: f64 = GammaRgb::GAMMA_22, primaries: RgbPrimaries = RgbPrimaries::Srgb);
+
+fn power(c: f64, exponent: f64) -> f64 {
+    c.signum() * c.abs().powf(exponent)
+}
+
+impl GammaRgb {
+    /// Pure γ 2.2, as used by many legacy PC assets and tools
+    pub const GAMMA_22: f64 = 2.2;
+    /// Pure γ 1.8, the classic Mac OS display gamma
+    pub const GAMMA_18: f64 = 1.8;
+
+    /// Encode a color with the given gamma and primaries.
+    pub fn from_color_with(color: &Color, gamma: f64, primaries: RgbPrimaries) -> Self {
+        let (r, g, b) = primaries.color_to_linear(color);
+        let inv = 1.0 / gamma;
+        GammaRgb {
+            r: power(r, inv),
+            g: power(g, inv),
+            b: power(b, inv),
+            a: color.a,
+            gamma,
+            primaries,
+        }
+    }
+}
+
+impl ColorSpace for GammaRgb {
+    fn to_color(&self) -> Color {
+        self.primaries.linear_to_color(
+            power(self.r, self.gamma),
+            power(self.g, self.gamma),
+            power(self.b, self.gamma),
+            self.a,
+        )
+    }
+
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, Self::GAMMA_22, RgbPrimaries::Srgb)
+    }
+}
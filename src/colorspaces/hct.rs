@@ -0,0 +1,132 @@
+use crate::colorspaces::cam16::Cam16;
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::lab::{lightness_from_y, y_from_lightness};
+use crate::colorspaces::viewing_conditions::ViewingConditions;
+use crate::colorspaces::xyz::Xyz;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Converting to a color solves for the CAM16 lightness J that gives the requested
+// tone, with a Newton step on Y ≈ J². As in Material's HCT, a hue/chroma/tone that falls
+// outside sRGB returns the in-gamut color with the same hue and tone and the most chroma,
+// found by bisection, or the sRGB gray of that tone when no such color exists (near the
+// ends of the tone range the CAM16 neutral axis itself leaves sRGB). In-gamut values
+// round-trip exactly.
+
+const EPSILON: f64 = 1e-10;
+
+/// Allowed overshoot of a linear component before a solution counts as out of gamut
+const GAMUT_TOLERANCE: f64 = 1e-9;
+
+const J_ITERATIONS: usize = 32;
+const CHROMA_ITERATIONS: usize = 40;
+
+/// HCT (Material Design): CAM16 hue and chroma with CIELAB L* as tone
+///
+/// # Fields
+/// - h: CAM16 hue angle in degrees (0.0-360.0)
+/// - c: CAM16 chroma (0.0-~150.0)
+/// - t: tone, CIE L* (0.0-100.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Hct {
+    pub h: f64,
+    pub c: f64,
+    pub t: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Hct { h, c, t }, alpha);
+
+impl Hct {
+    /// Material's default environment: D65 white, L* 50 background, 200 lux ambient.
+    pub fn viewing_conditions() -> ViewingConditions {
+        let background = y_from_lightness(50.0) * 100.0;
+        ViewingConditions {
+            adapting_luminance: 200.0 / std::f64::consts::PI * background / 100.0,
+            background_luminance: background,
+            ..ViewingConditions::default()
+        }
+    }
+}
+
+/// XYZ with CAM16 hue `h` and chroma `c` whose luminance is `y`, if the solve converges.
+fn solve(h: f64, c: f64, y: f64, vc: &ViewingConditions) -> Option<Xyz> {
+    let [wx, wy, wz] = vc.white.map(|w| w * y);
+    let mut j = Cam16::from_xyz(&Xyz::new(wx, wy, wz), vc).j;
+    for _ in 0..J_ITERATIONS {
+        let xyz = Cam16::new(j, c, h).to_xyz(vc);
+        if !xyz.y.is_finite() || xyz.y <= 0.0 {
+            return None;
+        }
+        if (xyz.y - y).abs() <= EPSILON * y {
+            return Some(xyz);
+        }
+        j -= (xyz.y - y) * j / (2.0 * xyz.y);
+    }
+    None
+}
+
+/// The color clamped to the sRGB cube, if it lies within `GAMUT_TOLERANCE` of it.
+fn into_gamut(color: Color) -> Option<Color> {
+    let range = -GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE;
+    [color.r, color.g, color.b]
+        .iter()
+        .all(|v| range.contains(v))
+        .then(|| {
+            Color::new(
+                color.r.clamp(0.0, 1.0),
+                color.g.clamp(0.0, 1.0),
+                color.b.clamp(0.0, 1.0),
+                color.a,
+            )
+        })
+}
+
+impl ColorSpace for Hct {
+    fn to_color(&self) -> Color {
+        let vc = Hct::viewing_conditions();
+        let y = y_from_lightness(self.t.clamp(0.0, 100.0));
+        if y <= EPSILON {
+            return Color::new(0.0, 0.0, 0.0, self.alpha);
+        }
+        let gray = Color::new(y, y, y, self.alpha);
+        let attempt = |c: f64| solve(self.h, c, y, &vc).and_then(|xyz| into_gamut(xyz.to_color()));
+        if self.c < EPSILON {
+            return gray;
+        }
+        if let Some(color) = attempt(self.c) {
+            return Color {
+                a: self.alpha,
+                ..color
+            };
+        }
+
+        let (mut lo, mut hi) = (0.0, self.c);
+        let mut best = gray;
+        for _ in 0..CHROMA_ITERATIONS {
+            let mid = 0.5 * (lo + hi);
+            match attempt(mid) {
+                Some(color) => {
+                    best = color;
+                    lo = mid;
+                }
+                None => hi = mid,
+            }
+        }
+        Color {
+            a: self.alpha,
+            ..best
+        }
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let cam = Cam16::from_color_with(color, &Hct::viewing_conditions());
+        Hct {
+            h: cam.h,
+            c: cam.c,
+            t: lightness_from_y(color.luminance()),
+            alpha: color.a,
+        }
+    }
+}
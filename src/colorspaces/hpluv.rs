@@ -0,0 +1,58 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::hsluv::{from_lch_uv, to_lch_uv};
+use crate::colorspaces::luv::srgb_bounds;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Shares the Luv gamut boundary with HSLuv (see its NOTE). Saturation above 100
+// is valid for some hues, since the chroma limit is the smallest over all of them.
+
+const EPSILON: f64 = 1e-8;
+
+/// HPLuv: the pastel variant of HSLuv, whose chroma limit at each lightness is the
+/// largest chroma in gamut for every hue
+///
+/// # Fields
+/// - h: hue angle in degrees (0.0-360.0), the LCh(uv) hue
+/// - p: saturation (0.0-100.0), 100.0 at the hue-independent chroma limit
+/// - l: lightness L* (0.0-100.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Hpluv {
+    pub h: f64,
+    pub p: f64,
+    pub l: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Hpluv { h, p, l }, alpha);
+
+/// Largest chroma at lightness `l` that is in gamut for every hue: the distance from the
+/// neutral axis to the nearest boundary line.
+fn max_safe_chroma(l: f64) -> f64 {
+    srgb_bounds(l)
+        .iter()
+        .map(|(slope, intercept)| intercept.abs() / (slope * slope + 1.0).sqrt())
+        .fold(f64::INFINITY, f64::min)
+}
+
+impl ColorSpace for Hpluv {
+    fn to_color(&self) -> Color {
+        let c = if (EPSILON..=100.0 - EPSILON).contains(&self.l) {
+            max_safe_chroma(self.l) / 100.0 * self.p
+        } else {
+            0.0
+        };
+        from_lch_uv(self.l, c, self.h, self.alpha)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let (l, c, h) = to_lch_uv(color);
+        let p = if (EPSILON..=100.0 - EPSILON).contains(&l) {
+            c / max_safe_chroma(l) * 100.0
+        } else {
+            0.0
+        };
+        Hpluv::with_alpha(h, p, l, color.a)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::colorspaces::color::Color;
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Interpolate};
 use serde::{Deserialize, Serialize};
 
 // NOTE: This implementation does not clamp input/output values.
@@ -20,6 +20,8 @@ pub struct Hsl {
     pub a: f64,
 }
 
+impl_constructors!(Hsl { h, s, l }, a);
+
 impl ColorSpace for Hsl {
     fn to_color(&self) -> Color {
         // Precompute constants
@@ -109,3 +111,19 @@ impl ColorSpace for Hsl {
         Hsl { h, s, l, a: c.a }
     }
 }
+
+impl Interpolate for Hsl {
+    const HUE: Option<usize> = Some(0);
+
+    fn components(&self) -> [f64; 3] {
+        [self.h, self.s, self.l]
+    }
+
+    fn from_components([h, s, l]: [f64; 3], alpha: f64) -> Self {
+        Hsl::with_alpha(h, s, l, alpha)
+    }
+
+    fn hue_is_powerless(&self) -> bool {
+        self.s < EPSILON
+    }
+}
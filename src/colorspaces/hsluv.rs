@@ -0,0 +1,77 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::luv::{Luv, srgb_bounds};
+use serde::{Deserialize, Serialize};
+
+// NOTE: Luv uses the same white and matrices as the HSLuv reference. Its kappa and
+// epsilon are the rounded 903.3 and 0.008856 rather than the exact fractions, so values
+// near black differ slightly. Saturation is relative to the sRGB gamut; out-of-gamut
+// inputs give s > 100.
+
+const EPSILON: f64 = 1e-8;
+
+/// HSLuv: LCh(uv) with chroma rescaled so every saturation 0-100 stays inside sRGB
+///
+/// # Fields
+/// - h: hue angle in degrees (0.0-360.0), the LCh(uv) hue
+/// - s: saturation (0.0-100.0), 100.0 on the sRGB gamut boundary
+/// - l: lightness L* (0.0-100.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Hsluv {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Hsluv { h, s, l }, alpha);
+
+/// Largest in-gamut chroma at lightness `l` and hue `h` (degrees).
+pub(crate) fn max_chroma_for_hue(l: f64, h: f64) -> f64 {
+    let (sin, cos) = h.to_radians().sin_cos();
+    srgb_bounds(l)
+        .iter()
+        .map(|(slope, intercept)| intercept / (sin - slope * cos))
+        .filter(|length| *length >= 0.0)
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Polar LCh(uv) of a color.
+pub(crate) fn to_lch_uv(color: &Color) -> (f64, f64, f64) {
+    let Luv { l, u, v, .. } = Luv::from_color(color);
+    let c = (u * u + v * v).sqrt();
+    let h = if c < EPSILON {
+        0.0
+    } else {
+        v.atan2(u).to_degrees().rem_euclid(360.0)
+    };
+    (l, c, h)
+}
+
+/// Color from polar LCh(uv).
+pub(crate) fn from_lch_uv(l: f64, c: f64, h: f64, alpha: f64) -> Color {
+    let (sin, cos) = h.to_radians().sin_cos();
+    Luv::with_alpha(l, c * cos, c * sin, alpha).to_color()
+}
+
+impl ColorSpace for Hsluv {
+    fn to_color(&self) -> Color {
+        let c = if (EPSILON..=100.0 - EPSILON).contains(&self.l) {
+            max_chroma_for_hue(self.l, self.h) / 100.0 * self.s
+        } else {
+            0.0
+        };
+        from_lch_uv(self.l, c, self.h, self.alpha)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let (l, c, h) = to_lch_uv(color);
+        let s = if (EPSILON..=100.0 - EPSILON).contains(&l) {
+            c / max_chroma_for_hue(l, h) * 100.0
+        } else {
+            0.0
+        };
+        Hsluv::with_alpha(h, s, l, color.a)
+    }
+}
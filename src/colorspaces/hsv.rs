@@ -1,5 +1,5 @@
 use crate::colorspaces::color::Color;
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Interpolate};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -13,9 +13,12 @@ pub struct Hsv {
     pub a: f64,
 }
 
+impl_constructors!(Hsv { h, s, v }, a);
+
 // NOTE: This implementation does not clamp input/output values.
 // Documented risks: If input values are outside [0,1] for s, v, or a, or [0,360) for h, output RGB may be out of bounds.
 // Division by zero is avoided by logic, but not explicitly guarded. See comments below for details.
+
 const EPSILON: f64 = 1e-10;
 
 impl ColorSpace for Hsv {
@@ -80,3 +83,19 @@ impl ColorSpace for Hsv {
         }
     }
 }
+
+impl Interpolate for Hsv {
+    const HUE: Option<usize> = Some(0);
+
+    fn components(&self) -> [f64; 3] {
+        [self.h, self.s, self.v]
+    }
+
+    fn from_components([h, s, v]: [f64; 3], alpha: f64) -> Self {
+        Hsv::with_alpha(h, s, v, alpha)
+    }
+
+    fn hue_is_powerless(&self) -> bool {
+        self.s < EPSILON
+    }
+}
@@ -0,0 +1,57 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::luv::{XN_LUV, YN_LUV, ZN_LUV};
+use crate::colorspaces::xyz::Xyz;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The chromaticity coefficients Ka and Kb follow the white point as in ASTM E313
+// and colour-science (about 172.3 and 67.2 for D65). a and b divide by √Y, so they are
+// set to zero at black.
+
+const EPSILON: f64 = 1e-12;
+
+/// Hunter L, a, b (1948), as reported by many industrial colorimeters (D65)
+///
+/// # Fields
+/// - l: lightness (0.0-100.0)
+/// - a: red-green (~-100.0-100.0)
+/// - b: yellow-blue (~-100.0-100.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HunterLab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(HunterLab { l, a, b }, alpha);
+
+/// Hunter's chromaticity coefficients for the reference white (tristimulus on 0-100).
+fn coefficients() -> (f64, f64) {
+    let ka = 175.0 / 198.04 * (XN_LUV + YN_LUV) * 100.0;
+    let kb = 70.0 / 218.11 * (YN_LUV + ZN_LUV) * 100.0;
+    (ka, kb)
+}
+
+impl ColorSpace for HunterLab {
+    fn to_color(&self) -> Color {
+        let (ka, kb) = coefficients();
+        let s = self.l / 100.0;
+        let yr = s * s;
+        let x = XN_LUV * (self.a * s / ka + yr);
+        let z = ZN_LUV * (yr - self.b * s / kb);
+        Xyz::with_alpha(x, yr * YN_LUV, z, self.alpha).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let (ka, kb) = coefficients();
+        let Xyz { x, y, z, alpha, .. } = Xyz::from_color(c);
+        let (xr, yr, zr) = (x / XN_LUV, y / YN_LUV, z / ZN_LUV);
+        let s = yr.max(0.0).sqrt();
+        if s < EPSILON {
+            return HunterLab::with_alpha(0.0, 0.0, 0.0, alpha);
+        }
+        HunterLab::with_alpha(100.0 * s, ka * (xr - yr) / s, kb * (yr - zr) / s, alpha)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::colorspaces::color::Color;
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Interpolate};
 use crate::colorspaces::hsv::Hsv;
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +18,8 @@ pub struct Hwb {
     pub a: f64,
 }
 
+impl_constructors!(Hwb { h, w, b }, a);
+
 impl ColorSpace for Hwb {
     fn to_color(&self) -> Color {
         // Clamp w and b for stability, but output RGB is not clamped.
@@ -50,3 +52,19 @@ impl ColorSpace for Hwb {
         Hwb { h, w, b: bl, a }
     }
 }
+
+impl Interpolate for Hwb {
+    const HUE: Option<usize> = Some(0);
+
+    fn components(&self) -> [f64; 3] {
+        [self.h, self.w, self.b]
+    }
+
+    fn from_components([h, w, b]: [f64; 3], alpha: f64) -> Self {
+        Hwb::with_alpha(h, w, b, alpha)
+    }
+
+    fn hue_is_powerless(&self) -> bool {
+        self.w + self.b >= 1.0 - EPSILON
+    }
+}
@@ -0,0 +1,86 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::hdr::LuminanceContext;
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: PQ works on absolute luminance, so linear values are placed on a nit scale by a
+// `LuminanceContext`; the `ColorSpace` impl uses `LuminanceContext::default()` (BT.2408,
+// linear 1.0 = 203 nits). Negative LMS responses are encoded as zero by PQ and do not
+// round-trip.
+
+/// ICtCp (ITU-R BT.2100, PQ): intensity I and the Ct / Cp chroma axes
+///
+/// # Fields
+/// - i: intensity (0.0-1.0), the PQ signal of an achromatic color
+/// - ct: tritan (blue-yellow) axis (~-0.5-0.5)
+/// - cp: protan (red-green) axis (~-0.5-0.5)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Ictcp {
+    pub i: f64,
+    pub ct: f64,
+    pub cp: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Ictcp { i, ct, cp }, alpha);
+
+// BT.2100 Rec.2020-to-LMS matrix (with crosstalk) composed with linear sRGB to Rec.2020
+const SRGB_TO_LMS: Mat3 = Mat3([
+    [0.2958093545619696, 0.6231013045111414, 0.08108934092688898],
+    [0.15624897621578657, 0.7273257249314091, 0.11642529885280435],
+    [
+        0.035135662763863075,
+        0.15657472713108786,
+        0.8082896101050491,
+    ],
+]);
+
+const LMS_TO_SRGB: Mat3 = Mat3([
+    [6.173362741580503, -5.32038056803985, 0.1470178264593466],
+    [-1.3243114999749608, 2.5602264209242755, -0.2359149209493148],
+    [
+        -0.011816902682674926,
+        -0.26467203482692064,
+        1.2764889375095956,
+    ],
+]);
+
+const LMS_TO_ICTCP: Mat3 = Mat3([
+    [2048.0 / 4096.0, 2048.0 / 4096.0, 0.0],
+    [6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0],
+    [17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0],
+]);
+
+const ICTCP_TO_LMS: Mat3 = Mat3([
+    [1.0, 0.008609037037932756, 0.11102962500302596],
+    [1.0, -0.008609037037932756, -0.11102962500302596],
+    [1.0, 0.5600313357106791, -0.32062717498731885],
+]);
+
+impl Ictcp {
+    /// Convert a linear color, with linear 1.0 at `context.diffuse_white` nits.
+    pub fn from_color_with(color: &Color, context: &LuminanceContext) -> Self {
+        let lms = SRGB_TO_LMS.apply([color.r, color.g, color.b]);
+        let [i, ct, cp] = LMS_TO_ICTCP.apply(lms.map(|v| context.encode_pq(v)));
+        Ictcp::with_alpha(i, ct, cp, color.a)
+    }
+
+    /// Convert to a linear color, with linear 1.0 at `context.diffuse_white` nits.
+    pub fn to_color_with(&self, context: &LuminanceContext) -> Color {
+        let lms = ICTCP_TO_LMS.apply([self.i, self.ct, self.cp]);
+        let [r, g, b] = LMS_TO_SRGB.apply(lms.map(|v| context.decode_pq(v)));
+        Color::new(r, g, b, self.alpha)
+    }
+}
+
+impl ColorSpace for Ictcp {
+    fn to_color(&self) -> Color {
+        self.to_color_with(&LuminanceContext::default())
+    }
+
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, &LuminanceContext::default())
+    }
+}
@@ -1,5 +1,9 @@
 use crate::colorspaces::color::Color;
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Interpolate};
+use crate::colorspaces::luv::{XN_LUV, YN_LUV, ZN_LUV};
+use crate::colorspaces::xyz::{
+    SRGB_TO_XYZ, SRGB_TO_XYZ_D50, WHITE_D50, XYZ_D50_TO_SRGB, XYZ_TO_SRGB,
+};
 use serde::{Deserialize, Serialize};
 
 // NOTE: Numerical stability risks documented below.
@@ -16,9 +20,12 @@ pub struct Lab {
     pub alpha: f64,
 }
 
-const XN: f64 = 0.95047;
-const YN: f64 = 1.0;
-const ZN: f64 = 1.08883;
+impl_constructors!(Lab { l, a, b }, alpha);
+
+// The hub's D65 white, so that sRGB grays have a* = b* = 0
+const XN: f64 = XN_LUV;
+const YN: f64 = YN_LUV;
+const ZN: f64 = ZN_LUV;
 
 fn f(t: f64) -> f64 {
     // Precompute constants for minimal ops
@@ -50,46 +57,98 @@ fn f_inv(u: f64) -> f64 {
     }
 }
 
+/// Lightness L* for relative luminance `y` (white = 1.0).
+pub(crate) fn lightness_from_y(y: f64) -> f64 {
+    116.0 * f(y / YN) - 16.0
+}
+
+/// Relative luminance (white = 1.0) for lightness L*.
+pub(crate) fn y_from_lightness(l: f64) -> f64 {
+    YN * f_inv((l + 16.0) / 116.0)
+}
+
+/// L*a*b* of an XYZ triple relative to the white `[xn, yn, zn]`.
+pub(crate) fn xyz_to_lab([x, y, z]: [f64; 3], [xn, yn, zn]: [f64; 3]) -> [f64; 3] {
+    let fx = f(x / xn);
+    let fy = f(y / yn);
+    let fz = f(z / zn);
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// XYZ of an L*a*b* triple relative to the white `[xn, yn, zn]`.
+fn lab_to_xyz([l, a, b]: [f64; 3], [xn, yn, zn]: [f64; 3]) -> [f64; 3] {
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + (a / 500.0);
+    let fz = fy - (b / 200.0);
+
+    [xn * f_inv(fx), yn * f_inv(fy), zn * f_inv(fz)]
+}
+
 impl ColorSpace for Lab {
     fn from_color(c: &Color) -> Self {
-        let x = 0.4124 * c.r + 0.3576 * c.g + 0.1805 * c.b;
-        let y = 0.2126 * c.r + 0.7152 * c.g + 0.0722 * c.b;
-        let z = 0.0193 * c.r + 0.1192 * c.g + 0.9505 * c.b;
-
-        let fx = f(x / XN);
-        let fy = f(y / YN);
-        let fz = f(z / ZN);
-
-        let l = 116.0 * fy - 16.0;
-        let a = 500.0 * (fx - fy);
-        let b = 200.0 * (fy - fz);
-
-        Lab {
-            l,
-            a,
-            b,
-            alpha: c.a,
-        }
+        let xyz = SRGB_TO_XYZ.apply([c.r, c.g, c.b]);
+        let [l, a, b] = xyz_to_lab(xyz, [XN, YN, ZN]);
+        Lab::with_alpha(l, a, b, c.a)
     }
 
     fn to_color(&self) -> Color {
-        // Precompute constants for minimal ops
-        const XN: f64 = 0.95047;
-        const YN: f64 = 1.0;
-        const ZN: f64 = 1.08883;
+        let xyz = lab_to_xyz([self.l, self.a, self.b], [XN, YN, ZN]);
+        let [r, g, b] = XYZ_TO_SRGB.apply(xyz);
+        Color::new(r, g, b, self.alpha)
+    }
+}
 
-        let fy = (self.l + 16.0) / 116.0;
-        let fx = fy + (self.a / 500.0);
-        let fz = fy - (self.b / 200.0);
+/// CIE Lab relative to the ICC D50 white, the ICC profile connection space
+///
+/// Colors are adapted between the hub's D65 and D50 with Bradford, so D65 white maps to
+/// L* = 100, a* = b* = 0.
+///
+/// # Fields
+/// - l: lightness (0.0-100.0)
+/// - a: green-red axis (unbounded, ~-128.0-128.0)
+/// - b: blue-yellow axis (unbounded, ~-128.0-128.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LabD50 {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
 
-        let x = XN * f_inv(fx);
-        let y = YN * f_inv(fy);
-        let z = ZN * f_inv(fz);
+impl_constructors!(LabD50 { l, a, b }, alpha);
 
-        let r = 3.2406 * x - 1.5372 * y - 0.4986 * z;
-        let g = -0.9689 * x + 1.8758 * y + 0.0415 * z;
-        let b = 0.0557 * x - 0.2040 * y + 1.0570 * z;
+impl ColorSpace for LabD50 {
+    fn from_color(c: &Color) -> Self {
+        let xyz = SRGB_TO_XYZ_D50.apply([c.r, c.g, c.b]);
+        let [l, a, b] = xyz_to_lab(xyz, WHITE_D50);
+        LabD50::with_alpha(l, a, b, c.a)
+    }
 
+    fn to_color(&self) -> Color {
+        let xyz = lab_to_xyz([self.l, self.a, self.b], WHITE_D50);
+        let [r, g, b] = XYZ_D50_TO_SRGB.apply(xyz);
         Color::new(r, g, b, self.alpha)
     }
 }
+
+impl Interpolate for Lab {
+    fn components(&self) -> [f64; 3] {
+        [self.l, self.a, self.b]
+    }
+
+    fn from_components([l, a, b]: [f64; 3], alpha: f64) -> Self {
+        Lab::with_alpha(l, a, b, alpha)
+    }
+}
+
+impl Interpolate for LabD50 {
+    fn components(&self) -> [f64; 3] {
+        [self.l, self.a, self.b]
+    }
+
+    fn from_components([l, a, b]: [f64; 3], alpha: f64) -> Self {
+        LabD50::with_alpha(l, a, b, alpha)
+    }
+}
@@ -1,7 +1,10 @@
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Interpolate};
 use crate::colorspaces::lab::Lab;
 use serde::{Deserialize, Serialize};
 
+/// Chroma below which the hue is powerless; grays round to about 1e-13
+const ACHROMATIC_CHROMA: f64 = 1e-6;
+
 /// Cylindrical Lab: L, C, H (deg)
 ///
 /// # Numerical Stability
@@ -16,6 +19,8 @@ pub struct Lch {
     pub a: f64,
 }
 
+impl_constructors!(Lch { l, c, h }, a);
+
 impl ColorSpace for Lch {
     fn to_color(&self) -> crate::colorspaces::color::Color {
         // Precompute radians once
@@ -56,3 +61,19 @@ impl ColorSpace for Lch {
         }
     }
 }
+
+impl Interpolate for Lch {
+    const HUE: Option<usize> = Some(2);
+
+    fn components(&self) -> [f64; 3] {
+        [self.l, self.c, self.h]
+    }
+
+    fn from_components([l, c, h]: [f64; 3], alpha: f64) -> Self {
+        Lch::with_alpha(l, c, h, alpha)
+    }
+
+    fn hue_is_powerless(&self) -> bool {
+        self.c < ACHROMATIC_CHROMA
+    }
+}
@@ -0,0 +1,64 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut, Interpolate};
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+/// Linear Adobe RGB (1998) (D65 white, no transfer function)
+///
+/// Values are not clamped; out-of-gamut and HDR components pass through unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct LinearAdobeRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(LinearAdobeRgb { r, g, b }, a);
+
+// Derived at full precision from the primaries, D65 on both sides
+const ADOBE_RGB_TO_SRGB: Mat3 = Mat3([
+    [1.3983557439607783, -0.3983557439607783, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, -0.042928989294473266, 1.0429289892944733],
+]);
+
+const SRGB_TO_ADOBE_RGB: Mat3 = Mat3([
+    [0.7151256068556247, 0.2848743931443754, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, 0.04116194845011847, 0.9588380515498816],
+]);
+
+impl ColorSpace for LinearAdobeRgb {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = ADOBE_RGB_TO_SRGB.apply([self.r, self.g, self.b]);
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [r, g, b] = SRGB_TO_ADOBE_RGB.apply([c.r, c.g, c.b]);
+        LinearAdobeRgb { r, g, b, a: c.a }
+    }
+}
+
+impl Gamut for LinearAdobeRgb {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        let LinearAdobeRgb { r, g, b, .. } = LinearAdobeRgb::from_color(c);
+        [r, g, b]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = rgb;
+        LinearAdobeRgb::with_alpha(r, g, b, alpha).to_color()
+    }
+}
+
+impl Interpolate for LinearAdobeRgb {
+    fn components(&self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    fn from_components([r, g, b]: [f64; 3], alpha: f64) -> Self {
+        LinearAdobeRgb::with_alpha(r, g, b, alpha)
+    }
+}
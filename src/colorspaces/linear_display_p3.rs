@@ -0,0 +1,72 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut, Interpolate};
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+/// Linear Display P3 (DCI‑P3 primaries + D65 white, no transfer function)
+///
+/// Values are not clamped; out-of-gamut and HDR components pass through unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct LinearDisplayP3 {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(LinearDisplayP3 { r, g, b }, a);
+
+// Derived at full precision from the primaries, D65 on both sides
+const P3_TO_SRGB: Mat3 = Mat3([
+    [1.2249401762805598, -0.22494017628055996, 0.0],
+    [-0.04205695470968816, 1.042056954709688, 0.0],
+    [
+        -0.019637554590334432,
+        -0.07863604555063189,
+        1.0982736001409663,
+    ],
+]);
+
+const SRGB_TO_P3: Mat3 = Mat3([
+    [0.8224619687143623, 0.17753803128563775, 0.0],
+    [0.03319419885096162, 0.9668058011490384, 0.0],
+    [
+        0.017082630721120033,
+        0.07239744066396347,
+        0.9105199286149165,
+    ],
+]);
+
+impl ColorSpace for LinearDisplayP3 {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = P3_TO_SRGB.apply([self.r, self.g, self.b]);
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [r, g, b] = SRGB_TO_P3.apply([c.r, c.g, c.b]);
+        LinearDisplayP3 { r, g, b, a: c.a }
+    }
+}
+
+impl Gamut for LinearDisplayP3 {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        let LinearDisplayP3 { r, g, b, .. } = LinearDisplayP3::from_color(c);
+        [r, g, b]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = rgb;
+        LinearDisplayP3::with_alpha(r, g, b, alpha).to_color()
+    }
+}
+
+impl Interpolate for LinearDisplayP3 {
+    fn components(&self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    fn from_components([r, g, b]: [f64; 3], alpha: f64) -> Self {
+        LinearDisplayP3::with_alpha(r, g, b, alpha)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut, Interpolate};
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+/// Linear Rec.2020 RGB (D65, no transfer function)
+///
+/// Values are not clamped; out-of-gamut and HDR components pass through unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct LinearRec2020 {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(LinearRec2020 { r, g, b }, a);
+
+// Derived at full precision from the primaries, D65 on both sides
+const REC2020_TO_SRGB: Mat3 = Mat3([
+    [
+        1.6604910021084345,
+        -0.5876411387885495,
+        -0.07284986331988488,
+    ],
+    [
+        -0.12455047452159074,
+        1.1328998971259603,
+        -0.008349422604369477,
+    ],
+    [
+        -0.018150763354905303,
+        -0.10057889800800739,
+        1.1187296613629127,
+    ],
+]);
+
+const SRGB_TO_REC2020: Mat3 = Mat3([
+    [0.627403895934699, 0.3292830383778837, 0.043313065687417225],
+    [
+        0.06909728935823208,
+        0.9195403950754587,
+        0.011362315566309178,
+    ],
+    [0.01639143887515028, 0.08801330787722575, 0.895595253247624],
+]);
+
+impl ColorSpace for LinearRec2020 {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = REC2020_TO_SRGB.apply([self.r, self.g, self.b]);
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [r, g, b] = SRGB_TO_REC2020.apply([c.r, c.g, c.b]);
+        LinearRec2020 { r, g, b, a: c.a }
+    }
+}
+
+impl Gamut for LinearRec2020 {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        let LinearRec2020 { r, g, b, .. } = LinearRec2020::from_color(c);
+        [r, g, b]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = rgb;
+        LinearRec2020::with_alpha(r, g, b, alpha).to_color()
+    }
+}
+
+impl Interpolate for LinearRec2020 {
+    fn components(&self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    fn from_components([r, g, b]: [f64; 3], alpha: f64) -> Self {
+        LinearRec2020::with_alpha(r, g, b, alpha)
+    }
+}
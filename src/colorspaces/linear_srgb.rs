@@ -0,0 +1,48 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut, Interpolate};
+use serde::{Deserialize, Serialize};
+
+/// Linear sRGB (sRGB primaries + D65 white, no transfer function)
+///
+/// The same values as `Color`, as a distinct type for code that wants to mark
+/// already-decoded components. Values are not clamped.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct LinearSrgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(LinearSrgb { r, g, b }, a);
+
+impl ColorSpace for LinearSrgb {
+    fn to_color(&self) -> Color {
+        Color::new(self.r, self.g, self.b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        LinearSrgb::with_alpha(c.r, c.g, c.b, c.a)
+    }
+}
+
+impl Gamut for LinearSrgb {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        [c.r, c.g, c.b]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = rgb;
+        Color::new(r, g, b, alpha)
+    }
+}
+
+impl Interpolate for LinearSrgb {
+    fn components(&self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    fn from_components([r, g, b]: [f64; 3], alpha: f64) -> Self {
+        LinearSrgb::with_alpha(r, g, b, alpha)
+    }
+}
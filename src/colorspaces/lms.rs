@@ -0,0 +1,113 @@
+use crate::colorspaces::cam16::{M16, M16_INV};
+use crate::colorspaces::ciecam02::{M_CAT02, M_CAT02_INV, M_HPE, M_HPE_INV};
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::xyz::{SRGB_TO_XYZ, XYZ_TO_SRGB};
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: All matrices take CIE 1931 XYZ (Y = 1.0). Stockman-Sharpe fundamentals are
+// defined against the CIE 2006 observer; the CIE 170-2 matrix is applied to 1931 XYZ
+// here, which is the usual approximation.
+
+/// Cone response matrix used by `Lms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConeFundamentals {
+    /// Hunt-Pointer-Estevez, normalized to D65 (as in CIECAM02)
+    #[default]
+    HuntPointerEstevez,
+    /// CAT02 sharpened cone space (CIECAM02)
+    Cat02,
+    /// CAT16 sharpened cone space (CAM16)
+    Cat16,
+    /// Stockman-Sharpe 2° cone fundamentals (CIE 170-2)
+    StockmanSharpe,
+}
+
+const XYZ_TO_LMS_SS: Mat3 = Mat3([
+    [
+        0.21057581971238884,
+        0.8550976431611766,
+        -0.03969826519687406,
+    ],
+    [-0.41707637376969525, 1.177261096388501, 0.07862825141022504],
+    [0.0, 0.0, 0.5168350142160381],
+]);
+
+const LMS_SS_TO_XYZ: Mat3 = Mat3([
+    [1.94735469, -1.41445123, 0.36476327],
+    [0.68990272, 0.34832189, 0.0],
+    [0.0, 0.0, 1.93485343],
+]);
+
+impl ConeFundamentals {
+    /// XYZ to LMS matrix.
+    pub(crate) fn xyz_to_lms(&self) -> &'static Mat3 {
+        match self {
+            ConeFundamentals::HuntPointerEstevez => &M_HPE,
+            ConeFundamentals::Cat02 => &M_CAT02,
+            ConeFundamentals::Cat16 => &M16,
+            ConeFundamentals::StockmanSharpe => &XYZ_TO_LMS_SS,
+        }
+    }
+
+    /// LMS to XYZ matrix.
+    pub(crate) fn lms_to_xyz(&self) -> &'static Mat3 {
+        match self {
+            ConeFundamentals::HuntPointerEstevez => &M_HPE_INV,
+            ConeFundamentals::Cat02 => &M_CAT02_INV,
+            ConeFundamentals::Cat16 => &M16_INV,
+            ConeFundamentals::StockmanSharpe => &LMS_SS_TO_XYZ,
+        }
+    }
+}
+
+/// LMS: long, medium and short cone responses
+///
+/// # Fields
+/// - l, m, s: cone responses (white ≈ 1.0 for the normalized fundamentals)
+/// - alpha: opacity (0.0-1.0)
+/// - fundamentals: the cone response matrix the values are expressed in
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Lms {
+    pub l: f64,
+    pub m: f64,
+    pub s: f64,
+    pub alpha: f64,
+    pub fundamentals: ConeFundamentals,
+}
+
+impl_constructors!(
+    Lms { l, m, s }, alpha;
+    fundamentals: ConeFundamentals = ConeFundamentals::HuntPointerEstevez
+);
+
+impl Lms {
+    /// Convert a color using the given fundamentals.
+    pub fn from_color_with(color: &Color, fundamentals: ConeFundamentals) -> Self {
+        let xyz = SRGB_TO_XYZ.apply([color.r, color.g, color.b]);
+        let [l, m, s] = fundamentals.xyz_to_lms().apply(xyz);
+        Lms::with_alpha(l, m, s, color.a).fundamentals(fundamentals)
+    }
+
+    /// The same color expressed in other fundamentals.
+    pub fn to_fundamentals(&self, fundamentals: ConeFundamentals) -> Self {
+        Self::from_color_with(&self.to_color(), fundamentals)
+    }
+}
+
+impl ColorSpace for Lms {
+    fn to_color(&self) -> Color {
+        let xyz = self
+            .fundamentals
+            .lms_to_xyz()
+            .apply([self.l, self.m, self.s]);
+        let [r, g, b] = XYZ_TO_SRGB.apply(xyz);
+        Color::new(r, g, b, self.alpha)
+    }
+
+    /// Converts with Hunt-Pointer-Estevez; use `from_color_with` for the others.
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, ConeFundamentals::HuntPointerEstevez)
+    }
+}
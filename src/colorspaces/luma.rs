@@ -0,0 +1,86 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::srgb::Srgb;
+use crate::colorspaces::xyz::SRGB_TO_XYZ;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Rec.601 luma is a weighted sum of gamma-encoded components; it is taken on
+// sRGB-encoded values, as image libraries compute it, and decodes back through the sRGB
+// curve. The other derivations are linear, and a linear gray of that level converts back.
+// Rec.709 uses the rounded BT.709 coefficients, CIE Y the full-precision sRGB matrix row.
+
+/// How `Luma` reduces a color to a single channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LumaDerivation {
+    /// BT.709 relative luminance of linear RGB (0.2126, 0.7152, 0.0722)
+    #[default]
+    Rec709,
+    /// BT.601 luma of sRGB-encoded RGB (0.299, 0.587, 0.114)
+    Rec601,
+    /// CIE Y of linear sRGB
+    CieY,
+    /// Unweighted mean of the linear components
+    Average,
+}
+
+impl LumaDerivation {
+    fn weights(&self) -> [f64; 3] {
+        match self {
+            LumaDerivation::Rec709 => [0.2126, 0.7152, 0.0722],
+            LumaDerivation::Rec601 => [0.299, 0.587, 0.114],
+            LumaDerivation::CieY => SRGB_TO_XYZ.0[1],
+            LumaDerivation::Average => [1.0 / 3.0; 3],
+        }
+    }
+
+    /// Whether the weights apply to sRGB-encoded rather than linear components.
+    fn is_encoded(&self) -> bool {
+        matches!(self, LumaDerivation::Rec601)
+    }
+}
+
+/// Luma: a single gray channel with alpha
+///
+/// # Fields
+/// - l: gray level (0.0-1.0), linear or encoded according to `derivation`
+/// - alpha: opacity (0.0-1.0)
+/// - derivation: how the level was computed, and so how it converts back
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Luma {
+    pub l: f64,
+    pub alpha: f64,
+    pub derivation: LumaDerivation,
+}
+
+impl_constructors!(Luma { l }, alpha; derivation: LumaDerivation = LumaDerivation::Rec709);
+
+impl Luma {
+    /// Reduce a color to gray with the given derivation.
+    pub fn from_color_with(color: &Color, derivation: LumaDerivation) -> Self {
+        let rgb = if derivation.is_encoded() {
+            let Srgb { r, g, b, .. } = Srgb::from_color(color);
+            [r, g, b]
+        } else {
+            [color.r, color.g, color.b]
+        };
+        let [wr, wg, wb] = derivation.weights();
+        let l = wr * rgb[0] + wg * rgb[1] + wb * rgb[2];
+        Luma::with_alpha(l, color.a).derivation(derivation)
+    }
+}
+
+impl ColorSpace for Luma {
+    /// The neutral color at this gray level.
+    fn to_color(&self) -> Color {
+        if self.derivation.is_encoded() {
+            Srgb::with_alpha(self.l, self.l, self.l, self.alpha).to_color()
+        } else {
+            Color::new(self.l, self.l, self.l, self.alpha)
+        }
+    }
+
+    /// Converts with Rec.709 relative luminance; use `from_color_with` for the others.
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, LumaDerivation::Rec709)
+    }
+}
@@ -1,5 +1,8 @@
 use crate::colorspaces::color::Color;
 use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::xyz::{
+    SRGB_TO_XYZ, SRGB_TO_XYZ_D50, WHITE_D50, XYZ_D50_TO_SRGB, XYZ_TO_SRGB,
+};
 use serde::{Deserialize, Serialize};
 
 /// CIE L*u*v* (D65)
@@ -11,13 +14,17 @@ pub struct Luv {
     pub alpha: f64,
 }
 
-const XN_LUV: f64 = 0.95047;
-const YN_LUV: f64 = 1.0;
-const ZN_LUV: f64 = 1.08883;
+impl_constructors!(Luv { l, u, v }, alpha);
+
+// Reference white is D65 as the sRGB matrices define it (x = 0.3127, y = 0.3290), so
+// linear sRGB white has u* = v* = 0 exactly
+pub(crate) const XN_LUV: f64 = 0.9504559270516716;
+pub(crate) const YN_LUV: f64 = 1.0;
+pub(crate) const ZN_LUV: f64 = 1.0890577507598784;
 
 const EPSILON: f64 = 1e-10;
 
-fn u_prime(x: f64, y: f64, z: f64) -> f64 {
+pub(crate) fn u_prime(x: f64, y: f64, z: f64) -> f64 {
     let denom = x + 15.0 * y + 3.0 * z;
     if denom.abs() < EPSILON {
         0.0
@@ -25,7 +32,7 @@ fn u_prime(x: f64, y: f64, z: f64) -> f64 {
         4.0 * x / denom
     }
 }
-fn v_prime(x: f64, y: f64, z: f64) -> f64 {
+pub(crate) fn v_prime(x: f64, y: f64, z: f64) -> f64 {
     let denom = x + 15.0 * y + 3.0 * z;
     if denom.abs() < EPSILON {
         0.0
@@ -34,70 +41,123 @@ fn v_prime(x: f64, y: f64, z: f64) -> f64 {
     }
 }
 
+/// Relative luminance Y for lightness L*.
+fn lightness_to_y(l: f64) -> f64 {
+    if l > 8.0 {
+        ((l + 16.0) / 116.0).powi(3)
+    } else {
+        l / 903.3
+    }
+}
+
+/// Lines `v = slope · u + intercept` in the u*v* plane at lightness `l` where one linear
+/// sRGB component is 0 or 1. Together they bound the sRGB gamut's slice at that lightness.
+pub(crate) fn srgb_bounds(l: f64) -> [(f64, f64); 6] {
+    let y = lightness_to_y(l);
+    let un = u_prime(XN_LUV, YN_LUV, ZN_LUV);
+    let vn = v_prime(XN_LUV, YN_LUV, ZN_LUV);
+    let mut lines = [(0.0, 0.0); 6];
+    for (i, line) in lines.iter_mut().enumerate() {
+        let [m1, m2, m3] = XYZ_TO_SRGB.0[i / 2];
+        let t = (i % 2) as f64;
+        // Component = t, with X and Z written in terms of u' and v' at fixed Y, is linear
+        // in u' and v'; substitute u' = u / 13L + u'n and likewise for v'
+        let ku = (9.0 * m1 - 3.0 * m3) * y;
+        let kv = (4.0 * m2 - 20.0 * m3) * y - 4.0 * t;
+        let k0 = ku * un + kv * vn + 12.0 * m3 * y;
+        *line = (-ku / kv, -13.0 * l * k0 / kv);
+    }
+    lines
+}
+
+/// L*u*v* of an XYZ triple relative to the white `[xn, yn, zn]`.
+fn xyz_to_luv([x, y, z]: [f64; 3], [xn, yn, zn]: [f64; 3]) -> [f64; 3] {
+    let yr = y / yn;
+    let l = if yr > 0.008856 {
+        116.0 * yr.powf(1.0 / 3.0) - 16.0
+    } else {
+        903.3 * yr
+    };
+    let ur_p = u_prime(x, y, z);
+    let vr_p = v_prime(x, y, z);
+    let ur_n = u_prime(xn, yn, zn);
+    let vr_n = v_prime(xn, yn, zn);
+
+    [l, 13.0 * l * (ur_p - ur_n), 13.0 * l * (vr_p - vr_n)]
+}
+
+/// XYZ of an L*u*v* triple relative to the white `[xn, yn, zn]`.
+fn luv_to_xyz([l, u, v]: [f64; 3], [xn, yn, zn]: [f64; 3]) -> [f64; 3] {
+    // Reference white u', v'
+    let up_ref = u_prime(xn, yn, zn);
+    let vp_ref = v_prime(xn, yn, zn);
+
+    let yr = lightness_to_y(l) * yn;
+
+    let up = if l.abs() < EPSILON {
+        up_ref
+    } else {
+        u / (13.0 * l) + up_ref
+    };
+    let vp = if l.abs() < EPSILON {
+        vp_ref
+    } else {
+        v / (13.0 * l) + vp_ref
+    };
+
+    let vp_denom = (4.0 * vp).abs().max(EPSILON);
+
+    [
+        yr * 9.0 * up / vp_denom,
+        yr,
+        yr * (12.0 - 3.0 * up - 20.0 * vp) / vp_denom,
+    ]
+}
+
 impl ColorSpace for Luv {
     fn to_color(&self) -> Color {
-        // Reference white u', v'
-        let up_ref = u_prime(XN_LUV, YN_LUV, ZN_LUV);
-        let vp_ref = v_prime(XN_LUV, YN_LUV, ZN_LUV);
-
-        let l = self.l;
-        let u = self.u;
-        let v = self.v;
-
-        let yr = if l > 8.0 {
-            ((l + 16.0) / 116.0).powi(3)
-        } else {
-            l / 903.3
-        };
-
-        let up = if l.abs() < EPSILON {
-            up_ref
-        } else {
-            u / (13.0 * l) + up_ref
-        };
-        let vp = if l.abs() < EPSILON {
-            vp_ref
-        } else {
-            v / (13.0 * l) + vp_ref
-        };
-
-        let vp_denom = (4.0 * vp).abs().max(EPSILON);
-
-        let x = yr * 9.0 * up / vp_denom;
-        let y = yr;
-        let z = yr * (12.0 - 3.0 * up - 20.0 * vp) / vp_denom;
-
-        let r = 3.2406 * x - 1.5372 * y - 0.4986 * z;
-        let g = -0.9689 * x + 1.8758 * y + 0.0415 * z;
-        let b = 0.0557 * x - 0.2040 * y + 1.0570 * z;
+        let xyz = luv_to_xyz([self.l, self.u, self.v], [XN_LUV, YN_LUV, ZN_LUV]);
+        let [r, g, b] = XYZ_TO_SRGB.apply(xyz);
+        Color::new(r, g, b, self.alpha)
+    }
 
+    fn from_color(c: &Color) -> Self {
+        let xyz = SRGB_TO_XYZ.apply([c.r, c.g, c.b]);
+        let [l, u, v] = xyz_to_luv(xyz, [XN_LUV, YN_LUV, ZN_LUV]);
+        Luv::with_alpha(l, u, v, c.a)
+    }
+}
+
+/// CIE L*u*v* relative to the ICC D50 white
+///
+/// Colors are adapted between the hub's D65 and D50 with Bradford, so D65 white maps to
+/// L* = 100, u* = v* = 0.
+///
+/// # Fields
+/// - l: lightness (0.0-100.0)
+/// - u: green-red axis (unbounded, ~-100.0-175.0)
+/// - v: blue-yellow axis (unbounded, ~-140.0-110.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LuvD50 {
+    pub l: f64,
+    pub u: f64,
+    pub v: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(LuvD50 { l, u, v }, alpha);
+
+impl ColorSpace for LuvD50 {
+    fn to_color(&self) -> Color {
+        let xyz = luv_to_xyz([self.l, self.u, self.v], WHITE_D50);
+        let [r, g, b] = XYZ_D50_TO_SRGB.apply(xyz);
         Color::new(r, g, b, self.alpha)
     }
 
     fn from_color(c: &Color) -> Self {
-        let x = 0.4124 * c.r + 0.3576 * c.g + 0.1805 * c.b;
-        let y = 0.2126 * c.r + 0.7152 * c.g + 0.0722 * c.b;
-        let z = 0.0193 * c.r + 0.1192 * c.g + 0.9505 * c.b;
-
-        let yr = y / YN_LUV;
-        let l = if yr > 0.008856 {
-            116.0 * yr.powf(1.0 / 3.0) - 16.0
-        } else {
-            903.3 * yr
-        };
-        let ur_p = u_prime(x, y, z);
-        let vr_p = v_prime(x, y, z);
-        let ur_n = u_prime(XN_LUV, YN_LUV, ZN_LUV);
-        let vr_n = v_prime(XN_LUV, YN_LUV, ZN_LUV);
-
-        let u = 13.0 * l * (ur_p - ur_n);
-        let v = 13.0 * l * (vr_p - vr_n);
-
-        Luv {
-            l,
-            u,
-            v,
-            alpha: c.a,
-        }
+        let xyz = SRGB_TO_XYZ_D50.apply([c.r, c.g, c.b]);
+        let [l, u, v] = xyz_to_luv(xyz, WHITE_D50);
+        LuvD50::with_alpha(l, u, v, c.a)
     }
 }
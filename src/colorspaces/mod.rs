@@ -1,21 +1,118 @@
+/// Uniform constructors and builder-style setters for a color space struct.
+///
+/// `impl_constructors!(Oklch { l, c, h }, alpha)` generates `Oklch::new(l, c, h)`
+/// (opaque), `Oklch::with_alpha(l, c, h, alpha)` and the setters `.l(..)`, `.c(..)`,
+/// `.h(..)` and `.alpha(..)`. Extra fields are listed after the alpha field with
+/// the value `new` fills in, and get setters of their own.
+macro_rules! impl_constructors {
+    ($t:ident { $($field:ident),+ }, $alpha:ident $(; $($extra:ident: $ty:ty = $default:expr),+)?) => {
+        impl $t {
+            /// Construct an opaque color from its components.
+            pub fn new($($field: f64),+) -> Self {
+                Self::with_alpha($($field,)+ 1.0)
+            }
+
+            /// Construct a color from its components and alpha.
+            pub fn with_alpha($($field: f64,)+ alpha: f64) -> Self {
+                Self {
+                    $($field,)+
+                    $alpha: alpha,
+                    $($($extra: $default,)+)?
+                }
+            }
+
+            $(
+                #[doc = concat!("The same color with `", stringify!($field), "` replaced.")]
+                pub fn $field(self, $field: f64) -> Self {
+                    Self { $field, ..self }
+                }
+            )+
+
+            /// The same color with alpha replaced.
+            pub fn alpha(self, alpha: f64) -> Self {
+                Self { $alpha: alpha, ..self }
+            }
+
+            $($(
+                #[doc = concat!("The same color with `", stringify!($extra), "` replaced.")]
+                pub fn $extra(self, $extra: $ty) -> Self {
+                    Self { $extra, ..self }
+                }
+            )+)?
+        }
+    };
+}
+
 pub mod color;
 pub mod colorspace;
 pub mod hsl;
 pub mod hsv;
+pub mod luma;
 pub mod oklab;
 pub mod srgb;
 pub mod xyz;
 
 // wide‑gamut RGB
+pub mod aces;
 pub mod adobe_rgb;
+pub mod custom_rgb;
+pub mod dci_p3;
 pub mod display_p3;
+pub mod gamma_rgb;
+pub mod linear_adobe_rgb;
+pub mod linear_display_p3;
+pub mod linear_rec2020;
+pub mod linear_srgb;
+pub mod prophoto_rgb;
 pub mod rec2020;
+pub mod scrgb;
+
+// video
+pub mod rec2100_hlg;
+pub mod rec601;
+pub mod rec709;
+pub mod ycbcr;
+pub mod ycocg;
+pub mod yiq;
+pub mod ypbpr;
+
+// print
+pub mod cmy;
+pub mod cmyk;
 
 // perceptual & scientific
+pub mod cct;
+pub mod din99;
+pub mod hpluv;
+pub mod hsluv;
+pub mod hunter_lab;
+pub mod ictcp;
 pub mod lab;
 pub mod lch;
+pub mod lms;
 pub mod luv;
+pub mod munsell;
+pub mod okhsl;
+pub mod okhsv;
 pub mod oklch;
+pub mod oklrab;
+pub mod oklrch;
+pub mod prolab;
+pub mod srlab2;
+pub mod tsl;
+pub mod uv;
+pub mod uvw;
+pub mod xyb;
+pub mod xyy;
+
+// color appearance models
+pub mod cam16;
+pub mod ciecam02;
+pub mod hct;
+pub mod viewing_conditions;
+
+// artistic
+pub mod ryb;
 
 // CSS UI spaces
 pub mod hwb;
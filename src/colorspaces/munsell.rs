@@ -0,0 +1,455 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::lab::{lightness_from_y, y_from_lightness};
+use crate::colorspaces::lch::Lch;
+use crate::colorspaces::xyy::Xyy;
+use crate::colorspaces::xyz::Xyz;
+use crate::white_point::{Observer, WhitePoint};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+// NOTE: The `ColorSpace` impl is an approximation, not an interpolation of the 1943
+// renotation data. Value follows ASTM D1535 exactly (inverted by Newton's method). Hue is
+// mapped from the CIELAB (D65) hue angle through the approximate angles of the ten
+// principal hues at value 5, and chroma is C*ab / CHROMA_SCALE. Expect errors of a few
+// hue steps and around one chroma step, more for dark or very saturated colors; the
+// conversion is self-consistent, so values round-trip.
+//
+// `MunsellRenotation` converts through a renotation table instead, such as the
+// real-color subset (`real.dat`) of the 1943 renotation, relative to Illuminant C, and
+// adapts to and from the D65 hub with Bradford. The data is not bundled with the crate
+// yet, so the `ColorSpace` impl cannot use it; read the published file with `parse`
+// and call `Munsell::to_color_with` and `from_color_with`. Between
+// table entries, xy is interpolated linearly in chroma (from the neutral point at chroma
+// 0), in polar coordinates around the neutral point between the bracketing hues, and
+// linearly in value between the bracketing value planes; Y always follows ASTM D1535.
+// The inverse solves for hue and chroma by Newton's method, starting from the
+// approximation.
+
+const EPSILON: f64 = 1e-10;
+const NEWTON_ITERATIONS: usize = 16;
+
+/// Newton steps when inverting a renotation table
+const RENOTATION_ITERATIONS: usize = 64;
+/// xy distance at which an inverted table color counts as found
+const RENOTATION_TOLERANCE: f64 = 1e-10;
+/// xy distance from the neutral point below which a color counts as neutral
+const NEUTRAL_TOLERANCE: f64 = 1e-9;
+/// Finite-difference step in hue and chroma for the inversion's Jacobian
+const JACOBIAN_STEP: f64 = 1e-4;
+/// Largest hue and chroma change per inversion step, to keep Newton's method in the table
+const MAX_HUE_STEP: f64 = 5.0;
+const MAX_CHROMA_STEP: f64 = 2.0;
+
+/// CIELAB chroma per Munsell chroma step
+const CHROMA_SCALE: f64 = 5.0;
+
+/// Approximate CIELAB hue angle (degrees) of 5R, 5YR, 5Y, ..., 5RP at value 5
+const PRINCIPAL_HUE_ANGLES: [f64; 10] = [
+    24.0, 62.0, 92.0, 117.0, 157.0, 195.0, 232.0, 276.0, 317.0, 350.0,
+];
+
+const FAMILIES: [&str; 10] = ["R", "YR", "Y", "GY", "G", "BG", "B", "PB", "P", "RP"];
+
+/// Munsell hue, value and chroma (approximate, see the module notes)
+///
+/// # Fields
+/// - hue: hue number on the 100-step circle (0.0-100.0): 5.0 is 5R, 15.0 is 5YR, ...,
+///   95.0 is 5RP, and 0.0 / 100.0 is 10RP
+/// - value: lightness (0.0-10.0)
+/// - chroma: saturation (0.0 for neutrals, ~2.0-20.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Munsell {
+    pub hue: f64,
+    pub value: f64,
+    pub chroma: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Munsell { hue, value, chroma }, alpha);
+
+/// ASTM D1535 luminance factor (white = 100 for smoked MgO) of a Munsell value.
+fn d1535(v: f64) -> f64 {
+    v * (1.1914 + v * (-0.22533 + v * (0.23352 + v * (-0.020484 + v * 0.00081939))))
+}
+
+fn d1535_slope(v: f64) -> f64 {
+    1.1914 + v * (-0.45066 + v * (0.70056 + v * (-0.081936 + v * 0.00409695)))
+}
+
+/// Munsell value for a relative luminance (white = 1.0).
+fn value_from_y(y: f64) -> f64 {
+    let target = y.max(0.0) * 100.0;
+    let mut v = 10.0 * y.max(0.0).sqrt();
+    for _ in 0..NEWTON_ITERATIONS {
+        let step = (d1535(v) - target) / d1535_slope(v);
+        v -= step;
+        if step.abs() < EPSILON {
+            break;
+        }
+    }
+    v
+}
+
+/// Piecewise-linear map between the Munsell hue circle (0-100) and CIELAB hue angle.
+fn hue_to_angle(hue: f64) -> f64 {
+    let x = (hue - 5.0).rem_euclid(100.0) / 10.0;
+    let i = x.floor() as usize % 10;
+    let next = PRINCIPAL_HUE_ANGLES[(i + 1) % 10] + if i == 9 { 360.0 } else { 0.0 };
+    let a = PRINCIPAL_HUE_ANGLES[i];
+    (a + (next - a) * x.fract()).rem_euclid(360.0)
+}
+
+fn angle_to_hue(angle: f64) -> f64 {
+    let angle = angle.rem_euclid(360.0);
+    for i in 0..10 {
+        let a = PRINCIPAL_HUE_ANGLES[i];
+        let next = PRINCIPAL_HUE_ANGLES[(i + 1) % 10] + if i == 9 { 360.0 } else { 0.0 };
+        // The last segment wraps past 360°
+        let t = if angle < a { angle + 360.0 } else { angle };
+        if t >= a && t < next {
+            return (5.0 + 10.0 * (i as f64 + (t - a) / (next - a))).rem_euclid(100.0);
+        }
+    }
+    0.0
+}
+
+impl Munsell {
+    /// Hue family (`"R"`, `"YR"`, ..., `"RP"`) and the hue step within it (0.0-10.0].
+    pub fn hue_family(&self) -> (&'static str, f64) {
+        let hue = self.hue.rem_euclid(100.0);
+        let step = hue % 10.0;
+        let family = (hue / 10.0).floor() as usize % 10;
+        if step < EPSILON {
+            // 0R is written as 10RP
+            (FAMILIES[(family + 9) % 10], 10.0)
+        } else {
+            (FAMILIES[family], step)
+        }
+    }
+}
+
+impl fmt::Display for Munsell {
+    /// Munsell notation, e.g. `5.0R 4.0/14.0`, or `N 5.0/` for neutrals.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.chroma < 0.05 {
+            return write!(f, "N {:.1}/", self.value);
+        }
+        let (family, step) = self.hue_family();
+        write!(f, "{step:.1}{family} {:.1}/{:.1}", self.value, self.chroma)
+    }
+}
+
+impl ColorSpace for Munsell {
+    fn to_color(&self) -> Color {
+        let y = d1535(self.value.max(0.0)) / 100.0;
+        let l = lightness_from_y(y);
+        let c = self.chroma.max(0.0) * CHROMA_SCALE;
+        Lch::with_alpha(l, c, hue_to_angle(self.hue), self.alpha).to_color()
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let Lch { l, c, h, a: alpha } = Lch::from_color(color);
+        let value = value_from_y(y_from_lightness(l));
+        let chroma = c / CHROMA_SCALE;
+        let hue = if chroma < EPSILON {
+            0.0
+        } else {
+            angle_to_hue(h)
+        };
+        Munsell::with_alpha(hue, value, chroma, alpha)
+    }
+}
+
+impl Munsell {
+    /// Convert through a renotation table; `None` outside the table.
+    pub fn to_color_with(&self, table: &MunsellRenotation) -> Option<Color> {
+        let xyy = table.xyy(self)?;
+        Some(Xyz::from(xyy).white(WhitePoint::C).to_color())
+    }
+
+    /// Convert a color through a renotation table; `None` outside the table.
+    pub fn from_color_with(color: &Color, table: &MunsellRenotation) -> Option<Self> {
+        let xyz = Xyz::from_color_with(color, WhitePoint::C);
+        table.from_xyy(&illuminant_c_xyy(&xyz))
+    }
+}
+
+/// xyY of XYZ relative to Illuminant C, without the adaptation to D65 of `From<Xyz>`.
+fn illuminant_c_xyy(xyz: &Xyz) -> Xyy {
+    let sum = xyz.x + xyz.y + xyz.z;
+    if sum.abs() < EPSILON {
+        let [x, y] = WhitePoint::C.chromaticity(Observer::Cie1931);
+        return Xyy::with_alpha(x, y, xyz.y, xyz.alpha);
+    }
+    Xyy::with_alpha(xyz.x / sum, xyz.y / sum, xyz.y, xyz.alpha)
+}
+
+/// Errors raised while reading a renotation table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenotationError {
+    /// The text held no table rows
+    Empty,
+    /// A row that is not `hue value chroma x y [Y]`, by line number (from 1)
+    InvalidRow { line: usize, reason: String },
+}
+
+impl fmt::Display for RenotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenotationError::Empty => write!(f, "empty Munsell renotation table"),
+            RenotationError::InvalidRow { line, reason } => {
+                write!(f, "invalid Munsell renotation row on line {line}: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenotationError {}
+
+/// (chroma, xy) of the chips at one table hue and value, ascending by chroma
+type Chips = Vec<(f64, [f64; 2])>;
+
+/// Munsell renotation data: the CIE xy chromaticity (Illuminant C, 2° observer) of
+/// Munsell chips on a grid of hues, values and chromas.
+///
+/// `parse` reads the whitespace-separated `hue value chroma x y Y` rows of the published
+/// renotation files, such as `real.dat`, with hues written as `2.5R`, `10RP` and so on.
+/// Interpolation is described in the module notes.
+#[derive(Debug, Clone)]
+pub struct MunsellRenotation {
+    /// Table hues on the 100-step circle, ascending
+    hues: Vec<f64>,
+    /// Table values, ascending
+    values: Vec<f64>,
+    /// Chips by (hue index, value index)
+    chips: HashMap<(usize, usize), Chips>,
+    /// Illuminant C chromaticity, the neutral point
+    neutral: [f64; 2],
+}
+
+/// Hue number on the 100-step circle for notation such as `2.5R` or `10RP`.
+fn parse_hue(s: &str) -> Option<f64> {
+    let split = s.find(|c: char| c.is_ascii_alphabetic())?;
+    let (step, family) = s.split_at(split);
+    let step: f64 = step.parse().ok()?;
+    let family = FAMILIES.iter().position(|f| *f == family)?;
+    (step > 0.0 && step <= 10.0).then(|| (family as f64 * 10.0 + step).rem_euclid(100.0))
+}
+
+/// Key for grouping table coordinates that differ only by rounding.
+fn grid_key(v: f64) -> i64 {
+    (v * 1000.0).round() as i64
+}
+
+impl MunsellRenotation {
+    /// Read a renotation table. Blank lines, `#` comments and a leading header row are
+    /// skipped; the Y column, when present, is ignored in favor of ASTM D1535.
+    pub fn parse(text: &str) -> Result<Self, RenotationError> {
+        let mut rows: BTreeMap<(i64, i64), Chips> = BTreeMap::new();
+        let (mut hues, mut values) = (BTreeMap::new(), BTreeMap::new());
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: &str| RenotationError::InvalidRow {
+                line: i + 1,
+                reason: reason.to_string(),
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let numbers: Option<Vec<f64>> = fields.iter().skip(1).map(|f| f.parse().ok()).collect();
+            let Some(numbers) = numbers.filter(|n| n.iter().all(|v: &f64| v.is_finite())) else {
+                if rows.is_empty() {
+                    // A header row
+                    continue;
+                }
+                return Err(invalid("expected numbers after the hue"));
+            };
+            let &[value, chroma, x, y, ..] = numbers.as_slice() else {
+                return Err(invalid("expected hue, value, chroma, x and y"));
+            };
+            let hue = parse_hue(fields[0]).ok_or_else(|| invalid("unrecognized hue"))?;
+            if value <= 0.0 || chroma <= 0.0 {
+                return Err(invalid("value and chroma must be positive"));
+            }
+            if !(x > 0.0 && y > 0.0 && x + y < 1.0) {
+                return Err(invalid("chromaticity is outside the spectrum locus"));
+            }
+            hues.insert(grid_key(hue), hue);
+            values.insert(grid_key(value), value);
+            rows.entry((grid_key(hue), grid_key(value)))
+                .or_default()
+                .push((chroma, [x, y]));
+        }
+        if rows.is_empty() {
+            return Err(RenotationError::Empty);
+        }
+        let hue_index: HashMap<i64, usize> =
+            hues.keys().enumerate().map(|(i, k)| (*k, i)).collect();
+        let value_index: HashMap<i64, usize> =
+            values.keys().enumerate().map(|(i, k)| (*k, i)).collect();
+        let chips = rows
+            .into_iter()
+            .map(|((h, v), mut chips)| {
+                chips.sort_by(|a, b| a.0.total_cmp(&b.0));
+                ((hue_index[&h], value_index[&v]), chips)
+            })
+            .collect();
+        Ok(Self {
+            hues: hues.into_values().collect(),
+            values: values.into_values().collect(),
+            chips,
+            neutral: WhitePoint::C.chromaticity(Observer::Cie1931),
+        })
+    }
+
+    /// Number of chips in the table.
+    pub fn len(&self) -> usize {
+        self.chips.values().map(Vec::len).sum()
+    }
+
+    /// Whether the table has no chips; never true for a parsed table.
+    pub fn is_empty(&self) -> bool {
+        self.chips.is_empty()
+    }
+
+    /// CIE xyY (Illuminant C, white Y = 1.0) of `munsell`, or `None` if it falls outside
+    /// the table.
+    pub fn xyy(&self, munsell: &Munsell) -> Option<Xyy> {
+        let [x, y] = self.chromaticity(munsell.hue, munsell.value, munsell.chroma)?;
+        let luminance = d1535(munsell.value) / 100.0;
+        Some(Xyy::with_alpha(x, y, luminance, munsell.alpha))
+    }
+
+    /// The Munsell notation of an xyY color (Illuminant C, white Y = 1.0), or `None` if
+    /// it falls outside the table.
+    pub fn from_xyy(&self, xyy: &Xyy) -> Option<Munsell> {
+        let value = value_from_y(xyy.luminance);
+        let target = [xyy.x, xyy.y];
+        let offset = |p: [f64; 2]| [p[0] - target[0], p[1] - target[1]];
+        if (target[0] - self.neutral[0]).hypot(target[1] - self.neutral[1]) < NEUTRAL_TOLERANCE {
+            self.chromaticity(0.0, value, 0.0)?;
+            return Some(Munsell::with_alpha(0.0, value, 0.0, xyy.alpha));
+        }
+        let color = Xyz::from(*xyy).white(WhitePoint::C).to_color();
+        let guess = Munsell::from_color(&color);
+        let (mut hue, mut chroma) = (guess.hue, guess.chroma.max(JACOBIAN_STEP));
+        // The approximation can start beyond the table's edge at this hue
+        let mut f = loop {
+            match self.chromaticity(hue, value, chroma) {
+                Some(p) => break offset(p),
+                None if chroma > JACOBIAN_STEP => chroma *= 0.75,
+                None => return None,
+            }
+        };
+        for _ in 0..RENOTATION_ITERATIONS {
+            if f[0].hypot(f[1]) < RENOTATION_TOLERANCE {
+                return Some(Munsell::with_alpha(
+                    hue.rem_euclid(100.0),
+                    value,
+                    chroma,
+                    xyy.alpha,
+                ));
+            }
+            // Backward differences where a forward step leaves the table
+            let partial = |dh: f64, dc: f64| {
+                let step = |s: f64| {
+                    self.chromaticity(hue + dh * s, value, chroma + dc * s)
+                        .map(|p| [(p[0] - target[0] - f[0]) / s, (p[1] - target[1] - f[1]) / s])
+                };
+                step(JACOBIAN_STEP).or_else(|| step(-JACOBIAN_STEP))
+            };
+            let (dh, dc) = (partial(1.0, 0.0)?, partial(0.0, 1.0)?);
+            let det = dh[0] * dc[1] - dc[0] * dh[1];
+            if det.abs() < EPSILON * EPSILON {
+                return None;
+            }
+            let step_h = (-(f[0] * dc[1] - dc[0] * f[1]) / det).clamp(-MAX_HUE_STEP, MAX_HUE_STEP);
+            let step_c =
+                (-(dh[0] * f[1] - f[0] * dh[1]) / det).clamp(-MAX_CHROMA_STEP, MAX_CHROMA_STEP);
+            // Halve the step until it stays in the table and in nonnegative chroma
+            let mut scale = 1.0;
+            loop {
+                let (h, c) = (hue + scale * step_h, (chroma + scale * step_c).max(0.0));
+                if let Some(p) = self.chromaticity(h, value, c) {
+                    (hue, chroma, f) = (h, c, offset(p));
+                    break;
+                }
+                scale *= 0.5;
+                if scale < EPSILON {
+                    return None;
+                }
+            }
+        }
+        (f[0].hypot(f[1]) < RENOTATION_TOLERANCE.sqrt())
+            .then(|| Munsell::with_alpha(hue.rem_euclid(100.0), value, chroma, xyy.alpha))
+    }
+
+    /// Interpolated xy at (hue, value, chroma).
+    fn chromaticity(&self, hue: f64, value: f64, chroma: f64) -> Option<[f64; 2]> {
+        let (first, last) = (*self.values.first()?, *self.values.last()?);
+        if chroma < EPSILON {
+            return (value >= first - EPSILON && value <= last + EPSILON).then_some(self.neutral);
+        }
+        if value < first - EPSILON || value > last + EPSILON {
+            return None;
+        }
+        let j = self.values.partition_point(|v| *v < value - EPSILON);
+        let planes = if (self.values[j] - value).abs() <= EPSILON {
+            (j, j, 0.0)
+        } else {
+            let (v0, v1) = (self.values[j - 1], self.values[j]);
+            (j - 1, j, (value - v0) / (v1 - v0))
+        };
+        let p0 = self.on_value_plane(hue, planes.0, chroma)?;
+        if planes.2 == 0.0 {
+            return Some(p0);
+        }
+        let p1 = self.on_value_plane(hue, planes.1, chroma)?;
+        let t = planes.2;
+        Some([p0[0] + (p1[0] - p0[0]) * t, p0[1] + (p1[1] - p0[1]) * t])
+    }
+
+    /// Interpolated xy at `hue` and `chroma` on the table value `values[j]`.
+    fn on_value_plane(&self, hue: f64, j: usize, chroma: f64) -> Option<[f64; 2]> {
+        let hue = hue.rem_euclid(100.0);
+        let n = self.hues.len();
+        let k = self.hues.partition_point(|h| *h < hue - EPSILON);
+        if k < n && (self.hues[k] - hue).abs() <= EPSILON {
+            return self.on_table_hue(k, j, chroma);
+        }
+        // Bracketing table hues, wrapping around the circle
+        let (i0, i1) = ((k + n - 1) % n, k % n);
+        let span = (self.hues[i1] - self.hues[i0]).rem_euclid(100.0);
+        let span = if span < EPSILON { 100.0 } else { span };
+        let t = (hue - self.hues[i0]).rem_euclid(100.0) / span;
+        let (p0, p1) = (
+            self.on_table_hue(i0, j, chroma)?,
+            self.on_table_hue(i1, j, chroma)?,
+        );
+        let [nx, ny] = self.neutral;
+        let polar = |p: [f64; 2]| ((p[0] - nx).hypot(p[1] - ny), (p[1] - ny).atan2(p[0] - nx));
+        let ((r0, a0), (r1, a1)) = (polar(p0), polar(p1));
+        let da = (a1 - a0 + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU)
+            - std::f64::consts::PI;
+        let (r, a) = (r0 + (r1 - r0) * t, a0 + da * t);
+        Some([nx + r * a.cos(), ny + r * a.sin()])
+    }
+
+    /// Interpolated xy at `chroma` on the table hue `hues[i]` and value `values[j]`.
+    fn on_table_hue(&self, i: usize, j: usize, chroma: f64) -> Option<[f64; 2]> {
+        let chips = self.chips.get(&(i, j))?;
+        let k = chips.partition_point(|(c, _)| *c < chroma - EPSILON);
+        let &(c1, p1) = chips.get(k)?;
+        let (c0, p0) = if k == 0 {
+            (0.0, self.neutral)
+        } else {
+            chips[k - 1]
+        };
+        let t = ((chroma - c0) / (c1 - c0)).clamp(0.0, 1.0);
+        Some([p0[0] + (p1[0] - p0[0]) * t, p0[1] + (p1[1] - p0[1]) * t])
+    }
+}
@@ -0,0 +1,124 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::oklab::{
+    Oklab, cusp_slopes, find_cusp, find_gamut_intersection, toe, toe_inv,
+};
+use crate::colorspaces::oklch::ACHROMATIC_CHROMA;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Follows Ottosson's reference implementation, with hue in degrees rather than
+// turns and linear RGB in place of sRGB-encoded values. Saturation is relative to the
+// sRGB gamut, so out-of-gamut inputs give s > 1 and do not round-trip exactly. Grays
+// keep a chroma of about 4e-8 from Oklab's matrices, which the shrinking gamut near black
+// and white would blow up into real saturation, so chroma below Oklch's achromatic
+// threshold counts as gray.
+
+const EPSILON: f64 = 1e-10;
+
+/// Okhsl: HSL-like parameterization of Oklab over the sRGB gamut
+///
+/// # Fields
+/// - h: hue angle in degrees (0.0-360.0), the same as Oklch's
+/// - s: saturation (0.0-1.0), 1.0 on the sRGB gamut boundary
+/// - l: lightness estimate Lr (0.0-1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Okhsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Okhsl { h, s, l }, alpha);
+
+/// Smooth approximation of the cusp slopes (S, T) for the hue (a_, b_).
+fn mid_slopes(a_: f64, b_: f64) -> (f64, f64) {
+    let s = 0.11516993
+        + 1.0
+            / (7.44778970
+                + 4.15901240 * b_
+                + a_ * (-2.19557347
+                    + 1.75198401 * b_
+                    + a_ * (-2.13704948 - 10.02301043 * b_
+                        + a_ * (-4.24894561 + 5.38770819 * b_ + 4.69891013 * a_))));
+    let t = 0.11239642
+        + 1.0
+            / (1.61320320 - 0.68124379 * b_
+                + a_ * (0.40370612
+                    + 0.90148123 * b_
+                    + a_ * (-0.27087943
+                        + 0.61223990 * b_
+                        + a_ * (0.00299215 - 0.45399568 * b_ - 0.14661872 * a_))));
+    (s, t)
+}
+
+/// Chroma anchors (C0, Cmid, Cmax) that saturation 0.8 and 1.0 map to, at lightness `l`.
+fn chroma_anchors(l: f64, a_: f64, b_: f64) -> (f64, f64, f64) {
+    let cusp = find_cusp(a_, b_);
+    let c_max = find_gamut_intersection(a_, b_, l, 1.0, l, cusp);
+    let (s_max, t_max) = cusp_slopes(cusp);
+    // Compensate for the curved upper part of the gamut
+    let k = c_max / (l * s_max).min((1.0 - l) * t_max);
+
+    // Soft minimums instead of a sharp triangle keep chroma smooth across the cusp
+    let (s_mid, t_mid) = mid_slopes(a_, b_);
+    let (ca, cb) = (l * s_mid, (1.0 - l) * t_mid);
+    let c_mid = 0.9 * k * (1.0 / (1.0 / ca.powi(4) + 1.0 / cb.powi(4))).sqrt().sqrt();
+
+    // Hue-independent shape, roughly the average of the cusp slopes
+    let (ca, cb) = (l * 0.4, (1.0 - l) * 0.8);
+    let c_0 = (1.0 / (1.0 / (ca * ca) + 1.0 / (cb * cb))).sqrt();
+    (c_0, c_mid, c_max)
+}
+
+const MID: f64 = 0.8;
+
+impl ColorSpace for Okhsl {
+    fn to_color(&self) -> Color {
+        if self.l >= 1.0 - EPSILON {
+            return Color::new(1.0, 1.0, 1.0, self.alpha);
+        }
+        if self.l <= EPSILON {
+            return Color::new(0.0, 0.0, 0.0, self.alpha);
+        }
+        let (b_, a_) = self.h.to_radians().sin_cos();
+        let l = toe_inv(self.l);
+        let (c_0, c_mid, c_max) = chroma_anchors(l, a_, b_);
+        let s = self.s;
+        let c = if s < MID {
+            let t = s / MID;
+            let k1 = MID * c_0;
+            let k2 = 1.0 - k1 / c_mid;
+            t * k1 / (1.0 - k2 * t)
+        } else {
+            let t = (s - MID) / (1.0 - MID);
+            let k1 = (1.0 - MID) * c_mid * c_mid / (MID * MID * c_0);
+            let k2 = 1.0 - k1 / (c_max - c_mid);
+            c_mid + t * k1 / (1.0 - k2 * t)
+        };
+        Oklab::with_alpha(l, c * a_, c * b_, self.alpha).to_color()
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let Oklab { l, a, b, alpha } = Oklab::from_color(color);
+        let c = (a * a + b * b).sqrt();
+        if c < ACHROMATIC_CHROMA || l <= EPSILON || l >= 1.0 - EPSILON {
+            return Okhsl::with_alpha(0.0, 0.0, toe(l), alpha);
+        }
+        let (a_, b_) = (a / c, b / c);
+        let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+        let (c_0, c_mid, c_max) = chroma_anchors(l, a_, b_);
+        let s = if c < c_mid {
+            let k1 = MID * c_0;
+            let k2 = 1.0 - k1 / c_mid;
+            MID * c / (k1 + k2 * c)
+        } else {
+            let k1 = (1.0 - MID) * c_mid * c_mid / (MID * MID * c_0);
+            let k2 = 1.0 - k1 / (c_max - c_mid);
+            let t = (c - c_mid) / (k1 + k2 * (c - c_mid));
+            MID + (1.0 - MID) * t
+        };
+        Okhsl::with_alpha(h, s, toe(l), alpha)
+    }
+}
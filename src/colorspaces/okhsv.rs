@@ -0,0 +1,95 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::oklab::{Oklab, cusp_slopes, find_cusp, toe, toe_inv};
+use crate::colorspaces::oklch::ACHROMATIC_CHROMA;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Follows Ottosson's reference implementation, with hue in degrees rather than
+// turns and linear RGB in place of sRGB-encoded values. Saturation and value are
+// relative to the sRGB gamut, so out-of-gamut inputs do not round-trip exactly.
+
+const EPSILON: f64 = 1e-10;
+
+/// Okhsv: HSV-like parameterization of Oklab over the sRGB gamut
+///
+/// # Fields
+/// - h: hue angle in degrees (0.0-360.0), the same as Oklch's
+/// - s: saturation (0.0-1.0), 1.0 on the sRGB gamut boundary
+/// - v: value (0.0-1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Okhsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Okhsv { h, s, v }, alpha);
+
+/// Saturation of the triangle's lower edge that `s` is measured from
+const S_0: f64 = 0.5;
+
+/// Factor that scales (L, C) on the toe-compensated v = 1 line onto the gamut's curved top.
+fn top_scale(l_vt: f64, c_vt: f64, a_: f64, b_: f64) -> f64 {
+    let rgb = Oklab::new(l_vt, a_ * c_vt, b_ * c_vt).to_color();
+    (1.0 / rgb.r.max(rgb.g).max(rgb.b).max(0.0)).cbrt()
+}
+
+impl ColorSpace for Okhsv {
+    fn to_color(&self) -> Color {
+        if self.v <= EPSILON {
+            return Color::new(0.0, 0.0, 0.0, self.alpha);
+        }
+        let (b_, a_) = self.h.to_radians().sin_cos();
+        let (s_max, t_max) = cusp_slopes(find_cusp(a_, b_));
+        let s = self.s;
+        let k = 1.0 - S_0 / s_max;
+
+        // L and C as if the gamut were a perfect triangle, first at v = 1
+        let denom = S_0 + t_max - t_max * k * s;
+        let l_v = 1.0 - s * S_0 / denom;
+        let c_v = s * t_max * S_0 / denom;
+        let l = self.v * l_v;
+        let c = self.v * c_v;
+
+        // Compensate for the toe and the curved top of the gamut
+        let l_vt = toe_inv(l_v);
+        let c_vt = c_v * l_vt / l_v;
+        let l_new = toe_inv(l);
+        let c = c * l_new / l;
+        let scale = top_scale(l_vt, c_vt, a_, b_);
+        let (l, c) = (l_new * scale, c * scale);
+        Oklab::with_alpha(l, c * a_, c * b_, self.alpha).to_color()
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let Oklab { l, a, b, alpha } = Oklab::from_color(color);
+        let c = (a * a + b * b).sqrt();
+        if l <= EPSILON {
+            return Okhsv::with_alpha(0.0, 0.0, 0.0, alpha);
+        }
+        if c < ACHROMATIC_CHROMA {
+            return Okhsv::with_alpha(0.0, 0.0, toe(l), alpha);
+        }
+        let (a_, b_) = (a / c, b / c);
+        let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+        let (s_max, t_max) = cusp_slopes(find_cusp(a_, b_));
+        let k = 1.0 - S_0 / s_max;
+
+        // Project onto the v = 1 line of the triangle
+        let t = t_max / (c + l * t_max);
+        let l_v = t * l;
+        let c_v = t * c;
+
+        // Undo the toe and curved-top compensation
+        let l_vt = toe_inv(l_v);
+        let c_vt = c_v * l_vt / l_v;
+        let scale = top_scale(l_vt, c_vt, a_, b_);
+        let l = toe(l / scale);
+
+        let v = l / l_v;
+        let s = (S_0 + t_max) * c_v / (t_max * S_0 + t_max * k * c_v);
+        Okhsv::with_alpha(h, s, v, alpha)
+    }
+}
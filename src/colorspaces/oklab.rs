@@ -1,6 +1,7 @@
 /// Oklab color space and conversion to/from linear RGB (Color).
 use crate::colorspaces::color::Color;
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Interpolate};
+use crate::math::{Mat3, dot};
 use serde::{Deserialize, Serialize};
 
 /// Oklab color space (perceptual, 0.0-1.0 for L, usually -0.5..0.5 for a/b, 0.0-1.0 for alpha)
@@ -12,99 +13,214 @@ pub struct Oklab {
     pub alpha: f64,
 }
 
-const EPSILON: f64 = 1e-10;
+impl_constructors!(Oklab { l, a, b }, alpha);
 
-/// Clamp a value to a given range.
-fn clamp(val: f64, min: f64, max: f64) -> f64 {
-    if val < min {
-        min
-    } else if val > max {
-        max
+// NOTE: Values are not clamped, so wide-gamut and HDR colors survive a round trip.
+// The cube root is taken with cbrt, which is defined for the negative LMS values of
+// out-of-gamut colors. The forward matrices are Ottosson's published ones; the
+// inverses are computed from them rather than taken from the rounded published values.
+
+const SRGB_TO_LMS: Mat3 = Mat3([
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+]);
+
+const LMS_TO_OKLAB: Mat3 = Mat3([
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+]);
+
+const OKLAB_TO_LMS: Mat3 = Mat3([
+    [0.9999999984505198, 0.39633779217376786, 0.2158037580607588],
+    [
+        1.0000000088817609,
+        -0.10556134232365635,
+        -0.06385417477170591,
+    ],
+    [
+        1.0000000546724108,
+        -0.08948418209496575,
+        -1.2914855378640917,
+    ],
+]);
+
+const LMS_TO_SRGB: Mat3 = Mat3([
+    [4.076741661347994, -3.3077115904081933, 0.2309699287294279],
+    [-1.268438004092176, 2.6097574006633715, -0.3413193963102196],
+    [
+        -0.004196086541837109,
+        -0.7034186144594496,
+        1.7076147009309448,
+    ],
+]);
+
+// Toe constants from Ottosson's Okhsl/Okhsv derivation
+const K1: f64 = 0.206;
+const K2: f64 = 0.03;
+const K3: f64 = (1.0 + K1) / (1.0 + K2);
+
+/// Toe function mapping Oklab L to the lightness estimate Lr.
+pub(crate) fn toe(x: f64) -> f64 {
+    let t = K3 * x - K1;
+    0.5 * (t + (t * t + 4.0 * K2 * K3 * x).sqrt())
+}
+
+/// Inverse of `toe`, mapping Lr back to Oklab L.
+pub(crate) fn toe_inv(x: f64) -> f64 {
+    (x * x + K1 * x) / (K3 * (x + K2))
+}
+
+// sRGB gamut geometry from Ottosson's Okhsl/Okhsv reference implementation. Hue is
+// given as a unit vector (a_, b_) in the Oklab a/b plane.
+
+/// Rate of change of nonlinear LMS per unit chroma along the hue (a_, b_).
+fn lms_per_chroma(a_: f64, b_: f64) -> [f64; 3] {
+    let m = &OKLAB_TO_LMS.0;
+    [0, 1, 2].map(|i| m[i][1] * a_ + m[i][2] * b_)
+}
+
+/// Largest saturation S = C / L inside sRGB for the hue (a_, b_).
+pub(crate) fn max_saturation(a_: f64, b_: f64) -> f64 {
+    // Polynomial fit for the component that clips first, refined by one Halley step
+    let (k, row) = if -1.88170328 * a_ - 0.80936493 * b_ > 1.0 {
+        (
+            [1.19086277, 1.76576728, 0.59662641, 0.75515197, 0.56771245],
+            0,
+        )
+    } else if 1.81444104 * a_ - 1.19445276 * b_ > 1.0 {
+        (
+            [0.73956515, -0.45954404, 0.08285427, 0.12541070, 0.14503204],
+            1,
+        )
     } else {
-        val
+        (
+            [
+                1.35733652,
+                -0.00915799,
+                -1.15130210,
+                -0.50559606,
+                0.00692167,
+            ],
+            2,
+        )
+    };
+    let w = LMS_TO_SRGB.0[row];
+    let s = k[0] + k[1] * a_ + k[2] * b_ + k[3] * a_ * a_ + k[4] * a_ * b_;
+    let kl = lms_per_chroma(a_, b_);
+    let lms_ = kl.map(|k| 1.0 + s * k);
+    let lms = lms_.map(|v| v * v * v);
+    let d1 = [0, 1, 2].map(|i| 3.0 * kl[i] * lms_[i] * lms_[i]);
+    let d2 = [0, 1, 2].map(|i| 6.0 * kl[i] * kl[i] * lms_[i]);
+    let f = dot(w, lms);
+    let f1 = dot(w, d1);
+    let f2 = dot(w, d2);
+    s - f * f1 / (f1 * f1 - 0.5 * f * f2)
+}
+
+/// Lightness and chroma (L, C) of the most chromatic sRGB color of the hue (a_, b_).
+pub(crate) fn find_cusp(a_: f64, b_: f64) -> (f64, f64) {
+    let s_cusp = max_saturation(a_, b_);
+    let rgb = Oklab::new(1.0, s_cusp * a_, s_cusp * b_).to_color();
+    let l_cusp = (1.0 / rgb.r.max(rgb.g).max(rgb.b)).cbrt();
+    (l_cusp, l_cusp * s_cusp)
+}
+
+/// Where the line from (L0, 0) to (L1, C1) leaves sRGB, as a fraction `t` of its length.
+pub(crate) fn find_gamut_intersection(
+    a_: f64,
+    b_: f64,
+    l1: f64,
+    c1: f64,
+    l0: f64,
+    (cusp_l, cusp_c): (f64, f64),
+) -> f64 {
+    if (l1 - l0) * cusp_c - (cusp_l - l0) * c1 <= 0.0 {
+        // Below the cusp the boundary is close enough to a straight line
+        return cusp_c * l0 / (c1 * cusp_l + cusp_c * (l0 - l1));
+    }
+    // Above it, intersect with the line to white and refine with one Halley step
+    let t = cusp_c * (l0 - 1.0) / (c1 * (cusp_l - 1.0) + cusp_c * (l0 - l1));
+    let kl = lms_per_chroma(a_, b_);
+    let dt = kl.map(|k| (l1 - l0) + c1 * k);
+    let l = l0 * (1.0 - t) + t * l1;
+    let c = t * c1;
+    let lms_ = kl.map(|k| l + c * k);
+    let lms = lms_.map(|v| v * v * v);
+    let d1 = [0, 1, 2].map(|i| 3.0 * dt[i] * lms_[i] * lms_[i]);
+    let d2 = [0, 1, 2].map(|i| 6.0 * dt[i] * dt[i] * lms_[i]);
+    let step = LMS_TO_SRGB
+        .0
+        .iter()
+        .map(|w| {
+            let f = dot(*w, lms) - 1.0;
+            let f1 = dot(*w, d1);
+            let f2 = dot(*w, d2);
+            let u = f1 / (f1 * f1 - 0.5 * f * f2);
+            if u >= 0.0 { -f * u } else { f64::INFINITY }
+        })
+        .fold(f64::INFINITY, f64::min);
+    t + step
+}
+
+/// Slopes (S, T) of the triangle approximating the gamut: C = S·L below the cusp and
+/// C = T·(1 - L) above it.
+pub(crate) fn cusp_slopes((l, c): (f64, f64)) -> (f64, f64) {
+    (c / l, c / (1.0 - l))
+}
+
+impl Oklab {
+    /// Lightness estimate Lr, which tracks CIELAB L* (scaled to 0-1) more closely near black.
+    pub fn lr(&self) -> f64 {
+        toe(self.l)
     }
 }
 
 impl ColorSpace for Oklab {
     fn to_color(&self) -> Color {
-        // Oklab to linear RGB
-        let l = self.l;
-        let a = self.a;
-        let b = self.b;
-
-        // 1. Oklab to LMS
-        let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
-        let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
-        let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
-
-        // 2. LMS to nonlinear (cube, clamp for stability)
-        let l_cubed = if l_.abs() < EPSILON {
-            0.0
-        } else {
-            l_ * l_ * l_
-        };
-        let m_cubed = if m_.abs() < EPSILON {
-            0.0
-        } else {
-            m_ * m_ * m_
-        };
-        let s_cubed = if s_.abs() < EPSILON {
-            0.0
-        } else {
-            s_ * s_ * s_
-        };
+        // 1. Oklab to nonlinear LMS
+        let [l_, m_, s_] = OKLAB_TO_LMS.apply([self.l, self.a, self.b]);
+
+        // 2. Undo the cube root
+        let lms = [l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_];
 
         // 3. LMS to linear RGB
-        let r = 4.0767416621 * l_cubed - 3.3077115913 * m_cubed + 0.2309699292 * s_cubed;
-        let g = -1.2684380046 * l_cubed + 2.6097574011 * m_cubed - 0.3413193965 * s_cubed;
-        let b = 0.0041960863 * l_cubed - 0.7034186147 * m_cubed + 1.7076147010 * s_cubed;
+        let [r, g, b] = LMS_TO_SRGB.apply(lms);
 
         Color {
-            r: clamp(r, 0.0, 1.0),
-            g: clamp(g, 0.0, 1.0),
-            b: clamp(b, 0.0, 1.0),
-            a: clamp(self.alpha, 0.0, 1.0),
+            r,
+            g,
+            b,
+            a: self.alpha,
         }
     }
 
     fn from_color(c: &Color) -> Self {
-        // Clamp input for stability
-        let r = clamp(c.r, 0.0, 1.0);
-        let g = clamp(c.g, 0.0, 1.0);
-        let b = clamp(c.b, 0.0, 1.0);
-
         // 1. Linear RGB to LMS
-        let l = 0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b;
-        let m = 0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b;
-        let s = 0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b;
-
-        // 2. Nonlinear transform (cube root, clamp for stability)
-        let l_ = if l.abs() < EPSILON {
-            0.0
-        } else {
-            l.powf(1.0 / 3.0)
-        };
-        let m_ = if m.abs() < EPSILON {
-            0.0
-        } else {
-            m.powf(1.0 / 3.0)
-        };
-        let s_ = if s.abs() < EPSILON {
-            0.0
-        } else {
-            s.powf(1.0 / 3.0)
-        };
-
-        // 3. LMS to Oklab
-        let l_final = 0.2104542553 * l_ + 0.7936177850 * m_ - 0.0040720468 * s_;
-        let a_final = 1.9779984951 * l_ - 2.4285922050 * m_ + 0.4505937099 * s_;
-        let b_final = 0.0259040371 * l_ + 0.7827717662 * m_ - 0.8086757660 * s_;
+        let [l, m, s] = SRGB_TO_LMS.apply([c.r, c.g, c.b]);
+
+        // 2. Nonlinear transform (cube root)
+        let lms_ = [l.cbrt(), m.cbrt(), s.cbrt()];
+
+        // 3. Nonlinear LMS to Oklab
+        let [l, a, b] = LMS_TO_OKLAB.apply(lms_);
 
         Oklab {
-            l: clamp(l_final, 0.0, 1.0),
-            a: clamp(a_final, -0.5, 0.5),
-            b: clamp(b_final, -0.5, 0.5),
-            alpha: clamp(c.a, 0.0, 1.0),
+            l,
+            a,
+            b,
+            alpha: c.a,
         }
     }
 }
+
+impl Interpolate for Oklab {
+    fn components(&self) -> [f64; 3] {
+        [self.l, self.a, self.b]
+    }
+
+    fn from_components([l, a, b]: [f64; 3], alpha: f64) -> Self {
+        Oklab::with_alpha(l, a, b, alpha)
+    }
+}
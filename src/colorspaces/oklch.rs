@@ -1,7 +1,10 @@
-use crate::colorspaces::colorspace::ColorSpace;
-use crate::colorspaces::oklab::Oklab;
+use crate::colorspaces::colorspace::{ColorSpace, Interpolate};
+use crate::colorspaces::oklab::{Oklab, toe};
 use serde::{Deserialize, Serialize};
 
+/// Chroma below which the hue is powerless; grays round to about 4e-8
+pub(crate) const ACHROMATIC_CHROMA: f64 = 1e-6;
+
 /// Oklch color space (cylindrical representation of Oklab)
 ///
 /// # Fields
@@ -17,6 +20,8 @@ pub struct Oklch {
     pub alpha: f64,
 }
 
+impl_constructors!(Oklch { l, c, h }, alpha);
+
 impl Default for Oklch {
     fn default() -> Self {
         Self {
@@ -28,6 +33,13 @@ impl Default for Oklch {
     }
 }
 
+impl Oklch {
+    /// Lightness estimate Lr, see `Oklab::lr`.
+    pub fn lr(&self) -> f64 {
+        toe(self.l)
+    }
+}
+
 impl ColorSpace for Oklch {
     fn to_color(&self) -> crate::colorspaces::color::Color {
         // Precompute radians once
@@ -75,3 +87,19 @@ impl ColorSpace for Oklch {
         }
     }
 }
+
+impl Interpolate for Oklch {
+    const HUE: Option<usize> = Some(2);
+
+    fn components(&self) -> [f64; 3] {
+        [self.l, self.c, self.h]
+    }
+
+    fn from_components([l, c, h]: [f64; 3], alpha: f64) -> Self {
+        Oklch::with_alpha(l, c, h, alpha)
+    }
+
+    fn hue_is_powerless(&self) -> bool {
+        self.c < ACHROMATIC_CHROMA
+    }
+}
@@ -0,0 +1,52 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::oklab::{Oklab, toe, toe_inv};
+use serde::{Deserialize, Serialize};
+
+/// Oklrab: Oklab with lightness replaced by the toe-corrected estimate Lr
+///
+/// # Fields
+/// - l: lightness estimate Lr (0.0-1.0)
+/// - a, b: Oklab opponent axes, unchanged
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Oklrab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Oklrab { l, a, b }, alpha);
+
+impl From<Oklab> for Oklrab {
+    fn from(lab: Oklab) -> Self {
+        Oklrab {
+            l: toe(lab.l),
+            a: lab.a,
+            b: lab.b,
+            alpha: lab.alpha,
+        }
+    }
+}
+
+impl From<Oklrab> for Oklab {
+    fn from(lrab: Oklrab) -> Self {
+        Oklab {
+            l: toe_inv(lrab.l),
+            a: lrab.a,
+            b: lrab.b,
+            alpha: lrab.alpha,
+        }
+    }
+}
+
+impl ColorSpace for Oklrab {
+    fn to_color(&self) -> Color {
+        Oklab::from(*self).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Oklab::from_color(c).into()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::oklab::{toe, toe_inv};
+use crate::colorspaces::oklch::Oklch;
+use serde::{Deserialize, Serialize};
+
+/// Oklrch: Oklch with lightness replaced by the toe-corrected estimate Lr
+///
+/// # Fields
+/// - l: lightness estimate Lr (0.0-1.0)
+/// - c: chroma (0.0+, typically 0.0-0.4)
+/// - h: hue angle in degrees (0.0-360.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Oklrch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Oklrch { l, c, h }, alpha);
+
+impl Default for Oklrch {
+    fn default() -> Self {
+        Self {
+            l: 0.0,
+            c: 0.0,
+            h: 0.0,
+            alpha: 1.0,
+        }
+    }
+}
+
+impl From<Oklch> for Oklrch {
+    fn from(lch: Oklch) -> Self {
+        Oklrch {
+            l: toe(lch.l),
+            c: lch.c,
+            h: lch.h,
+            alpha: lch.alpha,
+        }
+    }
+}
+
+impl From<Oklrch> for Oklch {
+    fn from(lrch: Oklrch) -> Self {
+        Oklch {
+            l: toe_inv(lrch.l),
+            c: lrch.c,
+            h: lrch.h,
+            alpha: lrch.alpha,
+        }
+    }
+}
+
+impl ColorSpace for Oklrch {
+    fn to_color(&self) -> Color {
+        Oklch::from(*self).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Oklch::from_color(c).into()
+    }
+}
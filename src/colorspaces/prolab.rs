@@ -0,0 +1,96 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::luv::{XN_LUV, YN_LUV, ZN_LUV};
+use crate::colorspaces::xyz::Xyz;
+use crate::math::{Mat3, Vec3, dot};
+use serde::{Deserialize, Serialize};
+
+// NOTE: ProLab is a projective transform of white-relative XYZ, so both directions end
+// with a homogeneous divide. The divisor only reaches zero far outside any real gamut;
+// it is floored at epsilon rather than clamped so in-gamut values are never altered.
+
+/// ProLab (Konovalenko et al. 2021, D65) — L∈[0,100], a/b roughly [-100,100]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProLab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(ProLab { l, a, b }, alpha);
+
+/// Projective matrix from white-relative XYZ: (L, a, b) rows, then the divisor row
+const TO_PROLAB: Mat3 = Mat3([
+    [75.54, 486.66, 167.39],
+    [617.72, -595.45, -22.27],
+    [48.34, 194.94, -243.28],
+]);
+const TO_PROLAB_W: Vec3 = [0.7554, 3.8666, 1.6739];
+
+// Derived at full precision from the matrix above: the inverse of the 3×3 block, and
+// the divisor row that undoes TO_PROLAB_W
+const FROM_PROLAB: Mat3 = Mat3([
+    [
+        0.0013706328211735357,
+        0.001387382031383206,
+        0.0008160688511070954,
+    ],
+    [
+        0.0013706328211735357,
+        -0.0002431548542934065,
+        0.0009653291949249932,
+    ],
+    [
+        0.0013706328211735357,
+        8.083459429919238e-05,
+        -0.003174818967768846,
+    ],
+]);
+const FROM_PROLAB_W: Vec3 = [
+    -0.008629367178826465,
+    -0.0002431548542934065,
+    0.0009653291949249932,
+];
+
+/// D65 white, the hub's
+const WHITE: Vec3 = [XN_LUV, YN_LUV, ZN_LUV];
+
+const EPSILON: f64 = 1e-10;
+
+fn guard(w: f64) -> f64 {
+    if w.abs() < EPSILON {
+        EPSILON.copysign(w)
+    } else {
+        w
+    }
+}
+
+impl ColorSpace for ProLab {
+    fn to_color(&self) -> Color {
+        let lab = [self.l, self.a, self.b];
+        let [x, y, z] = FROM_PROLAB.apply(lab);
+        let w = guard(dot(FROM_PROLAB_W, lab) + 1.0);
+        Xyz::with_alpha(
+            WHITE[0] * x / w,
+            WHITE[1] * y / w,
+            WHITE[2] * z / w,
+            self.alpha,
+        )
+        .to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let xyz = Xyz::from_color(c);
+        let xyz = [xyz.x / WHITE[0], xyz.y / WHITE[1], xyz.z / WHITE[2]];
+        let w = guard(dot(TO_PROLAB_W, xyz) + 1.0);
+        let [l, a, b] = TO_PROLAB.apply(xyz).map(|v| v / w);
+
+        ProLab {
+            l,
+            a,
+            b,
+            alpha: c.a,
+        }
+    }
+}
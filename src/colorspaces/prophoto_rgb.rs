@@ -0,0 +1,107 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut, Interpolate};
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: ProPhoto is defined relative to D50. The matrices below include a Bradford
+// adaptation between D50 and the hub's D65, so ProPhoto white maps exactly to sRGB
+// white. The transfer curve is mirrored for negative components and not clamped.
+
+/// ProPhoto RGB / ROMM RGB (ISO 22028-2): very wide gamut, D50 white, γ 1.8
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProPhotoRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(ProPhotoRgb { r, g, b }, a);
+
+const GAMMA: f64 = 1.8;
+/// Linear value below which the curve is the straight segment
+const ENCODE_THRESHOLD: f64 = 1.0 / 512.0;
+/// Slope of the straight segment
+const LINEAR_SLOPE: f64 = 16.0;
+
+// Derived at full precision from the ROMM primaries, D50 -> D65 by Bradford
+const PROPHOTO_TO_SRGB: Mat3 = Mat3([
+    [
+        2.0343675434777984,
+        -0.7276344741733598,
+        -0.30673306930443855,
+    ],
+    [
+        -0.2288267981953874,
+        1.2317533962262321,
+        -0.0029265980308447546,
+    ],
+    [
+        -0.008558424336656588,
+        -0.15326820352831474,
+        1.1618266278649714,
+    ],
+]);
+
+const SRGB_TO_PROPHOTO: Mat3 = Mat3([
+    [0.5292804059761249, 0.3301529857118143, 0.14056660831206078],
+    [0.09836622197088565, 0.873463954506969, 0.0281698235221453],
+    [
+        0.016875340921386844,
+        0.11765941425612084,
+        0.8654652448224923,
+    ],
+]);
+
+fn decode(c: f64) -> f64 {
+    let v = c.abs();
+    let linear = if v < ENCODE_THRESHOLD * LINEAR_SLOPE {
+        v / LINEAR_SLOPE
+    } else {
+        v.powf(GAMMA)
+    };
+    linear.copysign(c)
+}
+
+fn encode(c: f64) -> f64 {
+    let v = c.abs();
+    let encoded = if v < ENCODE_THRESHOLD {
+        v * LINEAR_SLOPE
+    } else {
+        v.powf(1.0 / GAMMA)
+    };
+    encoded.copysign(c)
+}
+
+impl ColorSpace for ProPhotoRgb {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = PROPHOTO_TO_SRGB.apply([self.r, self.g, self.b].map(decode));
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [r, g, b] = SRGB_TO_PROPHOTO.apply([c.r, c.g, c.b]).map(encode);
+        ProPhotoRgb { r, g, b, a: c.a }
+    }
+}
+
+impl Gamut for ProPhotoRgb {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        SRGB_TO_PROPHOTO.apply([c.r, c.g, c.b])
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = PROPHOTO_TO_SRGB.apply(rgb);
+        Color::new(r, g, b, alpha)
+    }
+}
+
+impl Interpolate for ProPhotoRgb {
+    fn components(&self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    fn from_components([r, g, b]: [f64; 3], alpha: f64) -> Self {
+        ProPhotoRgb::with_alpha(r, g, b, alpha)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::colorspaces::color::Color;
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut, Interpolate};
+use crate::colorspaces::linear_rec2020::LinearRec2020;
 use serde::{Deserialize, Serialize};
 
 /// Rec.2020 RGB (D65), gamma ≈ 2.4 for SDR
@@ -11,49 +12,63 @@ pub struct Rec2020 {
     pub a: f64,
 }
 
-impl ColorSpace for Rec2020 {
-    fn to_color(&self) -> Color {
-        // Clamp input to [0.0, 1.0] for numerical stability before gamma decoding
-        let clamp01 = |c: f64| c.max(0.0).min(1.0);
-        let inv_gamma = |c: f64| clamp01(c).powf(2.4);
+impl_constructors!(Rec2020 { r, g, b }, a);
 
-        let r_lin = inv_gamma(self.r);
-        let g_lin = inv_gamma(self.g);
-        let b_lin = inv_gamma(self.b);
+// Clamp to [0.0, 1.0] for numerical stability around the power functions
+fn clamp01(c: f64) -> f64 {
+    c.clamp(0.0, 1.0)
+}
 
-        // Rec.2020 → XYZ
-        let x = 0.636958_f64 * r_lin + 0.144617_f64 * g_lin + 0.168881_f64 * b_lin;
-        let y = 0.2627_f64 * r_lin + 0.678_f64 * g_lin + 0.0593_f64 * b_lin;
-        let z = 0.0_f64 * r_lin + 0.028073_f64 * g_lin + 1.060985_f64 * b_lin;
+impl From<Rec2020> for LinearRec2020 {
+    fn from(rec: Rec2020) -> Self {
+        let inv_gamma = |c: f64| clamp01(c).powf(2.4);
+        LinearRec2020 {
+            r: inv_gamma(rec.r),
+            g: inv_gamma(rec.g),
+            b: inv_gamma(rec.b),
+            a: rec.a,
+        }
+    }
+}
 
-        // XYZ → linear sRGB
-        let r = 3.240969_f64 * x - 1.537383_f64 * y - 0.498611_f64 * z;
-        let g = -0.969244_f64 * x + 1.875968_f64 * y + 0.041555_f64 * z;
-        let b = 0.05563_f64 * x - 0.203977_f64 * y + 1.056972_f64 * z;
+impl From<LinearRec2020> for Rec2020 {
+    fn from(lin: LinearRec2020) -> Self {
+        let gamma_encode = |c: f64| clamp01(c).powf(1.0 / 2.4);
+        Rec2020 {
+            r: gamma_encode(lin.r),
+            g: gamma_encode(lin.g),
+            b: gamma_encode(lin.b),
+            a: lin.a,
+        }
+    }
+}
 
-        Color::new(r, g, b, self.a)
+impl ColorSpace for Rec2020 {
+    fn to_color(&self) -> Color {
+        LinearRec2020::from(*self).to_color()
     }
 
     fn from_color(c: &Color) -> Self {
-        // linear sRGB → XYZ
-        let x = 0.4124 * c.r + 0.3576 * c.g + 0.1805 * c.b;
-        let y = 0.2126 * c.r + 0.7152 * c.g + 0.0722 * c.b;
-        let z = 0.0193 * c.r + 0.1192 * c.g + 0.9505 * c.b;
-
-        // XYZ → Rec.2020 linear
-        let r_lin = 1.7166634 * x - 0.3556733 * y - 0.2533681 * z;
-        let g_lin = -0.6666738 * x + 1.6164557 * y + 0.0157683 * z;
-        let b_lin = 0.0176425 * x - 0.0427769 * y + 0.9422433 * z;
-
-        // Clamp before gamma encoding for stability
-        let clamp01 = |c: f64| c.max(0.0).min(1.0);
-        let gamma_encode = |c: f64| clamp01(c).powf(1.0 / 2.4);
+        LinearRec2020::from_color(c).into()
+    }
+}
 
-        Rec2020 {
-            r: gamma_encode(r_lin),
-            g: gamma_encode(g_lin),
-            b: gamma_encode(b_lin),
-            a: c.a,
-        }
+impl Gamut for Rec2020 {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        LinearRec2020::linear_rgb(c)
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        LinearRec2020::from_linear_rgb(rgb, alpha)
+    }
+}
+
+impl Interpolate for Rec2020 {
+    fn components(&self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    fn from_components([r, g, b]: [f64; 3], alpha: f64) -> Self {
+        Rec2020::with_alpha(r, g, b, alpha)
     }
 }
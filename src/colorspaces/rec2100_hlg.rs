@@ -0,0 +1,70 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::linear_rec2020::LinearRec2020;
+use crate::hdr::{LuminanceContext, hlg_inverse_oetf, hlg_oetf, hlg_system_gamma};
+use serde::{Deserialize, Serialize};
+
+// NOTE: `Color` is display light here. The HLG OOTF maps scene light to display light
+// for a display whose nominal peak is `LuminanceContext::peak`, with the system gamma
+// that peak implies and a zero black level. The `ColorSpace` impl uses
+// `LuminanceContext::BT2408` (1000 nit peak, linear 1.0 = 203 nits, the HLG 75% level).
+// Negative Rec.2020 components are treated as zero.
+
+const EPSILON: f64 = 1e-12;
+
+/// BT.2100 luma coefficients of Rec.2020 RGB
+const LUMA: [f64; 3] = [0.2627, 0.6780, 0.0593];
+
+/// Rec.2100 HLG: Rec.2020 primaries with the Hybrid Log-Gamma signal encoding
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Rec2100Hlg {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(Rec2100Hlg { r, g, b }, a);
+
+fn luma([r, g, b]: [f64; 3]) -> f64 {
+    LUMA[0] * r + LUMA[1] * g + LUMA[2] * b
+}
+
+impl Rec2100Hlg {
+    /// Encode display light, with linear 1.0 at `context.diffuse_white` nits, for a
+    /// display with a nominal peak of `context.peak` nits.
+    pub fn from_color_with(color: &Color, context: &LuminanceContext) -> Self {
+        let gamma = hlg_system_gamma(context.peak);
+        let LinearRec2020 { r, g, b, a } = LinearRec2020::from_color(color);
+        // Display light relative to the peak, then the inverse OOTF back to scene light
+        let display = [r, g, b].map(|v| (context.to_nits(v) / context.peak).max(0.0));
+        let y_display = luma(display);
+        let scale = if y_display < EPSILON {
+            0.0
+        } else {
+            y_display.powf((1.0 - gamma) / gamma)
+        };
+        let [r, g, b] = display.map(|v| hlg_oetf(v * scale));
+        Rec2100Hlg { r, g, b, a }
+    }
+
+    /// Decode to display light, with linear 1.0 at `context.diffuse_white` nits, for a
+    /// display with a nominal peak of `context.peak` nits.
+    pub fn to_color_with(&self, context: &LuminanceContext) -> Color {
+        let gamma = hlg_system_gamma(context.peak);
+        let scene = [self.r, self.g, self.b].map(hlg_inverse_oetf);
+        let scale = luma(scene).powf(gamma - 1.0);
+        let [r, g, b] = scene.map(|v| context.from_nits(context.peak * scale * v));
+        LinearRec2020::with_alpha(r, g, b, self.a).to_color()
+    }
+}
+
+impl ColorSpace for Rec2100Hlg {
+    fn to_color(&self) -> Color {
+        self.to_color_with(&LuminanceContext::BT2408)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Self::from_color_with(c, &LuminanceContext::BT2408)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut};
+use crate::colorspaces::rec709::{oetf, oetf_inverse};
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Uses the 525-line SMPTE-C primaries (SMPTE 170M); 625-line PAL material uses the
+// EBU primaries instead. The transfer function is the same camera OETF as BT.709.
+
+/// Rec.601 / SD video RGB (SMPTE-C primaries, D65, BT.601 OETF)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Rec601 {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(Rec601 { r, g, b }, a);
+
+// Derived at full precision from the primaries, D65 on both sides
+const REC601_TO_SRGB: Mat3 = Mat3([
+    [
+        0.9395420637732396,
+        0.05018135685986761,
+        0.010276579366892774,
+    ],
+    [
+        0.01777222314356081,
+        0.9657928624969045,
+        0.016434914359534616,
+    ],
+    [
+        -0.0016215999431855412,
+        -0.004369749659735675,
+        1.0059913496029211,
+    ],
+]);
+
+const SRGB_TO_REC601: Mat3 = Mat3([
+    [
+        1.065379033769955,
+        -0.05540087282452723,
+        -0.009978160945427632,
+    ],
+    [
+        -0.0196325498731453,
+        1.0363630945786033,
+        -0.016730544705458057,
+    ],
+    [
+        0.0016320510640102004,
+        0.0044123731575076075,
+        0.9939555757784821,
+    ],
+]);
+
+impl ColorSpace for Rec601 {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = REC601_TO_SRGB.apply([self.r, self.g, self.b].map(oetf_inverse));
+        Color::new(r, g, b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [r, g, b] = SRGB_TO_REC601.apply([c.r, c.g, c.b]).map(oetf);
+        Rec601 { r, g, b, a: c.a }
+    }
+}
+
+impl Gamut for Rec601 {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        SRGB_TO_REC601.apply([c.r, c.g, c.b])
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = REC601_TO_SRGB.apply(rgb);
+        Color::new(r, g, b, alpha)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut};
+use serde::{Deserialize, Serialize};
+
+// NOTE: BT.709 shares the sRGB primaries and white, so only the transfer function
+// differs. It is the scene-referred camera OETF, not the display EOTF (BT.1886); values
+// are not clamped and the curve is mirrored for negative components.
+
+/// Rec.709 / BT.709 video RGB (sRGB primaries, D65, BT.709 OETF)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Rec709 {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(Rec709 { r, g, b }, a);
+
+// Exact constants that make the two segments meet with a continuous slope; the
+// specification rounds them to 1.099 and 0.018
+const ALPHA: f64 = 1.09929682680944;
+const BETA: f64 = 0.018053968510807;
+const LINEAR_SLOPE: f64 = 4.5;
+const EXPONENT: f64 = 0.45;
+
+/// BT.709 OETF: scene linear light to signal.
+pub(crate) fn oetf(l: f64) -> f64 {
+    let v = l.abs();
+    let e = if v < BETA {
+        LINEAR_SLOPE * v
+    } else {
+        ALPHA * v.powf(EXPONENT) - (ALPHA - 1.0)
+    };
+    e.copysign(l)
+}
+
+/// Inverse BT.709 OETF: signal to scene linear light.
+pub(crate) fn oetf_inverse(e: f64) -> f64 {
+    let v = e.abs();
+    let l = if v < LINEAR_SLOPE * BETA {
+        v / LINEAR_SLOPE
+    } else {
+        ((v + (ALPHA - 1.0)) / ALPHA).powf(1.0 / EXPONENT)
+    };
+    l.copysign(e)
+}
+
+impl ColorSpace for Rec709 {
+    fn to_color(&self) -> Color {
+        Color::new(
+            oetf_inverse(self.r),
+            oetf_inverse(self.g),
+            oetf_inverse(self.b),
+            self.a,
+        )
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Rec709::with_alpha(oetf(c.r), oetf(c.g), oetf(c.b), c.a)
+    }
+}
+
+impl Gamut for Rec709 {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        [c.r, c.g, c.b]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = rgb;
+        Color::new(r, g, b, alpha)
+    }
+}
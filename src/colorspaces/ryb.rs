@@ -0,0 +1,108 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::srgb::Srgb;
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The Gossett-Chen cube maps RYB to sRGB-encoded values by trilinear interpolation
+// and has no closed-form inverse. `from_color` solves for RYB with a clamped Newton
+// iteration; colors outside the cube's image (saturated blues and greens in particular)
+// come back as the nearest RYB color found, not an exact round-trip.
+
+const EPSILON: f64 = 1e-12;
+const NEWTON_ITERATIONS: usize = 32;
+
+/// RYB: artist's red-yellow-blue primaries (Gossett & Chen 2004)
+///
+/// # Fields
+/// - r, y, b: paint amounts (0.0-1.0); (0, 0, 0) is white and (1, 1, 1) near-black
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Ryb {
+    pub r: f64,
+    pub y: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Ryb { r, y, b }, alpha);
+
+/// sRGB-encoded color of each cube corner, indexed by `r << 2 | y << 1 | b`
+const CORNERS: [[f64; 3]; 8] = [
+    [1.0, 1.0, 1.0],
+    [0.163, 0.373, 0.6],
+    [1.0, 1.0, 0.0],
+    [0.0, 0.66, 0.2],
+    [1.0, 0.0, 0.0],
+    [0.5, 0.0, 0.5],
+    [1.0, 0.5, 0.0],
+    [0.2, 0.094, 0.0],
+];
+
+/// Trilinear weight of `corner` at `t`, with axis `skip` differentiated instead.
+fn weight(corner: usize, t: [f64; 3], skip: Option<usize>) -> f64 {
+    (0..3)
+        .map(|axis| {
+            let bit = corner >> (2 - axis) & 1 == 1;
+            match (Some(axis) == skip, bit) {
+                (true, true) => 1.0,
+                (true, false) => -1.0,
+                (false, true) => t[axis],
+                (false, false) => 1.0 - t[axis],
+            }
+        })
+        .product()
+}
+
+/// Sum of the corner colors weighted by `weight` at `t`.
+fn interpolate(t: [f64; 3], skip: Option<usize>) -> [f64; 3] {
+    let mut out = [0.0; 3];
+    for (corner, rgb) in CORNERS.iter().enumerate() {
+        let w = weight(corner, t, skip);
+        for (o, v) in out.iter_mut().zip(rgb) {
+            *o += w * v;
+        }
+    }
+    out
+}
+
+impl ColorSpace for Ryb {
+    fn to_color(&self) -> Color {
+        let t = [self.r, self.y, self.b].map(|v| v.clamp(0.0, 1.0));
+        let [r, g, b] = interpolate(t, None);
+        Srgb::with_alpha(r, g, b, self.alpha).to_color()
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let Srgb { r, g, b, a } = Srgb::from_color(color);
+        let target = [r, g, b];
+        let residual = |t: [f64; 3]| {
+            let rgb = interpolate(t, None);
+            [0, 1, 2].map(|i| rgb[i] - target[i])
+        };
+        let norm = |v: [f64; 3]| v.iter().map(|x| x * x).sum::<f64>();
+
+        // Start from the complement, which is exact along the neutral axis' ends
+        let mut t = target.map(|v| (1.0 - v).clamp(0.0, 1.0));
+        let mut best = (norm(residual(t)), t);
+        for _ in 0..NEWTON_ITERATIONS {
+            let f = residual(t);
+            if norm(f) < EPSILON * EPSILON {
+                break;
+            }
+            let columns = [0, 1, 2].map(|axis| interpolate(t, Some(axis)));
+            let jacobian = Mat3([0, 1, 2].map(|row| columns.map(|c| c[row])));
+            let step = jacobian.inverse().apply(f);
+            if step.iter().any(|s| !s.is_finite()) {
+                break;
+            }
+            t = [0, 1, 2].map(|i| (t[i] - step[i]).clamp(0.0, 1.0));
+            let err = norm(residual(t));
+            if err < best.0 {
+                best = (err, t);
+            }
+        }
+        let [r, y, b] = best.1;
+        Ryb::with_alpha(r, y, b, a)
+    }
+}
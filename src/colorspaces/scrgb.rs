@@ -0,0 +1,47 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::hdr::LuminanceContext;
+use serde::{Deserialize, Serialize};
+
+// NOTE: scRGB (IEC 61966-2-2) is linear sRGB where 1.0 is 80 nits; the usual range is
+// about [-0.5, 7.5]. Components are never clamped, so negative (wide-gamut) and > 1
+// (HDR) values pass straight through. The `ColorSpace` impl treats scRGB 1.0 as the
+// hub's 1.0; use the `_with` methods to honour an explicit diffuse white.
+
+/// scRGB: extended-range linear sRGB, as used by Windows HDR swapchains
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl_constructors!(ScRgb { r, g, b }, a);
+
+impl ScRgb {
+    /// Luminance of scRGB 1.0, in nits
+    pub const UNIT_NITS: f64 = 80.0;
+
+    /// Convert a linear color, with linear 1.0 at `context.diffuse_white` nits.
+    pub fn from_color_with(color: &Color, context: &LuminanceContext) -> Self {
+        let scale = context.to_nits(1.0) / Self::UNIT_NITS;
+        ScRgb::with_alpha(color.r * scale, color.g * scale, color.b * scale, color.a)
+    }
+
+    /// Convert to a linear color, with linear 1.0 at `context.diffuse_white` nits.
+    pub fn to_color_with(&self, context: &LuminanceContext) -> Color {
+        let scale = context.from_nits(Self::UNIT_NITS);
+        Color::new(self.r * scale, self.g * scale, self.b * scale, self.a)
+    }
+}
+
+impl ColorSpace for ScRgb {
+    fn to_color(&self) -> Color {
+        Color::new(self.r, self.g, self.b, self.a)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        ScRgb::with_alpha(c.r, c.g, c.b, c.a)
+    }
+}
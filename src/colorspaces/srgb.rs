@@ -13,20 +13,54 @@ pub struct Srgb {
     pub a: f64,
 }
 
+impl_constructors!(Srgb { r, g, b }, a);
+
 use crate::colorspaces::color::Color;
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut, Interpolate};
+use crate::colorspaces::linear_srgb::LinearSrgb;
 
-impl ColorSpace for Srgb {
-    fn to_color(&self) -> Color {
-        // Convert sRGB to linear RGB
-        fn srgb_to_linear(c: f64) -> f64 {
-            if c <= 0.04045 {
-                c / 12.92
-            } else {
-                ((c + 0.055) / 1.055).powf(2.4)
-            }
+// Convert sRGB to linear RGB
+pub(crate) fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Convert linear RGB to sRGB
+pub(crate) fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl From<Srgb> for LinearSrgb {
+    fn from(srgb: Srgb) -> Self {
+        LinearSrgb {
+            r: srgb_to_linear(srgb.r),
+            g: srgb_to_linear(srgb.g),
+            b: srgb_to_linear(srgb.b),
+            a: srgb.a,
         }
+    }
+}
+
+impl From<LinearSrgb> for Srgb {
+    fn from(lin: LinearSrgb) -> Self {
+        Srgb {
+            r: linear_to_srgb(lin.r),
+            g: linear_to_srgb(lin.g),
+            b: linear_to_srgb(lin.b),
+            a: lin.a,
+        }
+    }
+}
 
+impl ColorSpace for Srgb {
+    fn to_color(&self) -> Color {
         Color {
             r: srgb_to_linear(self.r),
             g: srgb_to_linear(self.g),
@@ -36,15 +70,6 @@ impl ColorSpace for Srgb {
     }
 
     fn from_color(color: &Color) -> Self {
-        // Convert linear RGB to sRGB
-        fn linear_to_srgb(c: f64) -> f64 {
-            if c <= 0.0031308 {
-                12.92 * c
-            } else {
-                1.055 * c.powf(1.0 / 2.4) - 0.055
-            }
-        }
-
         Srgb {
             r: linear_to_srgb(color.r),
             g: linear_to_srgb(color.g),
@@ -53,3 +78,24 @@ impl ColorSpace for Srgb {
         }
     }
 }
+
+impl Gamut for Srgb {
+    fn linear_rgb(c: &Color) -> [f64; 3] {
+        [c.r, c.g, c.b]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: f64) -> Color {
+        let [r, g, b] = rgb;
+        Color::new(r, g, b, alpha)
+    }
+}
+
+impl Interpolate for Srgb {
+    fn components(&self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    fn from_components([r, g, b]: [f64; 3], alpha: f64) -> Self {
+        Srgb::with_alpha(r, g, b, alpha)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: SRLAB2 (Jan Behrens) is defined directly on linear sRGB with a fixed D65
+// adaptation baked into its matrices. Inputs at or below the linear limit, negative
+// (out-of-gamut) ones included, take the linear segment, so they stay finite instead of
+// producing NaN.
+
+/// SRLAB2 — CIELAB-like space with improved hue linearity, L∈[0,100]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Srlab2 {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Srlab2 { l, a, b }, alpha);
+
+const KAPPA_INV: f64 = 24389.0 / 2700.0;
+const LINEAR_LIMIT: f64 = 216.0 / 24389.0;
+
+fn f(t: f64) -> f64 {
+    if t <= LINEAR_LIMIT {
+        t * KAPPA_INV
+    } else {
+        1.16 * t.cbrt() - 0.16
+    }
+}
+
+fn f_inv(u: f64) -> f64 {
+    if u <= 0.08 {
+        u / KAPPA_INV
+    } else {
+        ((u + 0.16) / 1.16).powi(3)
+    }
+}
+
+const SRGB_TO_XYZ_LIKE: Mat3 = Mat3([
+    [0.32053, 0.63692, 0.04256],
+    [0.161987, 0.756636, 0.081376],
+    [0.017228, 0.10866, 0.874112],
+]);
+
+const COMPANDED_TO_LAB: Mat3 = Mat3([
+    [37.0950, 62.9054, -0.0008],
+    [663.4684, -750.5078, 87.0328],
+    [63.9569, 108.4576, -172.4152],
+]);
+
+// Exact inverses of the forward matrices (the published ones are truncated)
+const LAB_TO_COMPANDED: Mat3 = Mat3([
+    [
+        0.010000102867215928,
+        0.0009041271715941015,
+        0.0004563444476915106,
+    ],
+    [
+        0.010000002927254975,
+        -0.0005331592745662988,
+        -0.0002691778016892685,
+    ],
+    [
+        0.009999999400002388,
+        -2.4570194602190738e-11,
+        -0.005799999084407482,
+    ],
+]);
+
+const XYZ_LIKE_TO_SRGB: Mat3 = Mat3([
+    [5.435039931492935, -4.598577399185538, 0.1634785187158888],
+    [-1.167669384435469, 2.3275113697497165, -0.15982798110903337],
+    [0.03803183959606755, -0.19869661325303367, 1.160664194641957],
+]);
+
+impl ColorSpace for Srlab2 {
+    fn to_color(&self) -> Color {
+        let companded = LAB_TO_COMPANDED.apply([self.l, self.a, self.b]);
+        let [r, g, b] = XYZ_LIKE_TO_SRGB.apply(companded.map(f_inv));
+
+        Color::new(r, g, b, self.alpha)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let companded = SRGB_TO_XYZ_LIKE.apply([c.r, c.g, c.b]).map(f);
+        let [l, a, b] = COMPANDED_TO_LAB.apply(companded);
+
+        Srlab2 {
+            l,
+            a,
+            b,
+            alpha: c.a,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::TAU;
+
+// NOTE: TSL (Terrillon et al.) is computed on the hub's RGB components, as Hsl is.
+// The published tint uses arctan(r'/g') with a quadrant offset and sets T = 0 whenever
+// g' = 0; this uses atan2, which agrees everywhere else and keeps the sign of r' on that
+// line so the conversion round-trips. Black has no chromaticity and gets T = S = 0.
+
+const EPSILON: f64 = 1e-12;
+const ONE_THIRD: f64 = 1.0 / 3.0;
+
+/// Rec.601 luma weights used for the lightness channel
+const LUMA: [f64; 3] = [0.299, 0.587, 0.114];
+
+/// TSL: tint, saturation and lightness, from the skin-segmentation literature
+///
+/// # Fields
+/// - t: tint, the hue of the normalized chromaticity (0.0-1.0)
+/// - s: saturation (0.0-~1.0, 1.0 at pure red or green)
+/// - l: lightness, Rec.601 weighted luma (0.0-1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Tsl {
+    pub t: f64,
+    pub s: f64,
+    pub l: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Tsl { t, s, l }, alpha);
+
+impl ColorSpace for Tsl {
+    fn to_color(&self) -> Color {
+        // Normalized chromaticity offsets from neutral
+        let k = self.s * (5.0f64 / 9.0).sqrt();
+        let angle = TAU * (self.t - 0.25);
+        let r = k * angle.sin() + ONE_THIRD;
+        let g = k * angle.cos() + ONE_THIRD;
+        let b = 1.0 - r - g;
+
+        let weighted = LUMA[0] * r + LUMA[1] * g + LUMA[2] * b;
+        if weighted.abs() < EPSILON {
+            return Color::new(0.0, 0.0, 0.0, self.alpha);
+        }
+        let sum = self.l / weighted;
+        Color::new(r * sum, g * sum, b * sum, self.alpha)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let l = LUMA[0] * c.r + LUMA[1] * c.g + LUMA[2] * c.b;
+        let sum = c.r + c.g + c.b;
+        if sum.abs() < EPSILON {
+            return Tsl::with_alpha(0.0, 0.0, l, c.a);
+        }
+
+        let r = c.r / sum - ONE_THIRD;
+        let g = c.g / sum - ONE_THIRD;
+        let s = (9.0 / 5.0 * (r * r + g * g)).sqrt();
+        let t = if s < EPSILON {
+            0.0
+        } else {
+            (r.atan2(g) / TAU + 0.25).rem_euclid(1.0)
+        };
+        Tsl::with_alpha(t, s, l, c.a)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::xyy::Xyy;
+use crate::colorspaces::xyz::Xyz;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Both diagrams are projective transforms of CIE 1931 xy, so they go through `Xyy`
+// and share its handling of black (the D65 chromaticity). The 1960 diagram differs from
+// the 1976 one only in v = 2/3 v'; it survives because CCT and Duv are defined on it,
+// while u'v' distances are the usual display-calibration tolerance.
+
+const EPSILON: f64 = 1e-12;
+
+/// CIE 1960 UCS chromaticity (u, v) with luminance Y
+///
+/// # Fields
+/// - u, v: CIE 1960 chromaticity coordinates (u 0.0-0.65, v 0.0-0.40)
+/// - luminance: relative luminance Y (0.0-1.0, white = 1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Uv1960 {
+    pub u: f64,
+    pub v: f64,
+    pub luminance: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Uv1960 { u, v, luminance }, alpha);
+
+/// CIE 1976 UCS chromaticity (u', v') with luminance Y
+///
+/// # Fields
+/// - u, v: CIE 1976 chromaticity coordinates u' and v' (0.0-0.65)
+/// - luminance: relative luminance Y (0.0-1.0, white = 1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UvPrime {
+    pub u: f64,
+    pub v: f64,
+    pub luminance: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(UvPrime { u, v, luminance }, alpha);
+
+impl Uv1960 {
+    /// Euclidean distance to another chromaticity in the 1960 diagram, ignoring luminance.
+    pub fn distance(&self, other: &Uv1960) -> f64 {
+        (self.u - other.u).hypot(self.v - other.v)
+    }
+}
+
+impl UvPrime {
+    /// Δu'v': Euclidean distance to another chromaticity, ignoring luminance.
+    pub fn distance(&self, other: &UvPrime) -> f64 {
+        (self.u - other.u).hypot(self.v - other.v)
+    }
+}
+
+impl From<Xyy> for UvPrime {
+    fn from(xyy: Xyy) -> Self {
+        let denom = -2.0 * xyy.x + 12.0 * xyy.y + 3.0;
+        if denom.abs() < EPSILON {
+            return UvPrime::with_alpha(0.0, 0.0, xyy.luminance, xyy.alpha);
+        }
+        UvPrime::with_alpha(
+            4.0 * xyy.x / denom,
+            9.0 * xyy.y / denom,
+            xyy.luminance,
+            xyy.alpha,
+        )
+    }
+}
+
+impl From<UvPrime> for Xyy {
+    fn from(uv: UvPrime) -> Self {
+        let denom = 6.0 * uv.u - 16.0 * uv.v + 12.0;
+        if denom.abs() < EPSILON {
+            return Xyy::with_alpha(0.0, 0.0, uv.luminance, uv.alpha);
+        }
+        Xyy::with_alpha(
+            9.0 * uv.u / denom,
+            4.0 * uv.v / denom,
+            uv.luminance,
+            uv.alpha,
+        )
+    }
+}
+
+impl From<Uv1960> for UvPrime {
+    fn from(uv: Uv1960) -> Self {
+        UvPrime::with_alpha(uv.u, uv.v * 1.5, uv.luminance, uv.alpha)
+    }
+}
+
+impl From<UvPrime> for Uv1960 {
+    fn from(uv: UvPrime) -> Self {
+        Uv1960::with_alpha(uv.u, uv.v / 1.5, uv.luminance, uv.alpha)
+    }
+}
+
+impl From<Xyz> for UvPrime {
+    fn from(xyz: Xyz) -> Self {
+        Xyy::from(xyz).into()
+    }
+}
+
+impl From<UvPrime> for Xyz {
+    fn from(uv: UvPrime) -> Self {
+        Xyy::from(uv).into()
+    }
+}
+
+impl From<Xyz> for Uv1960 {
+    fn from(xyz: Xyz) -> Self {
+        UvPrime::from(xyz).into()
+    }
+}
+
+impl From<Uv1960> for Xyz {
+    fn from(uv: Uv1960) -> Self {
+        UvPrime::from(uv).into()
+    }
+}
+
+impl ColorSpace for Uv1960 {
+    fn to_color(&self) -> Color {
+        Xyz::from(*self).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Xyz::from_color(c).into()
+    }
+}
+
+impl ColorSpace for UvPrime {
+    fn to_color(&self) -> Color {
+        Xyz::from(*self).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Xyz::from_color(c).into()
+    }
+}
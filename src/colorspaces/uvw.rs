@@ -0,0 +1,68 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::luv::{XN_LUV, YN_LUV, ZN_LUV, u_prime, v_prime};
+use crate::colorspaces::xyz::Xyz;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The 1964 formula for W* is only specified for 1 <= Y <= 100; below that
+// W* goes negative (down to -17 at black) and is passed through unclamped.
+// Chromaticity uses the CIE 1960 UCS diagram (u = u', v = 2/3 v').
+
+/// CIE 1964 U*V*W* (D65), still required for CIE 13.3 color rendering index
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Uvw {
+    pub u: f64,
+    pub v: f64,
+    pub w: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Uvw { u, v, w }, alpha);
+
+const EPSILON: f64 = 1e-10;
+
+/// CIE 1960 (u, v) chromaticity of an XYZ triple.
+fn uv_1960(x: f64, y: f64, z: f64) -> (f64, f64) {
+    (u_prime(x, y, z), v_prime(x, y, z) * 2.0 / 3.0)
+}
+
+impl ColorSpace for Uvw {
+    fn to_color(&self) -> Color {
+        let (u0, v0) = uv_1960(XN_LUV, YN_LUV, ZN_LUV);
+
+        let y = ((self.w + 17.0) / 25.0).powi(3) / 100.0 * YN_LUV;
+        let (u, v) = if self.w.abs() < EPSILON {
+            (u0, v0)
+        } else {
+            (self.u / (13.0 * self.w) + u0, self.v / (13.0 * self.w) + v0)
+        };
+
+        let v_denom = v.abs().max(EPSILON);
+        Xyz::with_alpha(
+            1.5 * u / v_denom * y,
+            y,
+            (4.0 - u - 10.0 * v) / (2.0 * v_denom) * y,
+            self.alpha,
+        )
+        .to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let Xyz { x, y, z, alpha, .. } = Xyz::from_color(c);
+        let (u0, v0) = uv_1960(XN_LUV, YN_LUV, ZN_LUV);
+        // Black has no chromaticity; treat it as the white point
+        let (u, v) = if y.abs() < EPSILON {
+            (u0, v0)
+        } else {
+            uv_1960(x, y, z)
+        };
+
+        let w = 25.0 * (100.0 * y / YN_LUV).cbrt() - 17.0;
+        Uvw {
+            u: 13.0 * w * (u - u0),
+            v: 13.0 * w * (v - v0),
+            w,
+            alpha,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+/// Surround condition of a viewing environment, as defined for CIECAM02.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Surround {
+    /// Surface colors and typical office viewing.
+    #[default]
+    Average,
+    /// Television or monitor viewed in a dim room.
+    Dim,
+    /// Projection in a dark room.
+    Dark,
+}
+
+impl Surround {
+    /// Returns the `(F, c, Nc)` parameters for this surround.
+    pub fn parameters(&self) -> (f64, f64, f64) {
+        match self {
+            Surround::Average => (1.0, 0.69, 1.0),
+            Surround::Dim => (0.9, 0.59, 0.9),
+            Surround::Dark => (0.8, 0.525, 0.8),
+        }
+    }
+}
+
+/// Viewing conditions used by the color appearance models.
+///
+/// The white point is given in XYZ scaled so that `Y = 1.0`, matching `Xyz`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ViewingConditions {
+    /// Adopted white in XYZ (Y = 1.0)
+    pub white: [f64; 3],
+    /// Adapting field luminance La in cd/m²
+    pub adapting_luminance: f64,
+    /// Relative background luminance Yb (0-100)
+    pub background_luminance: f64,
+    /// Surround condition
+    pub surround: Surround,
+    /// Assume full adaptation (D = 1) instead of computing D from La
+    pub discounting: bool,
+}
+
+impl ViewingConditions {
+    /// Construct viewing conditions with a D65 white.
+    pub fn new(adapting_luminance: f64, background_luminance: f64, surround: Surround) -> Self {
+        Self {
+            adapting_luminance,
+            background_luminance,
+            surround,
+            ..Self::default()
+        }
+    }
+
+    /// Degree of adaptation D, clamped to [0, 1].
+    pub fn degree_of_adaptation(&self) -> f64 {
+        if self.discounting {
+            return 1.0;
+        }
+        let (f, _, _) = self.surround.parameters();
+        let d = f * (1.0 - (1.0 / 3.6) * ((-self.adapting_luminance - 42.0) / 92.0).exp());
+        d.clamp(0.0, 1.0)
+    }
+}
+
+impl Default for ViewingConditions {
+    /// sRGB reference viewing: D65 white, 64 lux ambient, 20% gray background, average surround.
+    fn default() -> Self {
+        Self {
+            white: [0.95047, 1.0, 1.08883],
+            adapting_luminance: 64.0 / std::f64::consts::PI * 0.2,
+            background_luminance: 20.0,
+            surround: Surround::Average,
+            discounting: false,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Follows libjxl's opsin transform on linear sRGB with linear 1.0 at the image's
+// intensity target. B is the S channel itself; the JPEG XL bitstream codes B - Y, which
+// callers subtract themselves. Cube roots are taken of signed values, so out-of-gamut
+// colors round-trip.
+
+/// XYB (JPEG XL): cube-rooted, biased LMS with an opponent X axis
+///
+/// # Fields
+/// - x: L - M opponent axis (~-0.03-0.03)
+/// - y: luminance-like L + M (0.0-~0.85)
+/// - b: S (blue) response (0.0-~0.85)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Xyb {
+    pub x: f64,
+    pub y: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Xyb { x, y, b }, alpha);
+
+/// Linear sRGB to the biased opsin (LMS-like) absorbances
+const OPSIN: Mat3 = Mat3([
+    [0.30, 0.622, 0.078],
+    [0.23, 0.692, 0.078],
+    [0.2434226892454782, 0.2047674442449682, 0.5518098665095536],
+]);
+
+const OPSIN_INV: Mat3 = Mat3([
+    [11.031566904639861, -9.866943908131562, -0.1646229965082993],
+    [-3.2541473810744237, 4.418770377582723, -0.1646229965082993],
+    [-3.6588512867136807, 2.7129230459360913, 1.945928240777589],
+]);
+
+/// Absorbance bias, which keeps the cube root away from its infinite slope at zero
+const OPSIN_BIAS: f64 = 0.0037930732552754493;
+
+impl ColorSpace for Xyb {
+    fn to_color(&self) -> Color {
+        let bias_cbrt = OPSIN_BIAS.cbrt();
+        let lms = [self.y + self.x, self.y - self.x, self.b]
+            .map(|v| (v + bias_cbrt).powi(3) - OPSIN_BIAS);
+        let [r, g, b] = OPSIN_INV.apply(lms);
+        Color::new(r, g, b, self.alpha)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let bias_cbrt = OPSIN_BIAS.cbrt();
+        let [l, m, s] = OPSIN
+            .apply([c.r, c.g, c.b])
+            .map(|v| (v + OPSIN_BIAS).cbrt() - bias_cbrt);
+        Xyb::with_alpha(0.5 * (l - m), 0.5 * (l + m), s, c.a)
+    }
+}
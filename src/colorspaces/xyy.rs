@@ -0,0 +1,69 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::xyz::Xyz;
+use crate::white_point::WhitePoint;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Chromaticity is relative to D65, the hub white, so `From<Xyz>` adapts XYZ with
+// another white (with Bradford) before projecting it. Chromaticity is undefined where
+// X + Y + Z = 0. Black, and anything within EPSILON of it, gets the D65 white
+// chromaticity so that it sits on the neutral axis; a chromaticity with y = 0 has no
+// finite XYZ and converts to black.
+
+const EPSILON: f64 = 1e-12;
+
+/// D65 chromaticity, the sRGB white point
+const WHITE_X: f64 = 0.3127;
+const WHITE_Y: f64 = 0.3290;
+
+/// CIE xyY: chromaticity (x, y) with luminance Y
+///
+/// # Fields
+/// - x, y: CIE 1931 chromaticity coordinates (0.0-1.0)
+/// - luminance: relative luminance Y (0.0-1.0, white = 1.0)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Xyy {
+    pub x: f64,
+    pub y: f64,
+    pub luminance: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Xyy { x, y, luminance }, alpha);
+
+impl From<Xyz> for Xyy {
+    fn from(xyz: Xyz) -> Self {
+        let xyz = xyz.to_white(WhitePoint::D65);
+        let sum = xyz.x + xyz.y + xyz.z;
+        if sum.abs() < EPSILON {
+            return Xyy::with_alpha(WHITE_X, WHITE_Y, xyz.y, xyz.alpha);
+        }
+        Xyy::with_alpha(xyz.x / sum, xyz.y / sum, xyz.y, xyz.alpha)
+    }
+}
+
+impl From<Xyy> for Xyz {
+    fn from(xyy: Xyy) -> Self {
+        if xyy.y.abs() < EPSILON {
+            return Xyz::with_alpha(0.0, 0.0, 0.0, xyy.alpha);
+        }
+        let scale = xyy.luminance / xyy.y;
+        Xyz::with_alpha(
+            xyy.x * scale,
+            xyy.luminance,
+            (1.0 - xyy.x - xyy.y) * scale,
+            xyy.alpha,
+        )
+    }
+}
+
+impl ColorSpace for Xyy {
+    fn to_color(&self) -> Color {
+        Xyz::from(*self).to_color()
+    }
+
+    fn from_color(c: &Color) -> Self {
+        Xyz::from_color(c).into()
+    }
+}
@@ -1,39 +1,125 @@
+use crate::chromatic_adaptation::bradford;
 use crate::colorspaces::color::Color;
-use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::colorspace::{ColorSpace, Interpolate};
+use crate::math::Mat3;
+use crate::white_point::{Observer, WhitePoint};
 use serde::{Deserialize, Serialize};
 
-/// CIE XYZ with Observer=2°, Illuminant=D65
+// NOTE: The matrices are derived at full precision from the sRGB primaries and the D65
+// chromaticity (0.3127, 0.3290), and are exact inverses of each other. Values with
+// another reference white are adapted to and from D65 with Bradford; `WhitePoint::D50`
+// is the CIE white, which differs from the ICC D50 (`WHITE_D50`) by about 3e-4 in Z.
+
+/// Linear sRGB → XYZ (D65)
+pub(crate) const SRGB_TO_XYZ: Mat3 = Mat3([
+    [0.4123907992659595, 0.35758433938387796, 0.1804807884018343],
+    [0.21263900587151036, 0.7151686787677559, 0.07219231536073371],
+    [0.01933081871559185, 0.11919477979462599, 0.9505321522496606],
+]);
+
+/// XYZ (D65) → linear sRGB
+pub(crate) const XYZ_TO_SRGB: Mat3 = Mat3([
+    [3.2409699419045213, -1.5373831775700935, -0.4986107602930033],
+    [-0.9692436362808798, 1.8759675015077206, 0.04155505740717561],
+    [
+        0.05563007969699361,
+        -0.20397695888897657,
+        1.0569715142428786,
+    ],
+]);
+
+/// ICC profile connection space white (D50)
+pub(crate) const WHITE_D50: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+// Derived at full precision from the sRGB primaries, D65 -> ICC D50 by Bradford
+/// Linear sRGB → XYZ (D50)
+pub(crate) const SRGB_TO_XYZ_D50: Mat3 = Mat3([
+    [0.436041251616051, 0.3851129107981554, 0.14304583758579356],
+    [0.22248454022947745, 0.7169050786084573, 0.06061038116206526],
+    [
+        0.013920187471375392,
+        0.09706723869712401,
+        0.7139125738315006,
+    ],
+]);
+
+/// XYZ (D50) → linear sRGB
+pub(crate) const XYZ_D50_TO_SRGB: Mat3 = Mat3([
+    [3.134186364236819, -1.6172089589982748, -0.49069406400638393],
+    [-0.9787485041906944, 1.9161300967735873, 0.03343339915999554],
+    [
+        0.07196392780224674,
+        -0.22899387345320327,
+        1.4057537328964445,
+    ],
+]);
+
+/// CIE XYZ (2° observer) relative to a reference white
+///
+/// # Fields
+/// - x, y, z: tristimulus values (white has Y = 1.0)
+/// - alpha: opacity (0.0-1.0)
+/// - white: the reference white the values are relative to, D65 for `new` and
+///   `from_color`; `to_color` adapts other whites (such as the D50 XYZ of ICC profiles) to
+///   the D65 hub. The `white` setter relabels the values without adapting them.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Xyz {
     pub x: f64,
     pub y: f64,
     pub z: f64,
     pub alpha: f64,
+    #[serde(default)]
+    pub white: WhitePoint,
 }
 
-impl ColorSpace for Xyz {
-    fn to_color(&self) -> Color {
-        let x = self.x;
-        let y = self.y;
-        let z = self.z;
-        let r = 3.2406 * x - 1.5372 * y - 0.4986 * z;
-        let g = -0.9689 * x + 1.8758 * y + 0.0415 * z;
-        let b = 0.0557 * x - 0.2040 * y + 1.0570 * z;
-        Color::new(r, g, b, self.alpha)
+impl_constructors!(Xyz { x, y, z }, alpha; white: WhitePoint = WhitePoint::D65);
+
+/// Bradford adaptation between two named whites as an XYZ matrix, `None` if they match.
+fn adaptation(src: WhitePoint, dst: WhitePoint) -> Option<Mat3> {
+    (src != dst).then(|| bradford(src.xyz(Observer::Cie1931), dst.xyz(Observer::Cie1931)))
+}
+
+impl Xyz {
+    /// Convert a color to XYZ relative to `white`.
+    pub fn from_color_with(c: &Color, white: WhitePoint) -> Self {
+        Xyz::from_color(c).to_white(white)
     }
 
-    fn from_color(c: &Color) -> Self {
-        let r = c.r;
-        let g = c.g;
-        let b = c.b;
-        let x = 0.4124 * r + 0.3576 * g + 0.1805 * b;
-        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
-        let z = 0.0193 * r + 0.1192 * g + 0.9505 * b;
+    /// The same color relative to `white`, adapted with Bradford.
+    pub fn to_white(&self, white: WhitePoint) -> Self {
+        let Some(m) = adaptation(self.white, white) else {
+            return *self;
+        };
+        let [x, y, z] = m.apply([self.x, self.y, self.z]);
         Xyz {
             x,
             y,
             z,
-            alpha: c.a,
+            alpha: self.alpha,
+            white,
         }
     }
 }
+
+impl ColorSpace for Xyz {
+    fn to_color(&self) -> Color {
+        let d65 = self.to_white(WhitePoint::D65);
+        let [r, g, b] = XYZ_TO_SRGB.apply([d65.x, d65.y, d65.z]);
+        Color::new(r, g, b, self.alpha)
+    }
+
+    fn from_color(c: &Color) -> Self {
+        let [x, y, z] = SRGB_TO_XYZ.apply([c.r, c.g, c.b]);
+        Xyz::with_alpha(x, y, z, c.a)
+    }
+}
+
+impl Interpolate for Xyz {
+    fn components(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    fn from_components([x, y, z]: [f64; 3], alpha: f64) -> Self {
+        Xyz::with_alpha(x, y, z, alpha)
+    }
+}
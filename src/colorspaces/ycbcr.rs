@@ -0,0 +1,305 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::linear_rec2020::LinearRec2020;
+use crate::colorspaces::rec601::Rec601;
+use crate::colorspaces::rec709::{Rec709, oetf, oetf_inverse};
+use crate::colorspaces::srgb::Srgb;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Components are 8-bit code values held as f64 (Y 0.0-255.0, chroma centred on
+// 128.0); scale higher bit depths down by 2^(n - 8). The matrices act on the gamma-encoded
+// R'G'B' of the matching video space. JPEG/JFIF instead applies the BT.601 matrix to
+// sRGB-encoded values in full range, which `YCbCr601::from_jpeg` and `to_jpeg` do; the
+// `ColorSpace` impl would read those values as Rec.601 video and shift every color.
+
+/// Quantization of digital YCbCr code values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum QuantizationRange {
+    /// Full range, as in JPEG/JFIF: Y 0-255, Cb/Cr 0-255
+    #[default]
+    Full,
+    /// Studio / limited range: Y 16-235, Cb/Cr 16-240
+    Limited,
+}
+
+impl QuantizationRange {
+    /// `(offset, scale)` of Y and the scale of Cb/Cr, in 8-bit code values.
+    fn scales(&self) -> (f64, f64, f64) {
+        match self {
+            QuantizationRange::Full => (0.0, 255.0, 255.0),
+            QuantizationRange::Limited => (16.0, 219.0, 224.0),
+        }
+    }
+}
+
+/// Code value of zero chroma
+const CHROMA_OFFSET: f64 = 128.0;
+
+/// Analog Y' (0-1) and Pb/Pr (-0.5-0.5) from gamma-encoded R'G'B' with luma
+/// coefficients `kr`, `kb`.
+pub(crate) fn rgb_to_ypbpr([r, g, b]: [f64; 3], kr: f64, kb: f64) -> [f64; 3] {
+    let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+    [
+        y,
+        (b - y) / (2.0 * (1.0 - kb)),
+        (r - y) / (2.0 * (1.0 - kr)),
+    ]
+}
+
+/// Gamma-encoded R'G'B' from analog Y'PbPr with luma coefficients `kr`, `kb`.
+pub(crate) fn ypbpr_to_rgb([y, pb, pr]: [f64; 3], kr: f64, kb: f64) -> [f64; 3] {
+    let r = y + 2.0 * (1.0 - kr) * pr;
+    let b = y + 2.0 * (1.0 - kb) * pb;
+    let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+    [r, g, b]
+}
+
+/// Code values from analog Y'PbPr.
+fn quantize([y, pb, pr]: [f64; 3], range: QuantizationRange) -> [f64; 3] {
+    let (offset, y_scale, c_scale) = range.scales();
+    [
+        offset + y_scale * y,
+        CHROMA_OFFSET + c_scale * pb,
+        CHROMA_OFFSET + c_scale * pr,
+    ]
+}
+
+/// Analog Y'PbPr from code values.
+fn dequantize([y, cb, cr]: [f64; 3], range: QuantizationRange) -> [f64; 3] {
+    let (offset, y_scale, c_scale) = range.scales();
+    [
+        (y - offset) / y_scale,
+        (cb - CHROMA_OFFSET) / c_scale,
+        (cr - CHROMA_OFFSET) / c_scale,
+    ]
+}
+
+/// BT.601 luma coefficients
+pub(crate) const KR_601: f64 = 0.299;
+pub(crate) const KB_601: f64 = 0.114;
+
+/// Y'CbCr with BT.601 coefficients over Rec.601 R'G'B' (SD video)
+///
+/// JPEG/JFIF uses the same matrix over sRGB; convert those with `from_jpeg` and
+/// `to_jpeg`.
+///
+/// # Fields
+/// - y: luma code value (0.0-255.0 full range, 16.0-235.0 limited)
+/// - cb, cr: chroma code values centred on 128.0
+/// - alpha: opacity (0.0-1.0)
+/// - range: quantization of the code values
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct YCbCr601 {
+    pub y: f64,
+    pub cb: f64,
+    pub cr: f64,
+    pub alpha: f64,
+    pub range: QuantizationRange,
+}
+
+impl_constructors!(YCbCr601 { y, cb, cr }, alpha; range: QuantizationRange = QuantizationRange::Full);
+
+impl YCbCr601 {
+    /// Encode gamma-encoded R'G'B' (0.0-1.0) into the given range.
+    pub fn from_rgb_signal(rgb: [f64; 3], alpha: f64, range: QuantizationRange) -> Self {
+        let [y, cb, cr] = quantize(rgb_to_ypbpr(rgb, KR_601, KB_601), range);
+        YCbCr601::with_alpha(y, cb, cr, alpha).range(range)
+    }
+
+    /// Decode to gamma-encoded R'G'B' (0.0-1.0).
+    pub fn to_rgb_signal(&self) -> [f64; 3] {
+        ypbpr_to_rgb(
+            dequantize([self.y, self.cb, self.cr], self.range),
+            KR_601,
+            KB_601,
+        )
+    }
+
+    /// Convert a color, quantizing into the given range.
+    pub fn from_color_with(color: &Color, range: QuantizationRange) -> Self {
+        let Rec601 { r, g, b, a } = Rec601::from_color(color);
+        Self::from_rgb_signal([r, g, b], a, range)
+    }
+
+    /// Convert a color as JPEG/JFIF stores it: full range over sRGB-encoded values.
+    pub fn from_jpeg(color: &Color) -> Self {
+        let Srgb { r, g, b, a } = Srgb::from_color(color);
+        Self::from_rgb_signal([r, g, b], a, QuantizationRange::Full)
+    }
+
+    /// Decode JPEG/JFIF values, reading the R'G'B' signal as sRGB.
+    pub fn to_jpeg(&self) -> Color {
+        let [r, g, b] = self.to_rgb_signal();
+        Srgb::with_alpha(r, g, b, self.alpha).to_color()
+    }
+}
+
+impl ColorSpace for YCbCr601 {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = self.to_rgb_signal();
+        Rec601::with_alpha(r, g, b, self.alpha).to_color()
+    }
+
+    /// Converts into full range; use `from_color_with` for limited range.
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, QuantizationRange::Full)
+    }
+}
+
+/// BT.709 luma coefficients
+pub(crate) const KR_709: f64 = 0.2126;
+pub(crate) const KB_709: f64 = 0.0722;
+
+/// Y'CbCr with BT.709 coefficients over Rec.709 R'G'B' (HD video)
+///
+/// # Fields
+/// - y: luma code value (0.0-255.0 full range, 16.0-235.0 limited)
+/// - cb, cr: chroma code values centred on 128.0
+/// - alpha: opacity (0.0-1.0)
+/// - range: quantization of the code values
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct YCbCr709 {
+    pub y: f64,
+    pub cb: f64,
+    pub cr: f64,
+    pub alpha: f64,
+    pub range: QuantizationRange,
+}
+
+impl_constructors!(YCbCr709 { y, cb, cr }, alpha; range: QuantizationRange = QuantizationRange::Full);
+
+impl YCbCr709 {
+    /// Encode gamma-encoded R'G'B' (0.0-1.0) into the given range.
+    pub fn from_rgb_signal(rgb: [f64; 3], alpha: f64, range: QuantizationRange) -> Self {
+        let [y, cb, cr] = quantize(rgb_to_ypbpr(rgb, KR_709, KB_709), range);
+        YCbCr709::with_alpha(y, cb, cr, alpha).range(range)
+    }
+
+    /// Decode to gamma-encoded R'G'B' (0.0-1.0).
+    pub fn to_rgb_signal(&self) -> [f64; 3] {
+        ypbpr_to_rgb(
+            dequantize([self.y, self.cb, self.cr], self.range),
+            KR_709,
+            KB_709,
+        )
+    }
+
+    /// Convert a color, quantizing into the given range.
+    pub fn from_color_with(color: &Color, range: QuantizationRange) -> Self {
+        let Rec709 { r, g, b, a } = Rec709::from_color(color);
+        Self::from_rgb_signal([r, g, b], a, range)
+    }
+}
+
+impl ColorSpace for YCbCr709 {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = self.to_rgb_signal();
+        Rec709::with_alpha(r, g, b, self.alpha).to_color()
+    }
+
+    /// Converts into full range; use `from_color_with` for limited range.
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, QuantizationRange::Full)
+    }
+}
+
+/// BT.2020 luma coefficients
+const KR_2020: f64 = 0.2627;
+const KB_2020: f64 = 0.0593;
+
+// BT.2020 constant-luminance chroma divisors, for negative and positive differences
+const CL_CB_NEGATIVE: f64 = 1.9404;
+const CL_CB_POSITIVE: f64 = 1.5816;
+const CL_CR_NEGATIVE: f64 = 1.7184;
+const CL_CR_POSITIVE: f64 = 0.9936;
+
+/// How BT.2020 forms luma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LuminanceMode {
+    /// Y'CbCr: luma is a weighted sum of gamma-encoded R'G'B' (the common case)
+    #[default]
+    NonConstant,
+    /// Y'cCbcCrc: luma is the encoded linear luminance, chroma uses split divisors
+    Constant,
+}
+
+/// Y'CbCr with BT.2020 coefficients over Rec.2020, non-constant or constant luminance
+///
+/// The R'G'B' signal uses the BT.2020 camera OETF (the BT.709 curve), not the display
+/// gamma of `Rec2020`.
+///
+/// # Fields
+/// - y: luma code value (0.0-255.0 full range, 16.0-235.0 limited)
+/// - cb, cr: chroma code values centred on 128.0
+/// - alpha: opacity (0.0-1.0)
+/// - range: quantization of the code values
+/// - mode: non-constant (Y'CbCr) or constant (Y'cCbcCrc) luminance
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct YCbCr2020 {
+    pub y: f64,
+    pub cb: f64,
+    pub cr: f64,
+    pub alpha: f64,
+    pub range: QuantizationRange,
+    pub mode: LuminanceMode,
+}
+
+impl_constructors!(
+    YCbCr2020 { y, cb, cr }, alpha;
+    range: QuantizationRange = QuantizationRange::Full,
+    mode: LuminanceMode = LuminanceMode::NonConstant
+);
+
+/// Divide a constant-luminance color difference by the divisor for its sign.
+fn cl_chroma(diff: f64, negative: f64, positive: f64) -> f64 {
+    if diff <= 0.0 {
+        diff / negative
+    } else {
+        diff / positive
+    }
+}
+
+impl YCbCr2020 {
+    /// Convert a color, quantizing into the given range with the given luminance mode.
+    pub fn from_color_with(color: &Color, range: QuantizationRange, mode: LuminanceMode) -> Self {
+        let LinearRec2020 { r, g, b, a } = LinearRec2020::from_color(color);
+        let ypbpr = match mode {
+            LuminanceMode::NonConstant => rgb_to_ypbpr([r, g, b].map(oetf), KR_2020, KB_2020),
+            LuminanceMode::Constant => {
+                let y = oetf(KR_2020 * r + (1.0 - KR_2020 - KB_2020) * g + KB_2020 * b);
+                [
+                    y,
+                    cl_chroma(oetf(b) - y, CL_CB_NEGATIVE, CL_CB_POSITIVE),
+                    cl_chroma(oetf(r) - y, CL_CR_NEGATIVE, CL_CR_POSITIVE),
+                ]
+            }
+        };
+        let [y, cb, cr] = quantize(ypbpr, range);
+        YCbCr2020::with_alpha(y, cb, cr, a).range(range).mode(mode)
+    }
+}
+
+impl ColorSpace for YCbCr2020 {
+    fn to_color(&self) -> Color {
+        let ypbpr = dequantize([self.y, self.cb, self.cr], self.range);
+        let [r, g, b] = match self.mode {
+            LuminanceMode::NonConstant => ypbpr_to_rgb(ypbpr, KR_2020, KB_2020).map(oetf_inverse),
+            LuminanceMode::Constant => {
+                let [y, pb, pr] = ypbpr;
+                let scale = |c: f64, negative: f64, positive: f64| {
+                    if c <= 0.0 { c * negative } else { c * positive }
+                };
+                let b = oetf_inverse(y + scale(pb, CL_CB_NEGATIVE, CL_CB_POSITIVE));
+                let r = oetf_inverse(y + scale(pr, CL_CR_NEGATIVE, CL_CR_POSITIVE));
+                let g = (oetf_inverse(y) - KR_2020 * r - KB_2020 * b) / (1.0 - KR_2020 - KB_2020);
+                [r, g, b]
+            }
+        };
+        LinearRec2020::with_alpha(r, g, b, self.alpha).to_color()
+    }
+
+    /// Converts into full range, non-constant luminance; use `from_color_with` otherwise.
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, QuantizationRange::Full, LuminanceMode::NonConstant)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::srgb::Srgb;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Both transforms act on sRGB-encoded values, as codecs store them. `YCoCgR`
+// works on integer code values and is exactly reversible; its color conversions round
+// through 8-bit sRGB and carry no alpha, so it does not implement `ColorSpace`.
+
+/// YCoCg: luma with orange and green chroma, a cheap integer-friendly transform of sRGB
+///
+/// # Fields
+/// - y: luma (0.0-1.0)
+/// - co: orange chroma (-0.5-0.5)
+/// - cg: green chroma (-0.5-0.5)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct YCoCg {
+    pub y: f64,
+    pub co: f64,
+    pub cg: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(YCoCg { y, co, cg }, alpha);
+
+impl ColorSpace for YCoCg {
+    fn to_color(&self) -> Color {
+        let t = self.y - self.cg;
+        Srgb::with_alpha(t + self.co, self.y + self.cg, t - self.co, self.alpha).to_color()
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let Srgb { r, g, b, a } = Srgb::from_color(color);
+        YCoCg::with_alpha(
+            0.25 * r + 0.5 * g + 0.25 * b,
+            0.5 * r - 0.5 * b,
+            -0.25 * r + 0.5 * g - 0.25 * b,
+            a,
+        )
+    }
+}
+
+/// YCoCg-R: the lossless lifting form of YCoCg on integer code values
+///
+/// For n-bit RGB, `y` needs n bits and `co` / `cg` need n + 1 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct YCoCgR {
+    pub y: i32,
+    pub co: i32,
+    pub cg: i32,
+}
+
+impl YCoCgR {
+    /// Construct from components.
+    pub fn new(y: i32, co: i32, cg: i32) -> Self {
+        Self { y, co, cg }
+    }
+
+    /// Forward lifting transform of integer RGB code values.
+    pub fn from_rgb([r, g, b]: [i32; 3]) -> Self {
+        let co = r - b;
+        let t = b + (co >> 1);
+        let cg = g - t;
+        let y = t + (cg >> 1);
+        Self { y, co, cg }
+    }
+
+    /// Inverse lifting transform; exactly undoes `from_rgb`.
+    pub fn to_rgb(&self) -> [i32; 3] {
+        let t = self.y - (self.cg >> 1);
+        let g = self.cg + t;
+        let b = t - (self.co >> 1);
+        [b + self.co, g, b]
+    }
+
+    /// Transform of a color rounded to 8-bit sRGB.
+    pub fn from_color(color: &Color) -> Self {
+        let Srgb { r, g, b, .. } = Srgb::from_color(color);
+        Self::from_rgb([r, g, b].map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as i32))
+    }
+
+    /// The 8-bit sRGB color these values decode to, with the given alpha.
+    pub fn to_color(&self, alpha: f64) -> Color {
+        let [r, g, b] = self.to_rgb().map(|v| v as f64 / 255.0);
+        Srgb::with_alpha(r, g, b, alpha).to_color()
+    }
+}
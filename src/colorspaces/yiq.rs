@@ -0,0 +1,63 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::rec601::Rec601;
+use crate::math::Mat3;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The FCC NTSC matrix acts on gamma-encoded Rec.601 R'G'B'. Emulators that treat a
+// framebuffer as sRGB can use `from_rgb_signal` / `to_rgb_signal` with `Srgb` instead.
+// The inverse is the exact inverse of the rounded forward matrix, so values round-trip.
+
+/// YIQ (NTSC): luma with in-phase and quadrature chroma
+///
+/// # Fields
+/// - y: luma (0.0-1.0)
+/// - i: in-phase, orange-blue axis (-0.5959-0.5959)
+/// - q: quadrature, purple-green axis (-0.5227-0.5227)
+/// - alpha: opacity (0.0-1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Yiq {
+    pub y: f64,
+    pub i: f64,
+    pub q: f64,
+    pub alpha: f64,
+}
+
+impl_constructors!(Yiq { y, i, q }, alpha);
+
+const RGB_TO_YIQ: Mat3 = Mat3([
+    [0.299, 0.587, 0.114],
+    [0.5959, -0.2746, -0.3213],
+    [0.2115, -0.5227, 0.3112],
+]);
+
+const YIQ_TO_RGB: Mat3 = Mat3([
+    [1.0, 0.9560502263958942, 0.6207549413271235],
+    [1.0, -0.27205234368892417, -0.6472057134551777],
+    [1.0, -1.1067043153243326, 1.7044212836963109],
+]);
+
+impl Yiq {
+    /// Encode gamma-encoded R'G'B' (0.0-1.0).
+    pub fn from_rgb_signal(rgb: [f64; 3], alpha: f64) -> Self {
+        let [y, i, q] = RGB_TO_YIQ.apply(rgb);
+        Yiq::with_alpha(y, i, q, alpha)
+    }
+
+    /// Decode to gamma-encoded R'G'B' (0.0-1.0).
+    pub fn to_rgb_signal(&self) -> [f64; 3] {
+        YIQ_TO_RGB.apply([self.y, self.i, self.q])
+    }
+}
+
+impl ColorSpace for Yiq {
+    fn to_color(&self) -> Color {
+        let [r, g, b] = self.to_rgb_signal();
+        Rec601::with_alpha(r, g, b, self.alpha).to_color()
+    }
+
+    fn from_color(color: &Color) -> Self {
+        let Rec601 { r, g, b, a } = Rec601::from_color(color);
+        Self::from_rgb_signal([r, g, b], a)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::rec601::Rec601;
+use crate::colorspaces::rec709::Rec709;
+use crate::colorspaces::ycbcr::{KB_601, KB_709, KR_601, KR_709, rgb_to_ypbpr, ypbpr_to_rgb};
+use serde::{Deserialize, Serialize};
+
+/// Luma coefficients and R'G'B' encoding of a Y'PbPr signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum YPbPrStandard {
+    /// BT.601 coefficients over Rec.601 R'G'B' (SD)
+    Bt601,
+    /// BT.709 coefficients over Rec.709 R'G'B' (HD)
+    #[default]
+    Bt709,
+}
+
+impl YPbPrStandard {
+    fn coefficients(&self) -> (f64, f64) {
+        match self {
+            YPbPrStandard::Bt601 => (KR_601, KB_601),
+            YPbPrStandard::Bt709 => (KR_709, KB_709),
+        }
+    }
+}
+
+/// Y'PbPr: analog component video, unquantized Y'CbCr
+///
+/// # Fields
+/// - y: luma (0.0-1.0)
+/// - pb, pr: blue and red color differences (-0.5-0.5)
+/// - alpha: opacity (0.0-1.0)
+/// - standard: coefficients and R'G'B' encoding
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct YPbPr {
+    pub y: f64,
+    pub pb: f64,
+    pub pr: f64,
+    pub alpha: f64,
+    pub standard: YPbPrStandard,
+}
+
+impl_constructors!(YPbPr { y, pb, pr }, alpha; standard: YPbPrStandard = YPbPrStandard::Bt709);
+
+impl YPbPr {
+    /// Convert a color with the given standard's coefficients.
+    pub fn from_color_with(color: &Color, standard: YPbPrStandard) -> Self {
+        let (kr, kb) = standard.coefficients();
+        let (rgb, a) = match standard {
+            YPbPrStandard::Bt601 => {
+                let Rec601 { r, g, b, a } = Rec601::from_color(color);
+                ([r, g, b], a)
+            }
+            YPbPrStandard::Bt709 => {
+                let Rec709 { r, g, b, a } = Rec709::from_color(color);
+                ([r, g, b], a)
+            }
+        };
+        let [y, pb, pr] = rgb_to_ypbpr(rgb, kr, kb);
+        YPbPr::with_alpha(y, pb, pr, a).standard(standard)
+    }
+}
+
+impl ColorSpace for YPbPr {
+    fn to_color(&self) -> Color {
+        let (kr, kb) = self.standard.coefficients();
+        let [r, g, b] = ypbpr_to_rgb([self.y, self.pb, self.pr], kr, kb);
+        match self.standard {
+            YPbPrStandard::Bt601 => Rec601::with_alpha(r, g, b, self.alpha).to_color(),
+            YPbPrStandard::Bt709 => Rec709::with_alpha(r, g, b, self.alpha).to_color(),
+        }
+    }
+
+    /// Converts with BT.709 coefficients; use `from_color_with` for BT.601.
+    fn from_color(color: &Color) -> Self {
+        Self::from_color_with(color, YPbPrStandard::Bt709)
+    }
+}
@@ -0,0 +1,206 @@
+//! Text contrast metrics.
+//!
+//! WCAG 2.x contrast is computed from `Color::relative_luminance`, which follows the
+//! WCAG definition on the color as an sRGB display shows it. The ratio is symmetric and
+//! runs from 1 (no contrast) to 21 (black on white).
+//!
+//! APCA (the WCAG 3 draft candidate, version 0.0.98G-4g) gives a signed lightness
+//! contrast Lc that depends on polarity: positive for dark text on a light background,
+//! negative for light text on a dark one, roughly -108 to 106.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::oklch::Oklch;
+use crate::colorspaces::srgb::Srgb;
+use crate::palette::in_gamut_oklch;
+use serde::{Deserialize, Serialize};
+
+/// Minimum ratio for WCAG AA body text
+pub const AA_RATIO: f64 = 4.5;
+/// Minimum ratio for WCAG AA large text (18 pt, or 14 pt bold)
+pub const AA_LARGE_RATIO: f64 = 3.0;
+/// Minimum ratio for WCAG AAA body text
+pub const AAA_RATIO: f64 = 7.0;
+/// Minimum ratio for WCAG AAA large text
+pub const AAA_LARGE_RATIO: f64 = 4.5;
+
+/// WCAG 2.x contrast ratio of two colors, in [1, 21].
+pub fn wcag_contrast_ratio(a: &Color, b: &Color) -> f64 {
+    let (la, lb) = (a.relative_luminance(), b.relative_luminance());
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Whether `fg` on `bg` meets WCAG AA for body text.
+pub fn meets_aa(fg: &Color, bg: &Color) -> bool {
+    wcag_contrast_ratio(fg, bg) >= AA_RATIO
+}
+
+/// Whether `fg` on `bg` meets WCAG AA for large text.
+pub fn meets_aa_large(fg: &Color, bg: &Color) -> bool {
+    wcag_contrast_ratio(fg, bg) >= AA_LARGE_RATIO
+}
+
+/// Whether `fg` on `bg` meets WCAG AAA for body text.
+pub fn meets_aaa(fg: &Color, bg: &Color) -> bool {
+    wcag_contrast_ratio(fg, bg) >= AAA_RATIO
+}
+
+/// Whether `fg` on `bg` meets WCAG AAA for large text.
+pub fn meets_aaa_large(fg: &Color, bg: &Color) -> bool {
+    wcag_contrast_ratio(fg, bg) >= AAA_LARGE_RATIO
+}
+
+/// Bisection steps when searching Oklch lightness for a contrast target
+const SEARCH_ITERATIONS: usize = 48;
+
+// APCA 0.0.98G-4g constants
+const APCA_TRC: f64 = 2.4;
+const APCA_COEFFICIENTS: [f64; 3] = [0.2126729, 0.7151522, 0.0721750];
+const APCA_NORMAL_BG: f64 = 0.56;
+const APCA_NORMAL_TEXT: f64 = 0.57;
+const APCA_REVERSE_BG: f64 = 0.65;
+const APCA_REVERSE_TEXT: f64 = 0.62;
+const APCA_BLACK_THRESHOLD: f64 = 0.022;
+const APCA_BLACK_CLAMP: f64 = 1.414;
+const APCA_SCALE: f64 = 1.14;
+const APCA_OFFSET: f64 = 0.027;
+const APCA_DELTA_Y_MIN: f64 = 0.0005;
+const APCA_LOW_CLIP: f64 = 0.1;
+
+/// APCA screen luminance, with the soft clamp near black.
+fn apca_luminance(color: &Color) -> f64 {
+    let Srgb { r, g, b, .. } = Srgb::from_color(color);
+    let [r, g, b] = [r, g, b].map(|v| v.clamp(0.0, 1.0).powf(APCA_TRC));
+    let y = APCA_COEFFICIENTS[0] * r + APCA_COEFFICIENTS[1] * g + APCA_COEFFICIENTS[2] * b;
+    if y < APCA_BLACK_THRESHOLD {
+        y + (APCA_BLACK_THRESHOLD - y).powf(APCA_BLACK_CLAMP)
+    } else {
+        y
+    }
+}
+
+/// APCA lightness contrast Lc of `text` on `background`.
+///
+/// Swapping the arguments changes the result, not just its sign. |Lc| 75 is the
+/// suggested minimum for body text, 60 for larger text and 45 for headlines.
+pub fn apca_contrast(text: &Color, background: &Color) -> f64 {
+    let y_text = apca_luminance(text);
+    let y_bg = apca_luminance(background);
+    if (y_bg - y_text).abs() < APCA_DELTA_Y_MIN {
+        return 0.0;
+    }
+
+    let lc = if y_bg > y_text {
+        // Dark text on a light background
+        let sapc = (y_bg.powf(APCA_NORMAL_BG) - y_text.powf(APCA_NORMAL_TEXT)) * APCA_SCALE;
+        if sapc < APCA_LOW_CLIP {
+            0.0
+        } else {
+            sapc - APCA_OFFSET
+        }
+    } else {
+        let sapc = (y_bg.powf(APCA_REVERSE_BG) - y_text.powf(APCA_REVERSE_TEXT)) * APCA_SCALE;
+        if sapc > -APCA_LOW_CLIP {
+            0.0
+        } else {
+            sapc + APCA_OFFSET
+        }
+    };
+    lc * 100.0
+}
+
+/// Contrast measure used to rank or adjust text colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ContrastMetric {
+    /// WCAG 2.x contrast ratio
+    #[default]
+    Wcag,
+    /// APCA |Lc|, ignoring polarity
+    Apca,
+}
+
+impl ContrastMetric {
+    /// Contrast of `text` on `background`; larger is more readable for both metrics.
+    pub fn contrast(&self, text: &Color, background: &Color) -> f64 {
+        match self {
+            ContrastMetric::Wcag => wcag_contrast_ratio(text, background),
+            ContrastMetric::Apca => apca_contrast(text, background).abs(),
+        }
+    }
+}
+
+/// The candidate with the highest contrast against `background`.
+///
+/// With no candidates, picks between black and white. Ties go to the earlier candidate.
+pub fn pick_readable_text_color(
+    background: &Color,
+    candidates: &[Color],
+    metric: ContrastMetric,
+) -> Color {
+    let defaults = [Color::opaque(0.0, 0.0, 0.0), Color::opaque(1.0, 1.0, 1.0)];
+    let candidates = if candidates.is_empty() {
+        &defaults[..]
+    } else {
+        candidates
+    };
+    let mut best = candidates[0];
+    let mut best_contrast = metric.contrast(&best, background);
+    for candidate in &candidates[1..] {
+        let contrast = metric.contrast(candidate, background);
+        if contrast > best_contrast {
+            (best, best_contrast) = (*candidate, contrast);
+        }
+    }
+    best
+}
+
+/// Adjust `text` until its contrast against `background` reaches `target` (a WCAG ratio
+/// or an APCA |Lc|, by `metric`).
+///
+/// Only Oklch lightness is searched, lighter and darker, and the smaller change that
+/// meets the target wins. Hue is kept and chroma is reduced only as far as needed to stay
+/// in sRGB. Returns `text` unchanged if it already meets the target, and `None` if no
+/// lightness at that hue does, as when the target is beyond black or white.
+pub fn ensure_contrast(
+    text: &Color,
+    background: &Color,
+    target: f64,
+    metric: ContrastMetric,
+) -> Option<Color> {
+    if metric.contrast(text, background) >= target {
+        return Some(*text);
+    }
+    let base = Oklch::from_color(text);
+    let at = |l: f64| Color {
+        a: text.a,
+        ..in_gamut_oklch(l, base.c, base.h)
+    };
+    let meets = |l: f64| metric.contrast(&at(l), background) >= target;
+
+    // Contrast falls and then rises (if at all) as lightness moves from the text towards
+    // either end, so each direction crosses the target at most once
+    let search = |end: f64| {
+        if !meets(end) {
+            return None;
+        }
+        let (mut from, mut to) = (base.l, end);
+        for _ in 0..SEARCH_ITERATIONS {
+            let mid = 0.5 * (from + to);
+            if meets(mid) {
+                to = mid;
+            } else {
+                from = mid;
+            }
+        }
+        Some(to)
+    };
+    let best = match (search(1.0), search(0.0)) {
+        (Some(up), Some(down)) => Some(if up - base.l <= base.l - down {
+            up
+        } else {
+            down
+        }),
+        (up, down) => up.or(down),
+    };
+    best.map(at)
+}
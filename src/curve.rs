@@ -0,0 +1,228 @@
+//! Tone curves and 1D LUTs.
+//!
+//! A `Curve` maps one scalar to another. It can be applied to all channels alike (via
+//! its `Transform` impl), per channel with `ChannelCurves` (e.g. the three VCGT curves
+//! of a display calibration), or to luminance with `LuminanceCurve`. Curves can be
+//! inverted and chained, and every form can be baked to a sampled 1D LUT.
+
+use crate::pipeline::Transform;
+use serde::{Deserialize, Serialize};
+
+const EPSILON: f64 = 1e-10;
+
+/// A scalar transfer curve.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Curve {
+    /// Samples spaced uniformly over [0, 1], linearly interpolated; input is clamped
+    Sampled(Vec<f64>),
+    /// VCGT-style formula `min + (max - min) * x^gamma`, mirrored for negative x
+    Power { gamma: f64, min: f64, max: f64 },
+    /// The inverse of another curve, evaluated numerically for sampled curves
+    Inverse(Box<Curve>),
+    /// Curves applied in order
+    Chain(Vec<Curve>),
+}
+
+impl Curve {
+    /// A curve that leaves values unchanged.
+    pub fn identity() -> Self {
+        Curve::Chain(Vec::new())
+    }
+
+    /// Pure power curve `x^gamma`.
+    pub fn gamma(gamma: f64) -> Self {
+        Curve::Power {
+            gamma,
+            min: 0.0,
+            max: 1.0,
+        }
+    }
+
+    /// Build a sampled curve by evaluating `f` at `n` uniform points in [0, 1].
+    pub fn from_fn<F: Fn(f64) -> f64>(n: usize, f: F) -> Self {
+        let n = n.max(2);
+        Curve::Sampled((0..n).map(|i| f(i as f64 / (n - 1) as f64)).collect())
+    }
+
+    /// Evaluate the curve.
+    pub fn eval(&self, x: f64) -> f64 {
+        match self {
+            Curve::Sampled(table) => sample(table, x),
+            Curve::Power { gamma, min, max } => {
+                min + (max - min) * x.signum() * x.abs().powf(*gamma)
+            }
+            Curve::Inverse(inner) => inner.eval_inverse(x),
+            Curve::Chain(curves) => curves.iter().fold(x, |x, c| c.eval(x)),
+        }
+    }
+
+    fn eval_inverse(&self, y: f64) -> f64 {
+        match self {
+            Curve::Sampled(table) => sample_inverse(table, y),
+            Curve::Power { gamma, min, max } => {
+                let t = (y - min) / (max - min);
+                t.signum() * t.abs().powf(1.0 / gamma)
+            }
+            Curve::Inverse(inner) => inner.eval(y),
+            Curve::Chain(curves) => curves.iter().rev().fold(y, |y, c| c.eval_inverse(y)),
+        }
+    }
+
+    /// Whether the curve is strictly monotonic and can therefore be inverted.
+    pub fn is_invertible(&self) -> bool {
+        match self {
+            Curve::Sampled(table) => {
+                table.windows(2).all(|w| w[1] > w[0]) || table.windows(2).all(|w| w[1] < w[0])
+            }
+            Curve::Power { gamma, min, max } => *gamma > 0.0 && (max - min).abs() > EPSILON,
+            Curve::Inverse(inner) => inner.is_invertible(),
+            Curve::Chain(curves) => curves.iter().all(Curve::is_invertible),
+        }
+    }
+
+    /// The inverse curve, or `None` if the curve is not strictly monotonic.
+    pub fn inverse(&self) -> Option<Curve> {
+        if !self.is_invertible() {
+            return None;
+        }
+        Some(match self {
+            Curve::Inverse(inner) => (**inner).clone(),
+            Curve::Chain(curves) => {
+                Curve::Chain(curves.iter().rev().filter_map(Curve::inverse).collect())
+            }
+            other => Curve::Inverse(Box::new(other.clone())),
+        })
+    }
+
+    /// A curve applying `self` first and then `next`.
+    pub fn then(&self, next: &Curve) -> Curve {
+        let mut curves = match self {
+            Curve::Chain(curves) => curves.clone(),
+            other => vec![other.clone()],
+        };
+        match next {
+            Curve::Chain(more) => curves.extend(more.iter().cloned()),
+            other => curves.push(other.clone()),
+        }
+        Curve::Chain(curves)
+    }
+
+    /// Bake the curve into a sampled 1D LUT with `n` entries.
+    pub fn to_sampled(&self, n: usize) -> Curve {
+        Curve::from_fn(n, |x| self.eval(x))
+    }
+}
+
+fn sample(table: &[f64], x: f64) -> f64 {
+    match table.len() {
+        0 => x,
+        1 => table[0],
+        n => {
+            let max = (n - 1) as f64;
+            let pos = (x * max).clamp(0.0, max);
+            let i = (pos.floor() as usize).min(n - 2);
+            let t = pos - i as f64;
+            table[i] + (table[i + 1] - table[i]) * t
+        }
+    }
+}
+
+fn sample_inverse(table: &[f64], y: f64) -> f64 {
+    let n = table.len();
+    if n < 2 {
+        return y;
+    }
+    let increasing = table[n - 1] >= table[0];
+    // Clamp to the table's range, then find the segment that brackets y
+    let (lo, hi) = if increasing {
+        (table[0], table[n - 1])
+    } else {
+        (table[n - 1], table[0])
+    };
+    let y = y.clamp(lo, hi);
+    let i = if increasing {
+        table.partition_point(|&v| v < y)
+    } else {
+        table.partition_point(|&v| v > y)
+    }
+    .clamp(1, n - 1);
+    let (a, b) = (table[i - 1], table[i]);
+    let t = if (b - a).abs() < EPSILON {
+        0.0
+    } else {
+        (y - a) / (b - a)
+    };
+    ((i - 1) as f64 + t) / (n - 1) as f64
+}
+
+impl Transform for Curve {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        rgb.map(|c| self.eval(c))
+    }
+}
+
+/// Separate curves for the red, green and blue channels.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChannelCurves {
+    pub r: Curve,
+    pub g: Curve,
+    pub b: Curve,
+}
+
+impl ChannelCurves {
+    /// Use the same curve for all three channels.
+    pub fn uniform(curve: Curve) -> Self {
+        Self {
+            r: curve.clone(),
+            g: curve.clone(),
+            b: curve,
+        }
+    }
+
+    /// Per-channel inverse, or `None` if any channel is not invertible.
+    pub fn inverse(&self) -> Option<Self> {
+        Some(Self {
+            r: self.r.inverse()?,
+            g: self.g.inverse()?,
+            b: self.b.inverse()?,
+        })
+    }
+
+    /// Apply `self` and then `next`, channel by channel.
+    pub fn then(&self, next: &ChannelCurves) -> Self {
+        Self {
+            r: self.r.then(&next.r),
+            g: self.g.then(&next.g),
+            b: self.b.then(&next.b),
+        }
+    }
+}
+
+impl Transform for ChannelCurves {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        [
+            self.r.eval(rgb[0]),
+            self.g.eval(rgb[1]),
+            self.b.eval(rgb[2]),
+        ]
+    }
+}
+
+/// A curve applied to relative luminance, scaling RGB to preserve chromaticity.
+///
+/// Luminance uses the Rec.709 / sRGB weights, so input should be linear sRGB.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LuminanceCurve(pub Curve);
+
+impl Transform for LuminanceCurve {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let y = 0.2126 * rgb[0] + 0.7152 * rgb[1] + 0.0722 * rgb[2];
+        if y.abs() < EPSILON {
+            // Black has no chromaticity to preserve; map it as a neutral gray
+            let v = self.0.eval(0.0);
+            return [v, v, v];
+        }
+        let scale = self.0.eval(y) / y;
+        rgb.map(|c| c * scale)
+    }
+}
@@ -0,0 +1,306 @@
+//! Color difference metrics.
+//!
+//! Each metric takes values in the space it is defined on; the `_between` variants take
+//! any two `ColorSpace` values and convert them first. Results are in the units of the
+//! metric's space, so thresholds do not carry over between metrics.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::din99::Din99;
+use crate::colorspaces::ictcp::Ictcp;
+use crate::colorspaces::lab::Lab;
+use crate::colorspaces::oklab::Oklab;
+use crate::colorspaces::oklch::Oklch;
+use crate::hdr::LuminanceContext;
+use serde::{Deserialize, Serialize};
+
+/// Weighting constants for `delta_e_1994`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Cie94Application {
+    /// kL = 1, K1 = 0.045, K2 = 0.015
+    #[default]
+    GraphicArts,
+    /// kL = 2, K1 = 0.048, K2 = 0.014
+    Textiles,
+}
+
+impl Cie94Application {
+    /// (kL, K1, K2)
+    pub(crate) fn constants(&self) -> (f64, f64, f64) {
+        match self {
+            Cie94Application::GraphicArts => (1.0, 0.045, 0.015),
+            Cie94Application::Textiles => (2.0, 0.048, 0.014),
+        }
+    }
+}
+
+/// CIE 1976 ΔE*ab: Euclidean distance in CIELAB.
+///
+/// About 2.3 is the commonly quoted just-noticeable difference.
+pub fn delta_e_1976(a: &Lab, b: &Lab) -> f64 {
+    let dl = a.l - b.l;
+    let da = a.a - b.a;
+    let db = a.b - b.b;
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// `delta_e_1976` of two colors in any spaces.
+pub fn delta_e_1976_between(a: &impl ColorSpace, b: &impl ColorSpace) -> f64 {
+    delta_e_1976(&to_lab(a), &to_lab(b))
+}
+
+/// CIE 1994 ΔE*94.
+///
+/// The weights depend on the chroma of `reference`, so swapping the arguments changes the
+/// result slightly.
+pub fn delta_e_1994(reference: &Lab, sample: &Lab, application: Cie94Application) -> f64 {
+    let (kl, k1, k2) = application.constants();
+    let c1 = reference.a.hypot(reference.b);
+    let c2 = sample.a.hypot(sample.b);
+    let dl = reference.l - sample.l;
+    let dc = c1 - c2;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    // ΔH² from the remainder of ΔE*ab², which rounding can push slightly negative
+    let dh2 = (da * da + db * db - dc * dc).max(0.0);
+
+    let sc = 1.0 + k1 * c1;
+    let sh = 1.0 + k2 * c1;
+    ((dl / kl).powi(2) + (dc / sc).powi(2) + dh2 / (sh * sh)).sqrt()
+}
+
+/// `delta_e_1994` of two colors in any spaces.
+pub fn delta_e_1994_between(
+    reference: &impl ColorSpace,
+    sample: &impl ColorSpace,
+    application: Cie94Application,
+) -> f64 {
+    delta_e_1994(&to_lab(reference), &to_lab(sample), application)
+}
+
+/// CIEDE2000 ΔE00, with the parametric factors kL = kC = kH = 1.
+///
+/// Symmetric in its arguments. Matches the Sharma, Wu and Dalal (2005) reference data,
+/// including their conventions for the mean hue when the hues are 180° apart.
+pub fn delta_e_2000(a: &Lab, b: &Lab) -> f64 {
+    let c_mean = 0.5 * (a.a.hypot(a.b) + b.a.hypot(b.b));
+    let c7 = c_mean.powi(7);
+    let g = 0.5 * (1.0 - (c7 / (c7 + 25f64.powi(7))).sqrt());
+
+    // Chroma and hue after stretching a*
+    let a1 = (1.0 + g) * a.a;
+    let a2 = (1.0 + g) * b.a;
+    let c1 = a1.hypot(a.b);
+    let c2 = a2.hypot(b.b);
+    let h1 = hue_degrees(a.b, a1);
+    let h2 = hue_degrees(b.b, a2);
+
+    let dl = b.l - a.l;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 <= h1 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1 - 360.0
+    };
+    let dh_big = 2.0 * (c1 * c2).sqrt() * (dh.to_radians() / 2.0).sin();
+
+    let l_mean = 0.5 * (a.l + b.l);
+    let c_mean = 0.5 * (c1 + c2);
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        0.5 * (h1 + h2)
+    } else if h1 + h2 < 360.0 {
+        0.5 * (h1 + h2 + 360.0)
+    } else {
+        0.5 * (h1 + h2 - 360.0)
+    };
+
+    let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_mean).to_radians().cos()
+        + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+    let l50 = (l_mean - 50.0).powi(2);
+    let sl = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+    let sc = 1.0 + 0.045 * c_mean;
+    let sh = 1.0 + 0.015 * c_mean * t;
+
+    // Hue rotation term, which tilts the ellipses in the blue region
+    let d_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let c7 = c_mean.powi(7);
+    let rc = 2.0 * (c7 / (c7 + 25f64.powi(7))).sqrt();
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+    let (l, c, h) = (dl / sl, dc / sc, dh_big / sh);
+    (l * l + c * c + h * h + rt * c * h).sqrt()
+}
+
+/// `delta_e_2000` of two colors in any spaces.
+pub fn delta_e_2000_between(a: &impl ColorSpace, b: &impl ColorSpace) -> f64 {
+    delta_e_2000(&to_lab(a), &to_lab(b))
+}
+
+/// ΔEOK: Euclidean distance in Oklab, with L in [0, 1].
+///
+/// CSS Color 4 gamut mapping treats 0.02 as the just-noticeable difference.
+pub fn delta_e_ok(a: &Oklab, b: &Oklab) -> f64 {
+    let dl = a.l - b.l;
+    let da = a.a - b.a;
+    let db = a.b - b.b;
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// `delta_e_ok` of two Oklch values, without converting them to Oklab.
+pub fn delta_e_ok_lch(a: &Oklch, b: &Oklch) -> f64 {
+    let dl = a.l - b.l;
+    let dh = (a.h - b.h).to_radians();
+    // Law of cosines on the two chroma vectors
+    let dab2 = a.c * a.c + b.c * b.c - 2.0 * a.c * b.c * dh.cos();
+    (dl * dl + dab2.max(0.0)).sqrt()
+}
+
+/// `delta_e_ok` scaled by 100, matching CSS Color 4's percentage form of Oklab so that
+/// values sit on roughly the same scale as ΔE*ab and ΔE00 (a JND of about 2).
+pub fn delta_e_ok_scaled(a: &Oklab, b: &Oklab) -> f64 {
+    100.0 * delta_e_ok(a, b)
+}
+
+/// `delta_e_ok` of two colors in any spaces.
+pub fn delta_e_ok_between(a: &impl ColorSpace, b: &impl ColorSpace) -> f64 {
+    delta_e_ok(&to_oklab(a), &to_oklab(b))
+}
+
+/// HyAB (Abasi et al. 2020): |ΔL*| plus the Euclidean a*b* distance in CIELAB.
+///
+/// Tracks perceived differences better than ΔE*ab once they are large, as between
+/// palette entries.
+pub fn hyab(a: &Lab, b: &Lab) -> f64 {
+    (a.l - b.l).abs() + (a.a - b.a).hypot(a.b - b.b)
+}
+
+/// HyAB measured in Oklab instead of CIELAB, with L in [0, 1].
+pub fn hyab_ok(a: &Oklab, b: &Oklab) -> f64 {
+    (a.l - b.l).abs() + (a.a - b.a).hypot(a.b - b.b)
+}
+
+/// `hyab` of two colors in any spaces.
+pub fn hyab_between(a: &impl ColorSpace, b: &impl ColorSpace) -> f64 {
+    hyab(&to_lab(a), &to_lab(b))
+}
+
+/// ΔEITP (ITU-R BT.2124) between two ICtCp values.
+///
+/// 1.0 is roughly one just-noticeable difference under the BT.2124 viewing conditions,
+/// across the whole PQ range.
+pub fn delta_e_itp(a: &Ictcp, b: &Ictcp) -> f64 {
+    let di = a.i - b.i;
+    // T is half of Ct; P is Cp
+    let dt = 0.5 * (a.ct - b.ct);
+    let dp = a.cp - b.cp;
+    720.0 * (di * di + dt * dt + dp * dp).sqrt()
+}
+
+/// `delta_e_itp` of two colors in any spaces, with linear 1.0 at
+/// `context.diffuse_white` nits.
+pub fn delta_e_itp_between(
+    a: &impl ColorSpace,
+    b: &impl ColorSpace,
+    context: &LuminanceContext,
+) -> f64 {
+    delta_e_itp(
+        &Ictcp::from_color_with(&a.to_color(), context),
+        &Ictcp::from_color_with(&b.to_color(), context),
+    )
+}
+
+/// DIN99 ΔE99 of two CIELAB colors, with the weighting factors kE (lightness) and kCH
+/// (chroma and hue); 1 and 1 for the reference conditions.
+pub fn delta_e_din99(a: &Lab, b: &Lab, ke: f64, kch: f64) -> f64 {
+    Din99::from_lab_with(a, ke, kch).delta_e(&Din99::from_lab_with(b, ke, kch))
+}
+
+/// `delta_e_din99` with kE = kCH = 1 of two colors in any spaces.
+pub fn delta_e_din99_between(a: &impl ColorSpace, b: &impl ColorSpace) -> f64 {
+    delta_e_din99(&to_lab(a), &to_lab(b), 1.0, 1.0)
+}
+
+/// A color difference metric chosen at runtime, for APIs that take one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// `delta_e_1976`
+    DeltaE76,
+    /// `delta_e_1994`, with the first color as the reference
+    DeltaE94(Cie94Application),
+    /// `delta_e_2000`
+    DeltaE2000,
+    /// `delta_e_ok`
+    #[default]
+    DeltaEOk,
+    /// `hyab` in CIELAB
+    HyAb,
+    /// `delta_e_din99` with kE = kCH = 1
+    Din99,
+    /// `delta_e_itp` with the default `LuminanceContext`
+    DeltaEItp,
+}
+
+impl DistanceMetric {
+    /// Difference between two colors.
+    pub fn distance(&self, a: &Color, b: &Color) -> f64 {
+        match self {
+            DistanceMetric::DeltaE94(application) => {
+                delta_e_1994(&Lab::from_color(a), &Lab::from_color(b), *application)
+            }
+            DistanceMetric::DeltaE2000 => delta_e_2000(&Lab::from_color(a), &Lab::from_color(b)),
+            DistanceMetric::HyAb => hyab(&Lab::from_color(a), &Lab::from_color(b)),
+            _ => {
+                let (p, q) = (self.embed(a), self.embed(b));
+                ((p[0] - q[0]).powi(2) + (p[1] - q[1]).powi(2) + (p[2] - q[2]).powi(2)).sqrt()
+            }
+        }
+    }
+
+    /// Coordinates in which the metric is Euclidean, or CIELAB for the metrics that are
+    /// not Euclidean anywhere.
+    pub(crate) fn embed(&self, color: &Color) -> [f64; 3] {
+        match self {
+            DistanceMetric::DeltaEOk => {
+                let Oklab { l, a, b, .. } = Oklab::from_color(color);
+                [l, a, b]
+            }
+            DistanceMetric::Din99 => {
+                let Din99 { l, a, b, .. } = Din99::from_color(color);
+                [l, a, b]
+            }
+            DistanceMetric::DeltaEItp => {
+                let Ictcp { i, ct, cp, .. } = Ictcp::from_color(color);
+                [720.0 * i, 360.0 * ct, 720.0 * cp]
+            }
+            _ => {
+                let Lab { l, a, b, .. } = Lab::from_color(color);
+                [l, a, b]
+            }
+        }
+    }
+}
+
+/// Hue angle in degrees in [0, 360), 0 for a zero vector.
+fn hue_degrees(y: f64, x: f64) -> f64 {
+    if x == 0.0 && y == 0.0 {
+        0.0
+    } else {
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+}
+
+fn to_lab(color: &impl ColorSpace) -> Lab {
+    Lab::from_color(&color.to_color())
+}
+
+fn to_oklab(color: &impl ColorSpace) -> Oklab {
+    Oklab::from_color(&color.to_color())
+}
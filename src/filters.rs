@@ -0,0 +1,152 @@
+//! Photographic color filters.
+//!
+//! Wratten-style warming and cooling filters are modeled by their mired shift: the
+//! filter moves the scene white along the Planckian locus from 6500 K by that many mireds,
+//! and colors follow through a Bradford chromatic adaptation. Positive shifts warm the
+//! image (81 and 85 series), negative ones cool it (80 and 82 series).
+
+use crate::chromatic_adaptation::bradford;
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::xyz::Xyz;
+use crate::math::Mat3;
+use crate::pipeline::Transform;
+use crate::white_point::{Observer, WhitePoint};
+use serde::{Deserialize, Serialize};
+
+// NOTE: The Planckian locus is the one `WhitePoint::planckian` and `Cct` use, integrated
+// from Planck's law, so a filter's target temperature sits where `Cct` reports it.
+// Integrating the two whites dominates the cost of a pixel, so `apply` pays it on every
+// call while `apply_slice` pays it once per buffer (as do pipelines and streams, which go
+// through `apply_slice`), and `transform` pays it once for good.
+
+/// Nominal scene white the shifts are applied to, in kelvin
+const BASE_TEMPERATURE: f64 = 6500.0;
+/// Hottest white a cooling shift reaches, the top of the locus `WhitePoint::planckian`
+/// covers
+const MAX_TEMPERATURE: f64 = 100000.0;
+
+/// Named Kodak Wratten conversion and light-balancing filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WrattenFilter {
+    Wratten80A,
+    Wratten80B,
+    Wratten80C,
+    Wratten80D,
+    Wratten81,
+    Wratten81A,
+    Wratten81B,
+    Wratten81C,
+    Wratten81D,
+    Wratten81EF,
+    Wratten82,
+    Wratten82A,
+    Wratten82B,
+    Wratten82C,
+    Wratten85,
+    Wratten85B,
+    Wratten85C,
+}
+
+impl WrattenFilter {
+    /// Mired shift of the filter (positive warms, negative cools).
+    pub fn mired_shift(&self) -> f64 {
+        match self {
+            WrattenFilter::Wratten80A => -131.0,
+            WrattenFilter::Wratten80B => -112.0,
+            WrattenFilter::Wratten80C => -81.0,
+            WrattenFilter::Wratten80D => -56.0,
+            WrattenFilter::Wratten81 => 9.0,
+            WrattenFilter::Wratten81A => 18.0,
+            WrattenFilter::Wratten81B => 27.0,
+            WrattenFilter::Wratten81C => 35.0,
+            WrattenFilter::Wratten81D => 42.0,
+            WrattenFilter::Wratten81EF => 52.0,
+            WrattenFilter::Wratten82 => -10.0,
+            WrattenFilter::Wratten82A => -21.0,
+            WrattenFilter::Wratten82B => -32.0,
+            WrattenFilter::Wratten82C => -45.0,
+            WrattenFilter::Wratten85 => 112.0,
+            WrattenFilter::Wratten85B => 131.0,
+            WrattenFilter::Wratten85C => 81.0,
+        }
+    }
+
+    /// Whether the filter warms the image.
+    pub fn is_warming(&self) -> bool {
+        self.mired_shift() > 0.0
+    }
+}
+
+/// A warming or cooling filter of configurable strength, applied to linear sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PhotoFilter {
+    /// Mired shift at full strength
+    pub mired_shift: f64,
+    /// Multiplier on the shift; 0 is no effect, 1 is the nominal filter
+    pub strength: f64,
+}
+
+impl From<WrattenFilter> for PhotoFilter {
+    fn from(filter: WrattenFilter) -> Self {
+        Self::new(filter.mired_shift())
+    }
+}
+
+impl PhotoFilter {
+    /// Filter with the given mired shift at full strength.
+    pub fn new(mired_shift: f64) -> Self {
+        Self {
+            mired_shift,
+            strength: 1.0,
+        }
+    }
+
+    /// The same filter at a different strength.
+    pub fn with_strength(self, strength: f64) -> Self {
+        Self { strength, ..self }
+    }
+
+    /// Temperature the scene white is shifted to, in kelvin.
+    pub fn target_temperature(&self) -> f64 {
+        // Keep the reciprocal temperature positive so strong cooling saturates at the
+        // top of the locus instead of wrapping around to warm
+        let mired = 1e6 / BASE_TEMPERATURE + self.mired_shift * self.strength;
+        1e6 / mired.max(1e6 / MAX_TEMPERATURE)
+    }
+
+    /// The filter as a transform whose adaptation is computed once, for applying it to
+    /// pixels one at a time.
+    pub fn transform(self) -> impl Transform + Send + Sync + Copy {
+        let m = self.adaptation();
+        move |rgb: [f64; 3]| adapt(&m, rgb)
+    }
+
+    /// Bradford adaptation taking the base white to the shifted white, as an XYZ matrix.
+    fn adaptation(&self) -> Mat3 {
+        let white = |t: f64| WhitePoint::planckian(t).xyz(Observer::Cie1931);
+        bradford(white(BASE_TEMPERATURE), white(self.target_temperature()))
+    }
+}
+
+/// Apply the XYZ matrix `m` to linear sRGB.
+fn adapt(m: &Mat3, rgb: [f64; 3]) -> [f64; 3] {
+    let Xyz { x, y, z, .. } = Xyz::from_color(&Color::opaque(rgb[0], rgb[1], rgb[2]));
+    let [x, y, z] = m.apply([x, y, z]);
+    let c = Xyz::new(x, y, z).to_color();
+    [c.r, c.g, c.b]
+}
+
+impl Transform for PhotoFilter {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        adapt(&self.adaptation(), rgb)
+    }
+
+    fn apply_slice(&self, pixels: &mut [Color]) {
+        let m = self.adaptation();
+        for pixel in pixels.iter_mut() {
+            let [r, g, b] = adapt(&m, [pixel.r, pixel.g, pixel.b]);
+            *pixel = Color { r, g, b, ..*pixel };
+        }
+    }
+}
@@ -0,0 +1,864 @@
+//! Gamut analysis.
+//!
+//! Gamuts are RGB volumes described by `RgbPrimaries`. Volumes are measured in a
+//! perceptual space by sampling a regular grid over the reference gamut's bounding box
+//! and counting the samples that fall inside each gamut. `GamutBoundary` triangulates a
+//! gamut's surface in the same spaces for ray queries.
+//!
+//! `gamut_map` brings a color into a `Gamut` with the CSS Color 4 algorithm;
+//! `GamutMapping` selects between it and simpler strategies, and `convert` applies one
+//! while converting. `max_chroma` and `find_cusp` describe a gamut's boundary in Oklch,
+//! starting from an analytic estimate for sRGB and by search for other `Gamut`s.
+//! `GamutCompressor` rolls wide-gamut imagery into a smaller gamut with a soft knee in
+//! chroma. `gamut_report` explains why a color does not fit, and `gamut_map_slice` maps
+//! whole buffers.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, GAMUT_EPSILON, Gamut};
+use crate::colorspaces::gamma_rgb::RgbPrimaries;
+use crate::colorspaces::lab::Lab;
+use crate::colorspaces::oklab::{self, Oklab};
+use crate::colorspaces::oklch::Oklch;
+use crate::colorspaces::srgb::Srgb;
+use crate::distance::delta_e_ok;
+use crate::math::dot;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+// Tolerance for a linear component to count as inside [0, 1]
+const EPSILON: f64 = 1e-9;
+
+/// Smallest ray parameter counted as leaving the origin, so a query from a point on the
+/// surface finds the far side rather than itself
+const RAY_EPSILON: f64 = 1e-12;
+
+/// ΔEOK below which a clipped color counts as indistinguishable (CSS Color 4)
+const JND: f64 = 0.02;
+/// Chroma resolution of the CSS Color 4 search
+const CHROMA_EPSILON: f64 = 0.0001;
+/// Bisection steps when searching a gamut boundary along chroma
+const CHROMA_ITERATIONS: usize = 48;
+/// Golden-section steps when searching lightness for a cusp
+const CUSP_ITERATIONS: usize = 64;
+/// Starting chroma bound for boundary searches, doubled while still in gamut
+const CHROMA_BOUND: f64 = 0.5;
+/// Lightness intervals in a `GamutCompressor` boundary table
+const TABLE_LIGHTNESS_STEPS: usize = 128;
+/// Hue intervals in a `GamutCompressor` boundary table, 2 degrees each
+const TABLE_HUE_STEPS: usize = 180;
+/// Relative width of the bracket around an estimated boundary chroma
+const REFINE_BRACKET: f64 = 0.02;
+/// Most regula falsi steps within that bracket
+const REFINE_ITERATIONS: usize = 32;
+/// Chroma resolution of the refined boundary
+const REFINE_TOLERANCE: f64 = 1e-12;
+
+/// Perceptual space in which gamut volumes are compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VolumeSpace {
+    /// CIELAB (D65), the space display reviews usually quote
+    #[default]
+    Lab,
+    /// Oklab
+    Oklab,
+}
+
+impl VolumeSpace {
+    /// Coordinates of `color` in this space, as [L, a, b].
+    pub fn coordinates(&self, color: &Color) -> [f64; 3] {
+        match self {
+            VolumeSpace::Lab => {
+                let Lab { l, a, b, .. } = Lab::from_color(color);
+                [l, a, b]
+            }
+            VolumeSpace::Oklab => {
+                let Oklab { l, a, b, .. } = Oklab::from_color(color);
+                [l, a, b]
+            }
+        }
+    }
+
+    /// The opaque color at coordinates [L, a, b] in this space.
+    pub fn color_at(&self, [l, a, b]: [f64; 3]) -> Color {
+        match self {
+            VolumeSpace::Lab => Lab {
+                l,
+                a,
+                b,
+                alpha: 1.0,
+            }
+            .to_color(),
+            VolumeSpace::Oklab => Oklab {
+                l,
+                a,
+                b,
+                alpha: 1.0,
+            }
+            .to_color(),
+        }
+    }
+}
+
+/// Whether a color lies inside the RGB cube of the given primaries.
+fn contains(primaries: &RgbPrimaries, color: &Color) -> bool {
+    let (r, g, b) = primaries.color_to_linear(color);
+    [r, g, b]
+        .iter()
+        .all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+}
+
+/// Linear RGB of the point (u, v) on one of the six faces of the unit cube.
+fn face_rgb(face: usize, u: f64, v: f64) -> (f64, f64, f64) {
+    let fixed = (face % 2) as f64;
+    match face / 2 {
+        0 => (fixed, u, v),
+        1 => (u, fixed, v),
+        _ => (u, v, fixed),
+    }
+}
+
+/// Perceptual bounding box of a gamut, found from the surface of its RGB cube.
+fn bounds(primaries: &RgbPrimaries, space: VolumeSpace, n: usize) -> ([f64; 3], [f64; 3]) {
+    let mut lo = [f64::INFINITY; 3];
+    let mut hi = [f64::NEG_INFINITY; 3];
+    let step = 1.0 / (n - 1) as f64;
+    for face in 0..6 {
+        for i in 0..n {
+            for j in 0..n {
+                let rgb = face_rgb(face, i as f64 * step, j as f64 * step);
+                let color = primaries.linear_to_color(rgb.0, rgb.1, rgb.2, 1.0);
+                let p = space.coordinates(&color);
+                for k in 0..3 {
+                    lo[k] = lo[k].min(p[k]);
+                    hi[k] = hi[k].max(p[k]);
+                }
+            }
+        }
+    }
+    (lo, hi)
+}
+
+/// Cell-center samples of a regular grid over `primaries`' perceptual bounding box, and
+/// the volume of one cell.
+fn grid_samples(
+    primaries: &RgbPrimaries,
+    space: VolumeSpace,
+    n: usize,
+) -> (impl Iterator<Item = Color>, f64) {
+    let (lo, hi) = bounds(primaries, space, n);
+    let cell = (0..3)
+        .map(|axis| (hi[axis] - lo[axis]) / n as f64)
+        .product();
+    let samples = (0..n * n * n).map(move |index| {
+        let p = [index / (n * n), index / n % n, index % n].map(|c| c as f64 + 0.5);
+        let point = [0, 1, 2].map(|axis| lo[axis] + (hi[axis] - lo[axis]) * p[axis] / n as f64);
+        space.color_at(point)
+    });
+    (samples, cell)
+}
+
+/// Fraction in [0, 1] of `reference`'s volume that `gamut` also covers.
+///
+/// For example, `gamut_coverage(&display, &RgbPrimaries::DisplayP3, VolumeSpace::Lab, 64)`
+/// gives the share of P3 a display can show. `resolution` is the number of samples
+/// along each axis; 64 is accurate to roughly a percent.
+pub fn gamut_coverage(
+    gamut: &RgbPrimaries,
+    reference: &RgbPrimaries,
+    space: VolumeSpace,
+    resolution: usize,
+) -> f64 {
+    let (samples, _) = grid_samples(reference, space, resolution.max(2));
+    let mut inside_reference = 0usize;
+    let mut inside_both = 0usize;
+    for color in samples.filter(|c| contains(reference, c)) {
+        inside_reference += 1;
+        if contains(gamut, &color) {
+            inside_both += 1;
+        }
+    }
+    if inside_reference == 0 {
+        return 0.0;
+    }
+    inside_both as f64 / inside_reference as f64
+}
+
+/// Volume of a gamut in `space`, in cubic units of its coordinates (such as ΔE76³ for
+/// CIELAB). `resolution` is as for `gamut_coverage`.
+pub fn gamut_volume(gamut: &RgbPrimaries, space: VolumeSpace, resolution: usize) -> f64 {
+    let (samples, cell) = grid_samples(gamut, space, resolution.max(2));
+    samples.filter(|c| contains(gamut, c)).count() as f64 * cell
+}
+
+/// The two figures display reviews quote against a reference gamut. Multiply by 100 for
+/// a percentage.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CoverageStats {
+    /// Fraction of the reference the gamut covers, at most 1
+    pub coverage: f64,
+    /// Gamut volume over reference volume, which can exceed 1 and does not care whether
+    /// the volumes overlap
+    pub relative_volume: f64,
+}
+
+/// Coverage and relative volume of `gamut` against `reference`, as "95% P3 coverage,
+/// 130% sRGB volume".
+pub fn coverage_stats(
+    gamut: &RgbPrimaries,
+    reference: &RgbPrimaries,
+    space: VolumeSpace,
+    resolution: usize,
+) -> CoverageStats {
+    let reference_volume = gamut_volume(reference, space, resolution);
+    CoverageStats {
+        coverage: gamut_coverage(gamut, reference, space, resolution),
+        relative_volume: if reference_volume > 0.0 {
+            gamut_volume(gamut, space, resolution) / reference_volume
+        } else {
+            0.0
+        },
+    }
+}
+
+/// Fraction in [0, 1] of `colors` inside `gamut`, such as the share of an image's pixels or
+/// a palette a display can show; 0 for no colors.
+pub fn color_coverage(colors: &[Color], gamut: &RgbPrimaries) -> f64 {
+    if colors.is_empty() {
+        return 0.0;
+    }
+    colors.iter().filter(|c| contains(gamut, c)).count() as f64 / colors.len() as f64
+}
+
+/// The surface of an RGB gamut as a triangle mesh in a perceptual space.
+///
+/// Each face of the RGB cube is sampled on a `resolution` × `resolution` grid, so edges
+/// are shared exactly and the mesh is closed. Queries are brute force over the
+/// triangles, which suits analysis rather than per-pixel work.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamutBoundary {
+    space: VolumeSpace,
+    vertices: Vec<[f64; 3]>,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl GamutBoundary {
+    /// Triangulate the surface of `primaries`' gamut in `space`; `resolution` is clamped
+    /// to at least 2.
+    pub fn new(primaries: &RgbPrimaries, space: VolumeSpace, resolution: usize) -> Self {
+        let n = resolution.max(2);
+        let step = 1.0 / (n - 1) as f64;
+        let mut vertices = Vec::with_capacity(6 * n * n);
+        let mut triangles = Vec::with_capacity(12 * (n - 1) * (n - 1));
+        for face in 0..6 {
+            let base = vertices.len();
+            for i in 0..n {
+                for j in 0..n {
+                    let rgb = face_rgb(face, i as f64 * step, j as f64 * step);
+                    let color = primaries.linear_to_color(rgb.0, rgb.1, rgb.2, 1.0);
+                    vertices.push(space.coordinates(&color));
+                }
+            }
+            for i in 0..n - 1 {
+                for j in 0..n - 1 {
+                    let corner = base + i * n + j;
+                    let (right, down) = (corner + n, corner + 1);
+                    triangles.push([corner, right, down]);
+                    triangles.push([right, right + 1, down]);
+                }
+            }
+        }
+        Self {
+            space,
+            vertices,
+            triangles,
+        }
+    }
+
+    /// The space the mesh is expressed in.
+    pub fn space(&self) -> VolumeSpace {
+        self.space
+    }
+
+    /// Mesh vertices, as [L, a, b] in `space()`.
+    pub fn vertices(&self) -> &[[f64; 3]] {
+        &self.vertices
+    }
+
+    /// Mesh triangles, as indices into `vertices()`. Winding is not consistent.
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Distance `t` along `direction` from `origin` at which the ray first crosses the
+    /// surface, so the crossing is `origin + t·direction`, or `None` if it never does.
+    ///
+    /// From a point inside the gamut this is where the ray leaves it. `direction` need
+    /// not be normalized; `t` is in units of its length.
+    pub fn intersect(&self, origin: [f64; 3], direction: [f64; 3]) -> Option<f64> {
+        self.triangles
+            .iter()
+            .filter_map(|&[a, b, c]| {
+                ray_triangle(
+                    origin,
+                    direction,
+                    [self.vertices[a], self.vertices[b], self.vertices[c]],
+                )
+            })
+            .min_by(f64::total_cmp)
+    }
+
+    /// The point where the ray from `origin` along `direction` first crosses the surface.
+    pub fn boundary_point(&self, origin: [f64; 3], direction: [f64; 3]) -> Option<[f64; 3]> {
+        let t = self.intersect(origin, direction)?;
+        Some([0, 1, 2].map(|k| origin[k] + t * direction[k]))
+    }
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Ray parameter of the hit with a triangle (Möller-Trumbore), edges included.
+fn ray_triangle(origin: [f64; 3], direction: [f64; 3], [a, b, c]: [[f64; 3]; 3]) -> Option<f64> {
+    let (e1, e2) = (sub(b, a), sub(c, a));
+    let p = cross(direction, e2);
+    let det = dot(e1, p);
+    if det == 0.0 {
+        return None;
+    }
+    let s = sub(origin, a);
+    let u = dot(s, p) / det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = cross(s, e1);
+    let v = dot(direction, q) / det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = dot(e2, q) / det;
+    (t > RAY_EPSILON).then_some(t)
+}
+
+/// Map `color` into `T`'s gamut with the CSS Color 4 algorithm, as
+/// `gamut_map::<Srgb>(&color)`.
+///
+/// Colors already in gamut are returned unchanged. Otherwise lightness and hue are kept in
+/// Oklch and chroma is bisected for the most chromatic color whose clip into `T` is
+/// within a just-noticeable ΔEOK (0.02) of it; that clipped color is returned. Lightness
+/// at or beyond white or black gives white or black. Alpha is kept.
+pub fn gamut_map<T: Gamut>(color: &Color) -> Color {
+    let origin = Oklch::from_color(color);
+    if origin.l >= 1.0 {
+        return Color::new(1.0, 1.0, 1.0, color.a);
+    }
+    if origin.l <= 0.0 {
+        return Color::new(0.0, 0.0, 0.0, color.a);
+    }
+    if T::is_in_gamut(color) {
+        return *color;
+    }
+
+    let clip_distance = |current: &Color| {
+        let clipped = T::clip(current);
+        let e = delta_e_ok(&Oklab::from_color(&clipped), &Oklab::from_color(current));
+        (clipped, e)
+    };
+    let (mut clipped, e) = clip_distance(color);
+    if e < JND {
+        return clipped;
+    }
+
+    let (mut min, mut max) = (0.0, origin.c);
+    let mut min_in_gamut = true;
+    while max - min > CHROMA_EPSILON {
+        let chroma = 0.5 * (min + max);
+        let current = Oklch {
+            c: chroma,
+            ..origin
+        }
+        .to_color();
+        if min_in_gamut && T::is_in_gamut(&current) {
+            min = chroma;
+            continue;
+        }
+        let e;
+        (clipped, e) = clip_distance(&current);
+        if e < JND {
+            if JND - e < CHROMA_EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+    clipped
+}
+
+/// How to bring an out-of-gamut color into a target `Gamut`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GamutMapping {
+    /// Clamp each linear component to [0, 1]; fast, but shifts hue
+    #[default]
+    Clip,
+    /// Divide by the largest linear component when it exceeds 1, so highlights keep their
+    /// chromaticity and the brightest reaches white level, then clip negative components
+    ScaleToWhite,
+    /// Reduce Oklch chroma at constant lightness and hue until the color fits
+    ChromaReduce,
+    /// Move in linear light toward the gray of the same luminance until the color fits
+    ProjectTowardNeutral,
+    /// The CSS Color 4 algorithm of `gamut_map`
+    Css,
+    /// Leave the color unchanged, out of range components and all
+    None,
+}
+
+impl GamutMapping {
+    /// Map `color` into `T`'s gamut. Colors already inside are unchanged for every
+    /// strategy; alpha is always kept.
+    pub fn apply<T: Gamut>(&self, color: &Color) -> Color {
+        if *self == GamutMapping::None || T::is_in_gamut(color) {
+            return *color;
+        }
+        match self {
+            GamutMapping::Clip | GamutMapping::None => T::clip(color),
+            GamutMapping::ScaleToWhite => {
+                let rgb = T::linear_rgb(color);
+                let peak = rgb.iter().copied().fold(1.0, f64::max);
+                T::clip(&T::from_linear_rgb(rgb.map(|c| c / peak), color.a))
+            }
+            GamutMapping::ChromaReduce => chroma_reduce::<T>(color),
+            GamutMapping::ProjectTowardNeutral => project_toward_neutral::<T>(color),
+            GamutMapping::Css => gamut_map::<T>(color),
+        }
+    }
+}
+
+/// Convert `color` to `T`, mapping it into `T`'s gamut first.
+pub fn convert<T: Gamut>(color: &Color, mapping: GamutMapping) -> T {
+    T::from_color(&mapping.apply::<T>(color))
+}
+
+fn chroma_reduce<T: Gamut>(color: &Color) -> Color {
+    chroma_reduce_with::<T>(color, max_chroma_in::<T>)
+}
+
+/// `ChromaReduce` with the boundary chroma at (l, h) from `max_chroma`.
+fn chroma_reduce_with<T: Gamut>(
+    color: &Color,
+    mut max_chroma: impl FnMut(f64, f64) -> f64,
+) -> Color {
+    let origin = Oklch::from_color(color);
+    let l = origin.l.clamp(0.0, 1.0);
+    let c = origin.c.min(max_chroma(l, origin.h));
+    // Removes the rounding left after the search, and any excess at the ends of lightness
+    T::clip(&Oklch { l, c, ..origin }.to_color())
+}
+
+fn project_toward_neutral<T: Gamut>(color: &Color) -> Color {
+    // Every `Gamut` shares the hub's white, so the gray of luminance Y is (Y, Y, Y) in it
+    let gray = color.luminance().clamp(0.0, 1.0);
+    let rgb = T::linear_rgb(color);
+    // Largest step from the gray toward the color that keeps each component in [0, 1]
+    let t = rgb.iter().fold(1.0_f64, |t, &c| {
+        let d = c - gray;
+        if c > 1.0 {
+            t.min((1.0 - gray) / d)
+        } else if c < 0.0 {
+            t.min(-gray / d)
+        } else {
+            t
+        }
+    });
+    T::clip(&T::from_linear_rgb(
+        rgb.map(|c| gray + t * (c - gray)),
+        color.a,
+    ))
+}
+
+/// Largest Oklch chroma inside sRGB at lightness `l` and hue `h` (degrees).
+///
+/// Starts from Ottosson's analytic approximation of the sRGB boundary, which is off by up
+/// to about 6e-3 in chroma near blue where his fit picks the wrong boundary segment, and
+/// refines it on the exact boundary, so the result agrees with `max_chroma_in::<Srgb>`.
+/// Zero at and beyond black and white.
+pub fn max_chroma(l: f64, h: f64) -> f64 {
+    if l <= 0.0 || l >= 1.0 {
+        return 0.0;
+    }
+    let (b_, a_) = h.to_radians().sin_cos();
+    let cusp = oklab::find_cusp(a_, b_);
+    let estimate = oklab::find_gamut_intersection(a_, b_, l, 1.0, l, cusp).max(0.0);
+    refine_boundary::<Srgb>(l, h, estimate)
+}
+
+/// The most chromatic sRGB color of hue `h` (degrees), by Ottosson's analytic
+/// approximation.
+pub fn find_cusp(h: f64) -> Oklch {
+    let (b_, a_) = h.to_radians().sin_cos();
+    let (l, c) = oklab::find_cusp(a_, b_);
+    Oklch::new(l, c, h)
+}
+
+/// Largest Oklch chroma inside `T` at lightness `l` and hue `h` (degrees), found by
+/// bisection along chroma.
+pub fn max_chroma_in<T: Gamut>(l: f64, h: f64) -> f64 {
+    if l <= 0.0 || l >= 1.0 {
+        return 0.0;
+    }
+    let inside = |c: f64| T::is_in_gamut(&Oklch::new(l, c, h).to_color());
+    let mut hi = CHROMA_BOUND;
+    while inside(hi) {
+        hi *= 2.0;
+    }
+    let mut lo = 0.0;
+    for _ in 0..CHROMA_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        if inside(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// The most chromatic color of hue `h` (degrees) inside `T`, found by a golden-section
+/// search over lightness.
+pub fn find_cusp_in<T: Gamut>(h: f64) -> Oklch {
+    // 1 / golden ratio
+    const INV_PHI: f64 = 0.6180339887498949;
+    let (mut lo, mut hi) = (0.0, 1.0);
+    let mut x1 = hi - INV_PHI * (hi - lo);
+    let mut x2 = lo + INV_PHI * (hi - lo);
+    let (mut c1, mut c2) = (max_chroma_in::<T>(x1, h), max_chroma_in::<T>(x2, h));
+    for _ in 0..CUSP_ITERATIONS {
+        if c1 < c2 {
+            lo = x1;
+            (x1, c1) = (x2, c2);
+            x2 = lo + INV_PHI * (hi - lo);
+            c2 = max_chroma_in::<T>(x2, h);
+        } else {
+            hi = x2;
+            (x2, c2) = (x1, c1);
+            x1 = hi - INV_PHI * (hi - lo);
+            c1 = max_chroma_in::<T>(x1, h);
+        }
+    }
+    let l = 0.5 * (lo + hi);
+    Oklch::new(l, max_chroma_in::<T>(l, h), h)
+}
+
+/// Hue-preserving gamut compression from a source `Gamut` into a smaller target.
+///
+/// Works in Oklch at constant lightness and hue. With chroma measured as a fraction of the
+/// target's boundary, fractions below `knee` are left alone, and the range from `knee` to
+/// the source boundary is compressed smoothly onto `knee`..1, so only colors near or
+/// beyond the target's edge move and source colors keep their order. Both boundaries
+/// are tabulated once on construction, so one compressor can be reused across images.
+#[derive(Debug, Clone)]
+pub struct GamutCompressor {
+    knee: f64,
+    /// Source and target maximum chroma, indexed by hue then lightness
+    source: Vec<f64>,
+    target: Vec<f64>,
+    /// `T::clip`, so the compressor needs no type parameter
+    clip: fn(&Color) -> Color,
+}
+
+impl GamutCompressor {
+    /// Compressor from `S` into `T` (such as `DisplayP3` into `Srgb`). `knee` is clamped
+    /// to [0, 1); 0.8 is a reasonable start.
+    pub fn new<S: Gamut, T: Gamut>(knee: f64) -> Self {
+        Self {
+            knee: knee.clamp(0.0, 1.0 - EPSILON),
+            source: boundary_table::<S>(),
+            target: boundary_table::<T>(),
+            clip: T::clip,
+        }
+    }
+
+    /// The fraction of the target boundary where compression starts.
+    pub fn knee(&self) -> f64 {
+        self.knee
+    }
+
+    /// Compress one color into the target gamut; alpha is kept.
+    pub fn compress(&self, color: &Color) -> Color {
+        let origin = Oklch::from_color(color);
+        let l = origin.l.clamp(0.0, 1.0);
+        let limit = table_lookup(&self.target, l, origin.h);
+        if limit <= EPSILON {
+            return (self.clip)(color);
+        }
+        let k = self.knee;
+        let x = origin.c / limit;
+        let s = table_lookup(&self.source, l, origin.h) / limit;
+        if x <= k || s <= 1.0 {
+            return (self.clip)(color);
+        }
+        // k + d / (1 + d / m): unit slope at the knee, reaching 1 at the source boundary
+        let m = (s - k) * (1.0 - k) / (s - 1.0);
+        let d = x - k;
+        let c = limit * (k + d / (1.0 + d / m));
+        // The tables are interpolated, so clip what little is left outside
+        (self.clip)(&Oklch { l, c, ..origin }.to_color())
+    }
+
+    /// Compress every pixel in place.
+    pub fn compress_slice(&self, pixels: &mut [Color]) {
+        for pixel in pixels {
+            *pixel = self.compress(pixel);
+        }
+    }
+}
+
+/// Compress `pixels` in place from `S` into `T` with a `GamutCompressor`, as
+/// `compress_gamut::<Rec2020, Srgb>(&mut pixels, 0.8)`.
+pub fn compress_gamut<S: Gamut, T: Gamut>(pixels: &mut [Color], knee: f64) {
+    GamutCompressor::new::<S, T>(knee).compress_slice(pixels);
+}
+
+/// Maximum chroma of `T` on the table grid, indexed by hue then lightness.
+fn boundary_table<T: Gamut>() -> Vec<f64> {
+    (0..TABLE_HUE_STEPS)
+        .flat_map(boundary_column::<T>)
+        .collect()
+}
+
+/// Maximum chroma of `T` at each table lightness, for the `i`th table hue.
+fn boundary_column<T: Gamut>(i: usize) -> Vec<f64> {
+    let h = 360.0 * i as f64 / TABLE_HUE_STEPS as f64;
+    (0..=TABLE_LIGHTNESS_STEPS)
+        .map(|j| max_chroma_in::<T>(j as f64 / TABLE_LIGHTNESS_STEPS as f64, h))
+        .collect()
+}
+
+/// A boundary table whose hue columns are computed the first time a lookup needs them,
+/// so a buffer only pays for the hues it contains.
+struct BoundaryCache<T> {
+    table: Vec<f64>,
+    filled: Vec<bool>,
+    gamut: PhantomData<fn() -> T>,
+}
+
+impl<T: Gamut> BoundaryCache<T> {
+    fn new() -> Self {
+        Self {
+            table: vec![0.0; TABLE_HUE_STEPS * (TABLE_LIGHTNESS_STEPS + 1)],
+            filled: vec![false; TABLE_HUE_STEPS],
+            gamut: PhantomData,
+        }
+    }
+
+    /// Maximum chroma at (l, h), refined from the interpolated table value.
+    fn max_chroma(&mut self, l: f64, h: f64) -> f64 {
+        if l <= 0.0 || l >= 1.0 {
+            return 0.0;
+        }
+        let i = (h.rem_euclid(360.0) / 360.0 * TABLE_HUE_STEPS as f64) as usize % TABLE_HUE_STEPS;
+        for column in [i, (i + 1) % TABLE_HUE_STEPS] {
+            if !self.filled[column] {
+                let row = TABLE_LIGHTNESS_STEPS + 1;
+                self.table[column * row..(column + 1) * row]
+                    .copy_from_slice(&boundary_column::<T>(column));
+                self.filled[column] = true;
+            }
+        }
+        refine_boundary::<T>(l, h, table_lookup(&self.table, l, h))
+    }
+}
+
+/// Maximum chroma of `T` at (l, h), refined from a bracket around `estimate`; falls back
+/// to a full search when the bracket misses.
+fn refine_boundary<T: Gamut>(l: f64, h: f64, estimate: f64) -> f64 {
+    // Distance of the nearest linear channel inside the limits of `is_in_gamut`,
+    // negative outside; continuous in chroma, so the bracket can be refined by regula
+    // falsi instead of bisection
+    let margin = |c: f64| {
+        T::linear_rgb(&Oklch::new(l, c, h).to_color())
+            .iter()
+            .map(|v| (v + GAMUT_EPSILON).min(1.0 + GAMUT_EPSILON - v))
+            .fold(f64::INFINITY, f64::min)
+    };
+    let (mut lo, mut hi) = (
+        estimate * (1.0 - REFINE_BRACKET),
+        estimate * (1.0 + REFINE_BRACKET) + EPSILON,
+    );
+    let (mut f_lo, mut f_hi) = (margin(lo), margin(hi));
+    if !(f_lo >= 0.0 && f_hi < 0.0) {
+        return max_chroma_in::<T>(l, h);
+    }
+    // Illinois variant: halve the stale end's value when the same end moves twice
+    let mut last = 0;
+    for _ in 0..REFINE_ITERATIONS {
+        if hi - lo <= REFINE_TOLERANCE {
+            break;
+        }
+        let c = (lo * f_hi - hi * f_lo) / (f_hi - f_lo);
+        let f = margin(c);
+        if f >= 0.0 {
+            (lo, f_lo) = (c, f);
+            if last == 1 {
+                f_hi *= 0.5;
+            }
+            last = 1;
+        } else {
+            (hi, f_hi) = (c, f);
+            if last == -1 {
+                f_lo *= 0.5;
+            }
+            last = -1;
+        }
+    }
+    lo
+}
+
+/// Map every pixel of `pixels` into `T`'s gamut in place with `strategy`, as
+/// `gamut_map_slice::<Srgb>(&mut frame, GamutMapping::ChromaReduce)`.
+///
+/// Matches `strategy.apply::<T>` on each pixel (for `ChromaReduce`, to within about
+/// 1e-11). `ChromaReduce` shares a boundary cache across the buffer, filled per hue as
+/// pixels need it, and refines the cached boundary instead of searching from scratch
+/// for every pixel.
+pub fn gamut_map_slice<T: Gamut>(pixels: &mut [Color], strategy: GamutMapping) {
+    if strategy != GamutMapping::ChromaReduce {
+        for pixel in pixels {
+            *pixel = strategy.apply::<T>(pixel);
+        }
+        return;
+    }
+    let mut cache = BoundaryCache::<T>::new();
+    for pixel in pixels {
+        if !T::is_in_gamut(pixel) {
+            *pixel = chroma_reduce_with::<T>(pixel, |l, h| cache.max_chroma(l, h));
+        }
+    }
+}
+
+/// Bilinear interpolation in a boundary table, wrapping around in hue.
+fn table_lookup(table: &[f64], l: f64, h: f64) -> f64 {
+    let x = h.rem_euclid(360.0) / 360.0 * TABLE_HUE_STEPS as f64;
+    let y = l.clamp(0.0, 1.0) * TABLE_LIGHTNESS_STEPS as f64;
+    let (i, j) = (
+        x.floor() as usize % TABLE_HUE_STEPS,
+        (y.floor() as usize).min(TABLE_LIGHTNESS_STEPS - 1),
+    );
+    let (fx, fy) = (x - x.floor(), y - j as f64);
+    let row = TABLE_LIGHTNESS_STEPS + 1;
+    let next = (i + 1) % TABLE_HUE_STEPS;
+    let at = |i: usize, j: usize| table[i * row + j];
+    let lower = at(i, j) + (at(i, j + 1) - at(i, j)) * fy;
+    let upper = at(next, j) + (at(next, j + 1) - at(next, j)) * fy;
+    lower + (upper - lower) * fx
+}
+
+/// A linear RGB channel of a `Gamut`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+/// How a linear channel falls outside [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ChannelViolation {
+    /// Negative, by this amount (> 0): the color is more saturated than the primaries
+    Below(f64),
+    /// Above 1, by this amount (> 0): the color is brighter than white level
+    Above(f64),
+    /// The channel is NaN
+    NotANumber,
+}
+
+/// Why a color does or does not fit a `Gamut`, from `gamut_report`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GamutReport {
+    /// Linear components in the target's primaries
+    pub linear: [f64; 3],
+    /// Red, green and blue violations beyond `GAMUT_EPSILON`, `None` where the channel fits
+    pub channels: [Option<ChannelViolation>; 3],
+    /// ΔEOK between the color and its per-channel clip, how visible clipping would be
+    pub clip_error: f64,
+}
+
+impl GamutReport {
+    /// Whether no channel is out of range.
+    pub fn is_in_gamut(&self) -> bool {
+        self.channels.iter().all(Option::is_none)
+    }
+
+    /// The channels out of range, with how.
+    pub fn violations(&self) -> impl Iterator<Item = (Channel, ChannelViolation)> + '_ {
+        [Channel::Red, Channel::Green, Channel::Blue]
+            .into_iter()
+            .zip(self.channels)
+            .filter_map(|(channel, violation)| Some((channel, violation?)))
+    }
+
+    /// Whether any channel is negative, meaning the chromaticity is outside the gamut.
+    pub fn is_too_saturated(&self) -> bool {
+        self.violations()
+            .any(|(_, v)| matches!(v, ChannelViolation::Below(_)))
+    }
+
+    /// Whether any channel is above 1, meaning the color is too bright for the gamut.
+    pub fn is_too_bright(&self) -> bool {
+        self.violations()
+            .any(|(_, v)| matches!(v, ChannelViolation::Above(_)))
+    }
+
+    /// The largest distance of a channel outside [0, 1], 0 when in gamut and NaN if a
+    /// channel is NaN.
+    pub fn max_excess(&self) -> f64 {
+        self.violations()
+            .map(|(_, v)| match v {
+                ChannelViolation::Below(e) | ChannelViolation::Above(e) => e,
+                ChannelViolation::NotANumber => f64::NAN,
+            })
+            .fold(0.0, |m, e| {
+                if e.is_nan() || m.is_nan() {
+                    f64::NAN
+                } else {
+                    m.max(e)
+                }
+            })
+    }
+}
+
+/// Report which of `color`'s channels in `T` are out of range, and by how much.
+pub fn gamut_report<T: Gamut>(color: &Color) -> GamutReport {
+    let linear = T::linear_rgb(color);
+    let channels = linear.map(|c| {
+        if c.is_nan() {
+            Some(ChannelViolation::NotANumber)
+        } else if c < -GAMUT_EPSILON {
+            Some(ChannelViolation::Below(-c))
+        } else if c > 1.0 + GAMUT_EPSILON {
+            Some(ChannelViolation::Above(c - 1.0))
+        } else {
+            None
+        }
+    });
+    let clip_error = delta_e_ok(
+        &Oklab::from_color(color),
+        &Oklab::from_color(&T::clip(color)),
+    );
+    GamutReport {
+        linear,
+        channels,
+        clip_error,
+    }
+}
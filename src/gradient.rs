@@ -0,0 +1,301 @@
+//! Multi-stop gradients.
+//!
+//! A `Gradient` is a list of color stops at positions along a line, sampled by
+//! interpolating between the two stops around a position with `mix_with` in the
+//! gradient's color space and hue interpolation. As in CSS gradients, positions before
+//! the first stop or after the last take that stop's color, and two stops at the same
+//! position make a hard edge.
+//!
+//! An `Easing` reshapes the progress between two stops before the colors are mixed. One
+//! set with `easing` applies to every segment, and `segment_easing` overrides it for one
+//! segment; `Easing::Hint` is the CSS color hint, moving the segment's midpoint.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::Interpolate;
+use crate::mix::{HueInterpolation, mix_with};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// Bisection steps when inverting a cubic Bézier's x coordinate
+const BEZIER_ITERATIONS: usize = 52;
+
+/// Where the jumps of `Easing::Steps` fall, as in CSS `steps()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StepPosition {
+    /// The first jump is at the start
+    Start,
+    /// The last jump is at the end
+    #[default]
+    End,
+    /// Jumps at both ends, so there are `count + 1` of them
+    Both,
+    /// No jump at either end, so there are `count - 1` of them
+    None,
+}
+
+/// A progress curve from [0, 1] to about [0, 1], applied before mixing two stops.
+#[derive(Clone, Default)]
+pub enum Easing {
+    /// Progress unchanged
+    #[default]
+    Linear,
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`; x1 and x2 are clamped to [0, 1]. With y1 or y2
+    /// outside [0, 1] `eval` can leave it too, but a `Gradient` clamps the eased progress,
+    /// so it holds the stop's color rather than overshooting past it
+    CubicBezier { x1: f64, y1: f64, x2: f64, y2: f64 },
+    /// CSS `steps(count, position)`
+    Steps {
+        count: usize,
+        position: StepPosition,
+    },
+    /// 3t² - 2t³: eases in and out with zero slope at both stops
+    Smoothstep,
+    /// CSS color hint: the fraction of the segment (0.0-1.0) where the colors mix half
+    /// and half
+    Hint(f64),
+    /// Any other curve
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+impl fmt::Debug for Easing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Easing::Linear => write!(f, "Linear"),
+            Easing::CubicBezier { x1, y1, x2, y2 } => f
+                .debug_struct("CubicBezier")
+                .field("x1", x1)
+                .field("y1", y1)
+                .field("x2", x2)
+                .field("y2", y2)
+                .finish(),
+            Easing::Steps { count, position } => f
+                .debug_struct("Steps")
+                .field("count", count)
+                .field("position", position)
+                .finish(),
+            Easing::Smoothstep => write!(f, "Smoothstep"),
+            Easing::Hint(h) => f.debug_tuple("Hint").field(h).finish(),
+            Easing::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl Easing {
+    /// CSS `ease`.
+    pub const EASE: Easing = Easing::CubicBezier {
+        x1: 0.25,
+        y1: 0.1,
+        x2: 0.25,
+        y2: 1.0,
+    };
+    /// CSS `ease-in`.
+    pub const EASE_IN: Easing = Easing::CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 1.0,
+        y2: 1.0,
+    };
+    /// CSS `ease-out`.
+    pub const EASE_OUT: Easing = Easing::CubicBezier {
+        x1: 0.0,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    };
+    /// CSS `ease-in-out`.
+    pub const EASE_IN_OUT: Easing = Easing::CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    };
+
+    /// An easing from a closure.
+    pub fn custom<F: Fn(f64) -> f64 + Send + Sync + 'static>(f: F) -> Self {
+        Easing::Custom(Arc::new(f))
+    }
+
+    /// Eased progress at `t`, clamped to [0, 1].
+    pub fn eval(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::CubicBezier { x1, y1, x2, y2 } => {
+                cubic_bezier(x1.clamp(0.0, 1.0), *y1, x2.clamp(0.0, 1.0), *y2, t)
+            }
+            Easing::Steps { count, position } => steps(*count, *position, t),
+            Easing::Smoothstep => t * t * (3.0 - 2.0 * t),
+            Easing::Hint(h) => {
+                if *h <= 0.0 {
+                    if t > 0.0 { 1.0 } else { 0.0 }
+                } else if *h >= 1.0 {
+                    if t < 1.0 { 0.0 } else { 1.0 }
+                } else {
+                    t.powf(0.5f64.ln() / h.ln())
+                }
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// The y of the cubic Bézier from (0, 0) to (1, 1) with control points (x1, y1) and
+/// (x2, y2) at x = `t`; x is monotonic since x1 and x2 are in [0, 1].
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, t: f64) -> f64 {
+    let at = |p1: f64, p2: f64, s: f64| {
+        let r = 1.0 - s;
+        3.0 * r * r * s * p1 + 3.0 * r * s * s * p2 + s * s * s
+    };
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..BEZIER_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        if at(x1, x2, mid) < t {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    at(y1, y2, 0.5 * (lo + hi))
+}
+
+/// CSS step easing at `t` in [0, 1].
+fn steps(count: usize, position: StepPosition, t: f64) -> f64 {
+    let jumps = match position {
+        StepPosition::Start | StepPosition::End => count,
+        StepPosition::Both => count + 1,
+        StepPosition::None => count.saturating_sub(1),
+    };
+    if count == 0 || jumps == 0 {
+        return t;
+    }
+    let mut step = (t * count as f64).floor();
+    if matches!(position, StepPosition::Start | StepPosition::Both) {
+        step += 1.0;
+    }
+    (step / jumps as f64).min(1.0)
+}
+
+/// A color at a position along a gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColorStop {
+    /// Position along the gradient, in whatever units `sample` is given
+    pub position: f64,
+    pub color: Color,
+}
+
+impl ColorStop {
+    /// A stop with `color` at `position`.
+    pub fn new(position: f64, color: Color) -> Self {
+        Self { position, color }
+    }
+}
+
+/// A gradient through positioned color stops.
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    /// Sorted by position; stops at equal positions keep their given order
+    stops: Vec<ColorStop>,
+    hue: HueInterpolation,
+    easing: Easing,
+    /// Overrides of `easing`, by segment
+    segments: Vec<Option<Easing>>,
+    /// `mix_with::<S>`, so the gradient needs no type parameter
+    mix: fn(&Color, &Color, f64, HueInterpolation) -> Color,
+}
+
+impl Gradient {
+    /// A gradient through `stops`, interpolated in `S` with the shorter hue arc.
+    pub fn new<S: Interpolate>(stops: &[ColorStop]) -> Self {
+        let mut stops = stops.to_vec();
+        stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        Self {
+            segments: vec![None; stops.len().saturating_sub(1)],
+            stops,
+            hue: HueInterpolation::default(),
+            easing: Easing::Linear,
+            mix: mix_with::<S>,
+        }
+    }
+
+    /// A gradient through `colors` spaced evenly from 0 to 1, interpolated in `S`.
+    pub fn evenly<S: Interpolate>(colors: &[Color]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f64;
+        let stops: Vec<ColorStop> = colors
+            .iter()
+            .enumerate()
+            .map(|(i, c)| ColorStop::new(i as f64 / last, *c))
+            .collect();
+        Self::new::<S>(&stops)
+    }
+
+    /// The same gradient interpolated in `S`.
+    pub fn space<S: Interpolate>(self) -> Self {
+        Self {
+            mix: mix_with::<S>,
+            ..self
+        }
+    }
+
+    /// The same gradient with hue interpolation `hue`.
+    pub fn hue(self, hue: HueInterpolation) -> Self {
+        Self { hue, ..self }
+    }
+
+    /// The same gradient with `easing` on every segment without its own.
+    pub fn easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+
+    /// The same gradient with `easing` on one segment, the one from stop `segment` to the
+    /// next in position order. Out-of-range segments are ignored.
+    pub fn segment_easing(mut self, segment: usize, easing: Easing) -> Self {
+        if let Some(slot) = self.segments.get_mut(segment) {
+            *slot = Some(easing);
+        }
+        self
+    }
+
+    /// The stops, ordered by position.
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// The hue interpolation between stops.
+    pub fn hue_interpolation(&self) -> HueInterpolation {
+        self.hue
+    }
+
+    /// The color at `position`. A gradient with no stops is transparent black, and a NaN
+    /// position gives the first stop.
+    pub fn sample(&self, position: f64) -> Color {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Color::new(0.0, 0.0, 0.0, 0.0);
+        };
+        if position.is_nan() || position <= first.position {
+            return first.color;
+        }
+        if position >= last.position {
+            return last.color;
+        }
+        // The segment starts at the last stop at or before `position`, so a hard edge
+        // shows the later color at its position
+        let i = self.stops.partition_point(|s| s.position <= position) - 1;
+        let (from, to) = (&self.stops[i], &self.stops[i + 1]);
+        let t = (position - from.position) / (to.position - from.position);
+        let easing = self.segments[i].as_ref().unwrap_or(&self.easing);
+        (self.mix)(&from.color, &to.color, easing.eval(t), self.hue)
+    }
+
+    /// `n` colors at evenly spaced positions from the first stop to the last.
+    pub fn samples(&self, n: usize) -> Vec<Color> {
+        let (start, end) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first.position, last.position),
+            _ => (0.0, 1.0),
+        };
+        let steps = n.saturating_sub(1).max(1) as f64;
+        (0..n)
+            .map(|i| self.sample(start + (end - start) * i as f64 / steps))
+            .collect()
+    }
+}
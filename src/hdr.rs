@@ -0,0 +1,209 @@
+//! HDR luminance handling and analysis.
+//!
+//! A `LuminanceContext` pins down what linear 1.0 means: it is the diffuse (reference)
+//! white, at `diffuse_white` nits, and the display or mastering peak sits at `peak`
+//! nits. The PQ transfer functions and light level statistics take a context so that
+//! SDR and HDR material can be combined on a common, explicit scale.
+//!
+//! Content light level statistics follow CTA-861.3: the light level of a pixel is the
+//! largest of its linear Rec.2020 components in nits, MaxCLL is the brightest such
+//! pixel over the whole content and MaxFALL the brightest frame-average light level.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::linear_rec2020::LinearRec2020;
+use serde::{Deserialize, Serialize};
+
+// NOTE: PQ is defined on absolute luminance (0-10000 nits). Negative inputs are
+// treated as zero so the fractional powers stay real.
+
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+/// Luminance at PQ signal 1.0, in nits
+pub const PQ_MAX_NITS: f64 = 10000.0;
+
+/// Reference white and peak luminance that give linear values an absolute meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LuminanceContext {
+    /// Nits represented by linear 1.0 (diffuse / graphics white)
+    pub diffuse_white: f64,
+    /// Peak luminance in nits
+    pub peak: f64,
+}
+
+impl Default for LuminanceContext {
+    fn default() -> Self {
+        Self::BT2408
+    }
+}
+
+impl LuminanceContext {
+    /// SDR reference display: 100 nit white, no headroom
+    pub const SDR: LuminanceContext = LuminanceContext {
+        diffuse_white: 100.0,
+        peak: 100.0,
+    };
+    /// ITU-R BT.2408 HDR reference white (203 nits) on a 1000 nit display
+    pub const BT2408: LuminanceContext = LuminanceContext {
+        diffuse_white: 203.0,
+        peak: 1000.0,
+    };
+
+    /// Context with the given diffuse white and peak, both in nits.
+    pub fn new(diffuse_white: f64, peak: f64) -> Self {
+        Self {
+            diffuse_white,
+            peak,
+        }
+    }
+
+    /// Absolute luminance of a linear value.
+    pub fn to_nits(&self, linear: f64) -> f64 {
+        linear * self.diffuse_white
+    }
+
+    /// Linear value for an absolute luminance.
+    pub fn from_nits(&self, nits: f64) -> f64 {
+        nits / self.diffuse_white
+    }
+
+    /// Peak luminance as a linear value (the available headroom above diffuse white).
+    pub fn peak_linear(&self) -> f64 {
+        self.peak / self.diffuse_white
+    }
+
+    /// Re-express a linear color from another context in this one, preserving nits.
+    pub fn convert_from(&self, color: &Color, from: &LuminanceContext) -> Color {
+        let scale = from.diffuse_white / self.diffuse_white;
+        Color::new(color.r * scale, color.g * scale, color.b * scale, color.a)
+    }
+
+    /// PQ-encode a linear value.
+    pub fn encode_pq(&self, linear: f64) -> f64 {
+        pq_inverse_eotf(self.to_nits(linear))
+    }
+
+    /// Decode a PQ signal to a linear value.
+    pub fn decode_pq(&self, signal: f64) -> f64 {
+        self.from_nits(pq_eotf(signal))
+    }
+}
+
+/// SMPTE ST 2084 (PQ) EOTF: signal in [0, 1] to nits.
+pub fn pq_eotf(signal: f64) -> f64 {
+    let p = signal.max(0.0).powf(1.0 / PQ_M2);
+    let num = (p - PQ_C1).max(0.0);
+    let den = PQ_C2 - PQ_C3 * p;
+    PQ_MAX_NITS * (num / den).powf(1.0 / PQ_M1)
+}
+
+/// SMPTE ST 2084 (PQ) inverse EOTF: nits to signal in [0, 1].
+pub fn pq_inverse_eotf(nits: f64) -> f64 {
+    let y = (nits / PQ_MAX_NITS).max(0.0).powf(PQ_M1);
+    ((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y)).powf(PQ_M2)
+}
+
+const HLG_A: f64 = 0.17883277;
+const HLG_B: f64 = 1.0 - 4.0 * HLG_A;
+const HLG_C: f64 = 0.55991073;
+
+/// ARIB STD-B67 / BT.2100 HLG OETF: normalized scene light in [0, 1] to signal in [0, 1].
+pub fn hlg_oetf(scene: f64) -> f64 {
+    let e = scene.max(0.0);
+    if e <= 1.0 / 12.0 {
+        (3.0 * e).sqrt()
+    } else {
+        HLG_A * (12.0 * e - HLG_B).ln() + HLG_C
+    }
+}
+
+/// BT.2100 HLG inverse OETF: signal in [0, 1] to normalized scene light in [0, 1].
+pub fn hlg_inverse_oetf(signal: f64) -> f64 {
+    let e = signal.max(0.0);
+    if e <= 0.5 {
+        e * e / 3.0
+    } else {
+        (((e - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+    }
+}
+
+/// HLG system gamma for a display of the given nominal peak luminance in nits
+/// (1.2 at 1000 nits, BT.2100 extended formula).
+pub fn hlg_system_gamma(peak: f64) -> f64 {
+    1.2 + 0.42 * (peak / 1000.0).log10()
+}
+
+/// MaxCLL / MaxFALL pair in cd/m², as carried in HDR10 static metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContentLightLevel {
+    /// Maximum content light level: brightest pixel
+    pub max_cll: f64,
+    /// Maximum frame-average light level: brightest frame on average
+    pub max_fall: f64,
+}
+
+/// Light level of a single pixel in nits.
+///
+/// Negative components (out of Rec.2020 gamut) count as zero.
+pub fn pixel_light_level(color: &Color, context: &LuminanceContext) -> f64 {
+    let LinearRec2020 { r, g, b, .. } = LinearRec2020::from_color(color);
+    context.to_nits(r.max(g).max(b).max(0.0))
+}
+
+/// Statistics of one frame: its brightest pixel and its average light level.
+pub fn frame_light_level(pixels: &[Color], context: &LuminanceContext) -> ContentLightLevel {
+    if pixels.is_empty() {
+        return ContentLightLevel::default();
+    }
+    let mut max = 0.0f64;
+    let mut sum = 0.0;
+    for pixel in pixels {
+        let level = pixel_light_level(pixel, context);
+        max = max.max(level);
+        sum += level;
+    }
+    ContentLightLevel {
+        max_cll: max,
+        max_fall: sum / pixels.len() as f64,
+    }
+}
+
+/// Accumulates MaxCLL / MaxFALL over a sequence of frames.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct LightLevelAnalyzer {
+    /// Luminance mapping of the analyzed buffers
+    pub context: LuminanceContext,
+    level: ContentLightLevel,
+    frames: usize,
+}
+
+impl LightLevelAnalyzer {
+    /// Analyzer for buffers in the given luminance context.
+    pub fn new(context: LuminanceContext) -> Self {
+        Self {
+            context,
+            ..Self::default()
+        }
+    }
+
+    /// Add one frame's pixels.
+    pub fn add_frame(&mut self, pixels: &[Color]) {
+        let frame = frame_light_level(pixels, &self.context);
+        self.level.max_cll = self.level.max_cll.max(frame.max_cll);
+        self.level.max_fall = self.level.max_fall.max(frame.max_fall);
+        self.frames += 1;
+    }
+
+    /// Number of frames analyzed so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Statistics over all frames added so far.
+    pub fn result(&self) -> ContentLightLevel {
+        self.level
+    }
+}
@@ -0,0 +1,4 @@
+//! Conversions to and from pixel types of other crates, each behind a feature flag.
+
+#[cfg(feature = "rgb")]
+pub mod rgb;
@@ -0,0 +1,124 @@
+//! Conversions between ColorLab types and the `rgb` crate's pixel types.
+//!
+//! The `rgb` crate does not record an encoding, so every pixel type here is assumed
+//! to hold sRGB-encoded components: integers span the full type range and `f32`
+//! components span 0.0-1.0. Converting into `Srgb` copies components as-is; converting
+//! into `Color` additionally decodes the sRGB transfer function. Converting back
+//! quantizes integers with clamping and rounding, while `f32` values are not clamped.
+//! If your `f32` pixels are linear, build a `Color` from the fields directly instead.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::srgb::Srgb;
+use ::rgb::{RGB, RGBA};
+
+/// Pixel component types with a defined mapping to the 0.0-1.0 range.
+trait Component: Copy {
+    fn to_unit(self) -> f64;
+    fn from_unit(v: f64) -> Self;
+}
+
+impl Component for u8 {
+    fn to_unit(self) -> f64 {
+        self as f64 / 255.0
+    }
+
+    fn from_unit(v: f64) -> Self {
+        (v.clamp(0.0, 1.0) * 255.0).round() as u8
+    }
+}
+
+impl Component for u16 {
+    fn to_unit(self) -> f64 {
+        self as f64 / 65535.0
+    }
+
+    fn from_unit(v: f64) -> Self {
+        (v.clamp(0.0, 1.0) * 65535.0).round() as u16
+    }
+}
+
+impl Component for f32 {
+    fn to_unit(self) -> f64 {
+        self as f64
+    }
+
+    fn from_unit(v: f64) -> Self {
+        v as f32
+    }
+}
+
+macro_rules! impl_rgb_interop {
+    ($($t:ty),*) => {$(
+        impl From<RGB<$t>> for Srgb {
+            fn from(p: RGB<$t>) -> Self {
+                Srgb {
+                    r: p.r.to_unit(),
+                    g: p.g.to_unit(),
+                    b: p.b.to_unit(),
+                    a: 1.0,
+                }
+            }
+        }
+
+        impl From<RGBA<$t>> for Srgb {
+            fn from(p: RGBA<$t>) -> Self {
+                Srgb {
+                    r: p.r.to_unit(),
+                    g: p.g.to_unit(),
+                    b: p.b.to_unit(),
+                    a: p.a.to_unit(),
+                }
+            }
+        }
+
+        /// Drops alpha.
+        impl From<Srgb> for RGB<$t> {
+            fn from(c: Srgb) -> Self {
+                RGB {
+                    r: <$t>::from_unit(c.r),
+                    g: <$t>::from_unit(c.g),
+                    b: <$t>::from_unit(c.b),
+                }
+            }
+        }
+
+        impl From<Srgb> for RGBA<$t> {
+            fn from(c: Srgb) -> Self {
+                RGBA {
+                    r: <$t>::from_unit(c.r),
+                    g: <$t>::from_unit(c.g),
+                    b: <$t>::from_unit(c.b),
+                    a: <$t>::from_unit(c.a),
+                }
+            }
+        }
+
+        impl From<RGB<$t>> for Color {
+            fn from(p: RGB<$t>) -> Self {
+                Srgb::from(p).to_color()
+            }
+        }
+
+        impl From<RGBA<$t>> for Color {
+            fn from(p: RGBA<$t>) -> Self {
+                Srgb::from(p).to_color()
+            }
+        }
+
+        /// Drops alpha.
+        impl From<Color> for RGB<$t> {
+            fn from(c: Color) -> Self {
+                Srgb::from_color(&c).into()
+            }
+        }
+
+        impl From<Color> for RGBA<$t> {
+            fn from(c: Color) -> Self {
+                Srgb::from_color(&c).into()
+            }
+        }
+    )*};
+}
+
+impl_rgb_interop!(u8, u16, f32);
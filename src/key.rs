@@ -0,0 +1,97 @@
+//! Chroma keying.
+//!
+//! `ChromaKey` measures how far a pixel is from a key color, weighting luma and chroma
+//! differences separately, and turns that distance into a matte value in [0, 1]
+//! (0 = keyed out, 1 = fully kept).
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::oklab::Oklab;
+use crate::colorspaces::srgb::Srgb;
+use serde::{Deserialize, Serialize};
+
+const EPSILON: f64 = 1e-10;
+
+/// Space in which key distances are measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum KeySpace {
+    /// BT.709 Y'CbCr of sRGB-encoded values, as video keyers traditionally work
+    #[default]
+    YCbCr,
+    /// Oklab, for perceptually even falloff
+    Oklab,
+}
+
+impl KeySpace {
+    /// Split a color into a luma value and a two-component chroma vector.
+    fn split(&self, color: &Color) -> (f64, f64, f64) {
+        match self {
+            KeySpace::YCbCr => {
+                let Srgb { r, g, b, .. } = Srgb::from_color(color);
+                let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+                (y, (b - y) / 1.8556, (r - y) / 1.5748)
+            }
+            KeySpace::Oklab => {
+                let Oklab { l, a, b, .. } = Oklab::from_color(color);
+                (l, a, b)
+            }
+        }
+    }
+}
+
+/// A configurable chroma keyer.
+///
+/// Distances below `tolerance` are keyed out completely; the matte then ramps linearly
+/// to fully opaque over `softness`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ChromaKey {
+    /// Color to key out
+    pub key: Color,
+    /// Space the distance is measured in
+    pub space: KeySpace,
+    /// Weight of the luma difference (usually small, so shadows on the screen still key)
+    pub luma_weight: f64,
+    /// Weight of the chroma difference
+    pub chroma_weight: f64,
+    /// Distance at or below which the matte is 0
+    pub tolerance: f64,
+    /// Width of the transition from 0 to 1 beyond `tolerance`
+    pub softness: f64,
+}
+
+impl ChromaKey {
+    /// Keyer for `key` with YCbCr distances and defaults suited to green/blue screens.
+    pub fn new(key: Color) -> Self {
+        Self {
+            key,
+            space: KeySpace::YCbCr,
+            luma_weight: 0.1,
+            chroma_weight: 1.0,
+            tolerance: 0.1,
+            softness: 0.1,
+        }
+    }
+
+    /// Weighted distance between `color` and the key color.
+    pub fn distance(&self, color: &Color) -> f64 {
+        let (l1, c1, d1) = self.space.split(&self.key);
+        let (l2, c2, d2) = self.space.split(color);
+        let dl = l2 - l1;
+        let dc2 = (c2 - c1).powi(2) + (d2 - d1).powi(2);
+        (self.luma_weight * dl * dl + self.chroma_weight * dc2).sqrt()
+    }
+
+    /// Matte value in [0, 1]: 0 where `color` matches the key, 1 where it is kept.
+    pub fn matte(&self, color: &Color) -> f64 {
+        let d = self.distance(color) - self.tolerance;
+        if self.softness.abs() < EPSILON {
+            return if d > 0.0 { 1.0 } else { 0.0 };
+        }
+        (d / self.softness).clamp(0.0, 1.0)
+    }
+
+    /// Multiply the color's alpha by its matte value.
+    pub fn apply(&self, color: &Color) -> Color {
+        Color::new(color.r, color.g, color.b, color.a * self.matte(color))
+    }
+}
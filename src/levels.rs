@@ -0,0 +1,134 @@
+//! Lightness-based buffer enhancement.
+//!
+//! Auto-levels and histogram equalization act on the lightness channel of a perceptual
+//! space only, so hue and chroma are left as they were. The results are not gamut
+//! mapped; brightening a saturated color can push it outside sRGB.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::lab::Lab;
+use crate::colorspaces::oklab::Oklab;
+use serde::{Deserialize, Serialize};
+
+const EPSILON: f64 = 1e-10;
+
+/// Space whose lightness channel is adjusted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LightnessSpace {
+    /// Oklab L, in [0, 1]
+    #[default]
+    Oklab,
+    /// CIELAB L*, in [0, 100]
+    Lab,
+}
+
+impl LightnessSpace {
+    /// Largest lightness value (white).
+    fn max(&self) -> f64 {
+        match self {
+            LightnessSpace::Oklab => 1.0,
+            LightnessSpace::Lab => 100.0,
+        }
+    }
+
+    fn lightness(&self, color: &Color) -> f64 {
+        match self {
+            LightnessSpace::Oklab => Oklab::from_color(color).l,
+            LightnessSpace::Lab => Lab::from_color(color).l,
+        }
+    }
+
+    /// Replace the lightness of every pixel with `f(l)`.
+    fn remap<F: Fn(f64) -> f64>(&self, pixels: &mut [Color], f: F) {
+        for pixel in pixels.iter_mut() {
+            *pixel = match self {
+                LightnessSpace::Oklab => {
+                    let mut c = Oklab::from_color(pixel);
+                    c.l = f(c.l);
+                    c.to_color()
+                }
+                LightnessSpace::Lab => {
+                    let mut c = Lab::from_color(pixel);
+                    c.l = f(c.l);
+                    c.to_color()
+                }
+            };
+        }
+    }
+}
+
+/// Lightness of each pixel, sorted ascending.
+fn sorted_lightness(pixels: &[Color], space: LightnessSpace) -> Vec<f64> {
+    let mut values: Vec<f64> = pixels.iter().map(|p| space.lightness(p)).collect();
+    values.sort_by(f64::total_cmp);
+    values
+}
+
+/// Value at fraction `p` (in [0, 1]) of a sorted slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let pos = p.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let i = pos.floor() as usize;
+    let j = (i + 1).min(sorted.len() - 1);
+    sorted[i] + (sorted[j] - sorted[i]) * (pos - i as f64)
+}
+
+/// Stretch lightness so the `low` percentile maps to black and the `high` percentile
+/// to white, e.g. `auto_levels(pixels, space, 0.005, 0.995)`. Lightness outside that
+/// range is clipped.
+pub fn auto_levels(pixels: &mut [Color], space: LightnessSpace, low: f64, high: f64) {
+    if pixels.is_empty() {
+        return;
+    }
+    let sorted = sorted_lightness(pixels, space);
+    let lo = percentile(&sorted, low);
+    let hi = percentile(&sorted, high);
+    if hi - lo < EPSILON {
+        return;
+    }
+    let max = space.max();
+    space.remap(pixels, |l| ((l - lo) / (hi - lo)).clamp(0.0, 1.0) * max);
+}
+
+/// Histogram-equalize lightness using `bins` histogram bins.
+///
+/// `clip` is the fraction of pixels ignored at each end before equalization (0 for
+/// none); those pixels map to black or white.
+pub fn equalize(pixels: &mut [Color], space: LightnessSpace, bins: usize, clip: f64) {
+    if pixels.is_empty() || bins == 0 {
+        return;
+    }
+    let sorted = sorted_lightness(pixels, space);
+    let lo = percentile(&sorted, clip);
+    let hi = percentile(&sorted, 1.0 - clip);
+    if hi - lo < EPSILON {
+        return;
+    }
+
+    let bin_of = |l: f64| (((l - lo) / (hi - lo) * bins as f64) as usize).min(bins - 1);
+    let mut cdf = vec![0.0; bins];
+    let mut total = 0.0;
+    for &l in sorted.iter().filter(|&&l| l >= lo && l <= hi) {
+        cdf[bin_of(l)] += 1.0;
+        total += 1.0;
+    }
+    let mut acc = 0.0;
+    for c in cdf.iter_mut() {
+        acc += *c;
+        *c = acc / total;
+    }
+
+    let max = space.max();
+    space.remap(pixels, |l| {
+        if l <= lo {
+            0.0
+        } else if l >= hi {
+            max
+        } else {
+            // Interpolate within the bin so the mapping is continuous
+            let pos = (l - lo) / (hi - lo) * bins as f64;
+            let i = (pos as usize).min(bins - 1);
+            let prev = if i == 0 { 0.0 } else { cdf[i - 1] };
+            (prev + (cdf[i] - prev) * (pos - i as f64)) * max
+        }
+    });
+}
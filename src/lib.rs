@@ -1,17 +1,94 @@
+//! Color spaces and color science on a linear-sRGB `Color` hub.
+//!
+//! # Precision
+//!
+//! Matrix conversions use fused multiply-add only when FMA is enabled at compile time.
+//! Default x86-64 builds do not enable it; to get the extra precision, build with
+//! `RUSTFLAGS="-C target-feature=+fma"` (or `-C target-cpu=native`) on hardware that has
+//! it.
+
+pub mod chromatic_adaptation;
+#[cfg(feature = "clf")]
+pub mod clf;
 pub mod colorspaces;
+pub mod contrast;
+pub mod curve;
+pub mod distance;
+pub mod filters;
+pub mod gamut;
+pub mod gradient;
+pub mod hdr;
+pub mod interop;
+pub mod key;
+pub mod levels;
+mod math;
+pub mod mix;
+pub mod nearest;
+pub mod palette;
+pub mod parse;
+pub mod pipeline;
+pub mod spectral;
+pub mod stream;
+pub mod theme;
+pub mod tonemap;
+pub mod white_point;
 
+pub use colorspaces::aces::{Aces2065_1, AcesCc, AcesCct, AcesCg};
 pub use colorspaces::adobe_rgb::AdobeRgb;
+pub use colorspaces::cam16::{Cam16, Cam16Ucs};
+pub use colorspaces::cct::Cct;
+pub use colorspaces::ciecam02::{Cam02Ucs, Ciecam02};
+pub use colorspaces::cmy::Cmy;
+pub use colorspaces::cmyk::Cmyk;
 pub use colorspaces::color::Color;
-pub use colorspaces::colorspace::ColorSpace;
+pub use colorspaces::colorspace::{ColorSpace, Gamut, Interpolate};
+pub use colorspaces::custom_rgb::{CustomRgb, CustomRgbSpace, TransferFunction};
+pub use colorspaces::dci_p3::DciP3;
+pub use colorspaces::din99::Din99;
 pub use colorspaces::display_p3::DisplayP3;
+pub use colorspaces::gamma_rgb::{GammaRgb, RgbPrimaries};
+pub use colorspaces::hct::Hct;
+pub use colorspaces::hpluv::Hpluv;
 pub use colorspaces::hsl::Hsl;
+pub use colorspaces::hsluv::Hsluv;
 pub use colorspaces::hsv::Hsv;
+pub use colorspaces::hunter_lab::HunterLab;
 pub use colorspaces::hwb::Hwb;
-pub use colorspaces::lab::Lab;
+pub use colorspaces::ictcp::Ictcp;
+pub use colorspaces::lab::{Lab, LabD50};
 pub use colorspaces::lch::Lch;
-pub use colorspaces::luv::Luv;
+pub use colorspaces::linear_adobe_rgb::LinearAdobeRgb;
+pub use colorspaces::linear_display_p3::LinearDisplayP3;
+pub use colorspaces::linear_rec2020::LinearRec2020;
+pub use colorspaces::linear_srgb::LinearSrgb;
+pub use colorspaces::lms::{ConeFundamentals, Lms};
+pub use colorspaces::luma::{Luma, LumaDerivation};
+pub use colorspaces::luv::{Luv, LuvD50};
+pub use colorspaces::munsell::{Munsell, MunsellRenotation, RenotationError};
+pub use colorspaces::okhsl::Okhsl;
+pub use colorspaces::okhsv::Okhsv;
 pub use colorspaces::oklab::Oklab;
 pub use colorspaces::oklch::Oklch;
+pub use colorspaces::oklrab::Oklrab;
+pub use colorspaces::oklrch::Oklrch;
+pub use colorspaces::prolab::ProLab;
+pub use colorspaces::prophoto_rgb::ProPhotoRgb;
+pub use colorspaces::rec601::Rec601;
+pub use colorspaces::rec709::Rec709;
 pub use colorspaces::rec2020::Rec2020;
+pub use colorspaces::rec2100_hlg::Rec2100Hlg;
+pub use colorspaces::ryb::Ryb;
+pub use colorspaces::scrgb::ScRgb;
 pub use colorspaces::srgb::Srgb;
+pub use colorspaces::srlab2::Srlab2;
+pub use colorspaces::tsl::Tsl;
+pub use colorspaces::uv::{Uv1960, UvPrime};
+pub use colorspaces::uvw::Uvw;
+pub use colorspaces::viewing_conditions::{Surround, ViewingConditions};
+pub use colorspaces::xyb::Xyb;
+pub use colorspaces::xyy::Xyy;
 pub use colorspaces::xyz::Xyz;
+pub use colorspaces::ycbcr::{LuminanceMode, QuantizationRange, YCbCr601, YCbCr709, YCbCr2020};
+pub use colorspaces::ycocg::{YCoCg, YCoCgR};
+pub use colorspaces::yiq::Yiq;
+pub use colorspaces::ypbpr::{YPbPr, YPbPrStandard};
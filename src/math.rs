@@ -0,0 +1,84 @@
+//! Small fixed-size linear algebra shared by the color conversions.
+//!
+//! Products are accumulated with fused multiply-add when the target has an FMA
+//! instruction, which rounds once per term instead of twice. Elsewhere `f64::mul_add`
+//! would be a slow software call, so plain multiply-add is used instead.
+//!
+//! The choice is made at compile time. Default x86-64 builds do not enable FMA, so they
+//! get plain multiply-add everywhere; build with `-C target-feature=+fma` (or
+//! `-C target-cpu=native`) for the fused path. A run-time check was tried in
+//! `Mat3::apply`, but it stops the product from inlining and made conversions about
+//! twice as slow.
+
+pub(crate) type Vec3 = [f64; 3];
+
+/// Row-major 3×3 matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Mat3(pub [[f64; 3]; 3]);
+
+impl Mat3 {
+    /// Matrix with `d` on the diagonal.
+    pub const fn diagonal(d: Vec3) -> Mat3 {
+        Mat3([[d[0], 0.0, 0.0], [0.0, d[1], 0.0], [0.0, 0.0, d[2]]])
+    }
+
+    /// Matrix-vector product.
+    #[inline]
+    pub fn apply(&self, v: Vec3) -> Vec3 {
+        let m = &self.0;
+        [dot(m[0], v), dot(m[1], v), dot(m[2], v)]
+    }
+
+    /// Matrix product `self · other` (apply `other` first).
+    pub fn mul(&self, other: &Mat3) -> Mat3 {
+        let o = &other.0;
+        Mat3(
+            self.0
+                .map(|row| [0, 1, 2].map(|j| dot(row, [o[0][j], o[1][j], o[2][j]]))),
+        )
+    }
+
+    /// Inverse by cofactors. Singular matrices produce non-finite entries.
+    pub fn inverse(&self) -> Mat3 {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.0;
+        let co = [
+            mul_add(e, i, -f * h),
+            mul_add(f, g, -d * i),
+            mul_add(d, h, -e * g),
+        ];
+        let inv_det = 1.0 / dot([a, b, c], co);
+        Mat3([
+            [
+                co[0] * inv_det,
+                mul_add(c, h, -b * i) * inv_det,
+                mul_add(b, f, -c * e) * inv_det,
+            ],
+            [
+                co[1] * inv_det,
+                mul_add(a, i, -c * g) * inv_det,
+                mul_add(c, d, -a * f) * inv_det,
+            ],
+            [
+                co[2] * inv_det,
+                mul_add(b, g, -a * h) * inv_det,
+                mul_add(a, e, -b * d) * inv_det,
+            ],
+        ])
+    }
+}
+
+/// `a * b + c`, fused where the hardware supports it.
+#[inline(always)]
+pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+    if cfg!(target_feature = "fma") {
+        a.mul_add(b, c)
+    } else {
+        a * b + c
+    }
+}
+
+/// Dot product of two 3-vectors.
+#[inline]
+pub(crate) fn dot(a: Vec3, b: Vec3) -> f64 {
+    mul_add(a[2], b[2], mul_add(a[1], b[1], a[0] * b[0]))
+}
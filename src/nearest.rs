@@ -0,0 +1,167 @@
+//! Nearest-color search.
+//!
+//! `nearest` scans the candidates once. `NearestIndex` builds a k-d tree over the
+//! candidates for repeated queries and returns the same result as a scan. Metrics that
+//! are Euclidean in some space (ΔE76, ΔEOK, DIN99, ΔEITP) search that space directly.
+//! CIE94, CIEDE2000 and HyAB search CIELAB, pruning with a lower bound of the metric in
+//! terms of ΔE76; the CIEDE2000 bound is loose, so its searches prune little once the
+//! nearest candidate is further than about ΔE00 8.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::lab::Lab;
+use crate::distance::{DistanceMetric, delta_e_1994, delta_e_2000, hyab};
+
+/// sqrt(1 - sin 60°): the CIEDE2000 rotation term can cancel at most this much of the
+/// weighted difference
+const ROTATION_FLOOR: f64 = 0.36602540378443865;
+
+/// Index of the candidate closest to `color`, or `None` if there are no candidates.
+///
+/// With `DistanceMetric::DeltaE94`, `color` is the reference. Ties go to the earlier
+/// candidate.
+pub fn nearest(color: &Color, candidates: &[Color], metric: DistanceMetric) -> Option<usize> {
+    let mut best = None;
+    let mut best_distance = f64::INFINITY;
+    for (i, candidate) in candidates.iter().enumerate() {
+        let d = metric.distance(color, candidate);
+        if d < best_distance {
+            (best, best_distance) = (Some(i), d);
+        }
+    }
+    best
+}
+
+/// A k-d tree over a fixed set of candidate colors, for repeated nearest-color queries.
+#[derive(Debug, Clone)]
+pub struct NearestIndex {
+    metric: DistanceMetric,
+    colors: Vec<Color>,
+    /// Candidate coordinates in the metric's space
+    points: Vec<[f64; 3]>,
+    /// Candidate indices in tree order: each node is the median of its range, split on
+    /// axis `depth % 3`
+    order: Vec<usize>,
+}
+
+impl NearestIndex {
+    /// Build an index over `candidates` for the given metric.
+    pub fn new(candidates: &[Color], metric: DistanceMetric) -> Self {
+        let points: Vec<[f64; 3]> = candidates.iter().map(|c| metric.embed(c)).collect();
+        let mut order: Vec<usize> = (0..candidates.len()).collect();
+        build(&points, &mut order, 0);
+        Self {
+            metric,
+            colors: candidates.to_vec(),
+            points,
+            order,
+        }
+    }
+
+    /// Number of candidates in the index.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Whether the index has no candidates.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// The candidates, in the order they were given.
+    pub fn candidates(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Index of the candidate closest to `color`, or `None` if there are no candidates.
+    ///
+    /// Agrees with `nearest` over the same candidates.
+    pub fn nearest(&self, color: &Color) -> Option<usize> {
+        let query = self.metric.embed(color);
+        let mut best = (f64::INFINITY, usize::MAX);
+        self.search(&query, &self.order, 0, &mut best);
+        (best.1 != usize::MAX).then_some(best.1)
+    }
+
+    /// Metric distance from the query to candidate coordinates `p`.
+    fn distance(&self, query: &[f64; 3], p: &[f64; 3]) -> f64 {
+        let lab = |[l, a, b]: [f64; 3]| Lab::new(l, a, b);
+        match self.metric {
+            DistanceMetric::DeltaE94(application) => {
+                delta_e_1994(&lab(*query), &lab(*p), application)
+            }
+            DistanceMetric::DeltaE2000 => delta_e_2000(&lab(*query), &lab(*p)),
+            DistanceMetric::HyAb => hyab(&lab(*query), &lab(*p)),
+            _ => {
+                ((query[0] - p[0]).powi(2) + (query[1] - p[1]).powi(2) + (query[2] - p[2]).powi(2))
+                    .sqrt()
+            }
+        }
+    }
+
+    /// Largest coordinate distance at which a candidate could still be within `best` of
+    /// the query by the metric.
+    fn radius(&self, query: &[f64; 3], best: f64) -> f64 {
+        match self.metric {
+            DistanceMetric::DeltaE94(application) => {
+                // With the query as reference the weights are fixed, and each is >= 1
+                let (kl, k1, k2) = application.constants();
+                let c = query[1].hypot(query[2]);
+                best * kl.max(1.0 + k1 * c).max(1.0 + k2 * c)
+            }
+            DistanceMetric::DeltaE2000 => {
+                // ΔE00 >= ROTATION_FLOOR · D / S with the weights S <= s0 + s1 · D, where D is
+                // ΔE76 and s0, s1 bound SL and SC given the query's L* and C*
+                let c = query[1].hypot(query[2]);
+                let s0 = 1.0 + 0.015 * (query[0] - 50.0).abs() + 0.0675 * c;
+                let s1 = 0.0075 + 0.03375;
+                let denominator = ROTATION_FLOOR - s1 * best;
+                if denominator <= 0.0 {
+                    f64::INFINITY
+                } else {
+                    best * s0 / denominator
+                }
+            }
+            // HyAB is at least ΔE76; the rest are Euclidean in these coordinates
+            _ => best,
+        }
+    }
+
+    fn search(&self, query: &[f64; 3], range: &[usize], depth: usize, best: &mut (f64, usize)) {
+        if range.is_empty() {
+            return;
+        }
+        let mid = range.len() / 2;
+        let index = range[mid];
+        let point = &self.points[index];
+        let d = self.distance(query, point);
+        // Ties go to the earlier candidate, as in a scan
+        if d < best.0 || (d == best.0 && index < best.1) {
+            *best = (d, index);
+        }
+
+        let axis = depth % 3;
+        let offset = query[axis] - point[axis];
+        let (near, far) = if offset < 0.0 {
+            (&range[..mid], &range[mid + 1..])
+        } else {
+            (&range[mid + 1..], &range[..mid])
+        };
+        self.search(query, near, depth + 1, best);
+        if offset.abs() <= self.radius(query, best.0) {
+            self.search(query, far, depth + 1, best);
+        }
+    }
+}
+
+/// Arrange `order` into an implicit k-d tree over `points`.
+fn build(points: &[[f64; 3]], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let axis = depth % 3;
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by(mid, |&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
@@ -0,0 +1,442 @@
+//! Palette operations.
+//!
+//! Palettes are plain slices of `Color`. Morphing pairs the entries of two palettes by
+//! minimum total Oklab distance (an optimal assignment, so no color is dragged across
+//! the palette when a closer partner exists) and interpolates each pair in Oklch.
+//!
+//! Categorical color cycles for plotting can be generated under lightness, chroma and
+//! minimum-distance constraints, or taken from well-known presets.
+//!
+//! `quantize` reduces an image to a few representative swatches by k-means in Oklab.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::oklab::Oklab;
+use crate::colorspaces::oklch::Oklch;
+use crate::colorspaces::srgb::Srgb;
+use serde::{Deserialize, Serialize};
+
+const EPSILON: f64 = 1e-10;
+
+fn oklab_distance(a: &Oklab, b: &Oklab) -> f64 {
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+/// Minimum-cost assignment of each row to a distinct column (rows <= columns), using the
+/// Hungarian algorithm. Returns the column chosen for each row.
+fn assign(cost: &[Vec<f64>]) -> Vec<usize> {
+    let n = cost.len();
+    let m = cost.first().map_or(0, Vec::len);
+    // Potentials and matching are 1-indexed with 0 as a sentinel column
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    let mut row_of = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min_v = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if !used[j] {
+                    let cur = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                    if cur < min_v[j] {
+                        min_v[j] = cur;
+                        way[j] = j0;
+                    }
+                    if min_v[j] < delta {
+                        delta = min_v[j];
+                        j1 = j;
+                    }
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_v[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+        loop {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+            if j0 == 0 {
+                break;
+            }
+        }
+    }
+    let mut column_of = vec![0; n];
+    for j in 1..=m {
+        if row_of[j] != 0 {
+            column_of[row_of[j] - 1] = j - 1;
+        }
+    }
+    column_of
+}
+
+/// Pair the entries of two palettes by perceptual similarity.
+///
+/// Returns `(index_in_a, index_in_b)` pairs. With equal sizes this is a one-to-one
+/// optimal matching. Otherwise every entry of the smaller palette is matched once
+/// optimally, and the remaining entries of the larger one pair with their nearest
+/// color in the smaller one, so the result has `max(a.len(), b.len())` pairs.
+pub fn match_palettes(a: &[Color], b: &[Color]) -> Vec<(usize, usize)> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let (small, large, swapped) = if a.len() <= b.len() {
+        (a, b, false)
+    } else {
+        (b, a, true)
+    };
+    let small_lab: Vec<Oklab> = small.iter().map(Oklab::from_color).collect();
+    let large_lab: Vec<Oklab> = large.iter().map(Oklab::from_color).collect();
+    let cost: Vec<Vec<f64>> = small_lab
+        .iter()
+        .map(|s| large_lab.iter().map(|l| oklab_distance(s, l)).collect())
+        .collect();
+
+    let mut pairs: Vec<(usize, usize)> = assign(&cost).into_iter().enumerate().collect();
+    let mut taken = vec![false; large.len()];
+    for &(_, j) in &pairs {
+        taken[j] = true;
+    }
+    for (j, _) in taken.iter().enumerate().filter(|(_, t)| !**t) {
+        let nearest = (0..small.len())
+            .min_by(|&x, &y| cost[x][j].total_cmp(&cost[y][j]))
+            .unwrap_or(0);
+        pairs.push((nearest, j));
+    }
+    // Order by index in `a`
+    pairs.sort_by_key(|&(i, j)| if swapped { (j, i) } else { (i, j) });
+    if swapped {
+        pairs.into_iter().map(|(i, j)| (j, i)).collect()
+    } else {
+        pairs
+    }
+}
+
+/// Interpolate two colors in Oklch along the shorter hue arc.
+fn lerp_oklch(a: &Color, b: &Color, t: f64) -> Color {
+    let a = Oklch::from_color(a);
+    let b = Oklch::from_color(b);
+    // An achromatic endpoint has no meaningful hue; borrow the other one's
+    let ha = if a.c < EPSILON { b.h } else { a.h };
+    let hb = if b.c < EPSILON { a.h } else { b.h };
+    let mut dh = (hb - ha) % 360.0;
+    if dh > 180.0 {
+        dh -= 360.0;
+    } else if dh < -180.0 {
+        dh += 360.0;
+    }
+    Oklch {
+        l: a.l + (b.l - a.l) * t,
+        c: a.c + (b.c - a.c) * t,
+        h: (ha + dh * t).rem_euclid(360.0),
+        alpha: a.alpha + (b.alpha - a.alpha) * t,
+    }
+    .to_color()
+}
+
+/// A precomputed pairing between two palettes, for producing intermediate palettes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteMorph {
+    pairs: Vec<(Color, Color)>,
+}
+
+impl PaletteMorph {
+    /// Match `from` to `to` (see `match_palettes`).
+    pub fn new(from: &[Color], to: &[Color]) -> Self {
+        let pairs = match_palettes(from, to)
+            .into_iter()
+            .map(|(i, j)| (from[i], to[j]))
+            .collect();
+        Self { pairs }
+    }
+
+    /// Number of entries in every intermediate palette.
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Whether either input palette was empty.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Intermediate palette at `t` (0 = `from`, 1 = `to`).
+    pub fn at(&self, t: f64) -> Vec<Color> {
+        self.pairs
+            .iter()
+            .map(|(a, b)| lerp_oklch(a, b, t))
+            .collect()
+    }
+
+    /// `steps` evenly spaced palettes from `from` to `to`, inclusive.
+    pub fn steps(&self, steps: usize) -> Vec<Vec<Color>> {
+        match steps {
+            0 => Vec::new(),
+            1 => vec![self.at(0.0)],
+            n => (0..n).map(|i| self.at(i as f64 / (n - 1) as f64)).collect(),
+        }
+    }
+}
+
+/// Intermediate palette between `from` and `to` at `t`.
+pub fn morph_palettes(from: &[Color], to: &[Color], t: f64) -> Vec<Color> {
+    PaletteMorph::new(from, to).at(t)
+}
+
+/// Well-known categorical color cycles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CyclePreset {
+    /// matplotlib's default `tab10` cycle (Tableau 10)
+    Tab10,
+    /// Okabe & Ito's color-blind-safe palette
+    OkabeIto,
+}
+
+impl CyclePreset {
+    fn hex(&self) -> &'static [u32] {
+        match self {
+            CyclePreset::Tab10 => &[
+                0x1f77b4, 0xff7f0e, 0x2ca02c, 0xd62728, 0x9467bd, 0x8c564b, 0xe377c2, 0x7f7f7f,
+                0xbcbd22, 0x17becf,
+            ],
+            CyclePreset::OkabeIto => &[
+                0xe69f00, 0x56b4e9, 0x009e73, 0xf0e442, 0x0072b2, 0xd55e00, 0xcc79a7, 0x000000,
+            ],
+        }
+    }
+
+    /// The cycle's colors in order.
+    pub fn colors(&self) -> Vec<Color> {
+        self.hex()
+            .iter()
+            .map(|&hex| {
+                let channel = |shift: u32| ((hex >> shift) & 0xff) as f64 / 255.0;
+                Srgb {
+                    r: channel(16),
+                    g: channel(8),
+                    b: channel(0),
+                    a: 1.0,
+                }
+                .to_color()
+            })
+            .collect()
+    }
+}
+
+/// Constraints for generated categorical color cycles. Values are in Oklch units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CycleOptions {
+    /// Lightness band (min, max)
+    pub lightness: (f64, f64),
+    /// Chroma band (min, max); chroma is reduced below the band only to stay in sRGB
+    pub chroma: (f64, f64),
+    /// Minimum Oklab distance between any two colors of the cycle
+    pub min_delta_e: f64,
+    /// Hue of the first color in degrees
+    pub start_hue: f64,
+}
+
+impl Default for CycleOptions {
+    fn default() -> Self {
+        Self {
+            lightness: (0.55, 0.75),
+            chroma: (0.10, 0.16),
+            min_delta_e: 0.08,
+            start_hue: 250.0,
+        }
+    }
+}
+
+/// Golden angle in degrees, which spreads successive hues evenly around the circle
+const GOLDEN_ANGLE: f64 = 137.50776405003785;
+
+/// Most chromatic in-sRGB color at the given lightness and hue, up to `max_chroma`.
+pub(crate) fn in_gamut_oklch(l: f64, max_chroma: f64, h: f64) -> Color {
+    let inside = |c: &Color| {
+        [c.r, c.g, c.b]
+            .iter()
+            .all(|v| (-EPSILON..=1.0 + EPSILON).contains(v))
+    };
+    let at = |c: f64| {
+        Oklch {
+            l,
+            c,
+            h,
+            alpha: 1.0,
+        }
+        .to_color()
+    };
+    let full = at(max_chroma);
+    if inside(&full) {
+        return full;
+    }
+    let (mut lo, mut hi) = (0.0, max_chroma);
+    for _ in 0..32 {
+        let mid = 0.5 * (lo + hi);
+        if inside(&at(mid)) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    at(lo)
+}
+
+/// Generate `n` categorical colors within the bands of `options`.
+///
+/// Candidates step around the hue circle by the golden angle, cycling through three
+/// lightness and chroma levels within the bands. A candidate closer than `min_delta_e`
+/// to an accepted color is skipped; if no candidate qualifies the threshold is
+/// relaxed, so `n` colors are always returned.
+pub fn color_cycle(n: usize, options: &CycleOptions) -> Vec<Color> {
+    const LEVELS: usize = 3;
+    const ATTEMPTS: usize = 64;
+    let (l0, l1) = options.lightness;
+    let (c0, c1) = options.chroma;
+    let mut colors: Vec<Color> = Vec::with_capacity(n);
+    let mut labs: Vec<Oklab> = Vec::with_capacity(n);
+    let mut candidate = 0usize;
+    let mut threshold = options.min_delta_e;
+    while colors.len() < n {
+        let mut accepted = false;
+        for _ in 0..ATTEMPTS {
+            let k = candidate;
+            candidate += 1;
+            let level = |i: usize| [0.5, 1.0, 0.0][i % LEVELS];
+            let l = l0 + (l1 - l0) * level(k);
+            let c = c1 - (c1 - c0) * level(k / LEVELS);
+            let h = (options.start_hue + GOLDEN_ANGLE * k as f64).rem_euclid(360.0);
+            let color = in_gamut_oklch(l, c, h);
+            let lab = Oklab::from_color(&color);
+            if labs.iter().all(|o| oklab_distance(o, &lab) >= threshold) {
+                colors.push(color);
+                labs.push(lab);
+                accepted = true;
+                break;
+            }
+        }
+        if !accepted {
+            threshold *= 0.8;
+        }
+    }
+    colors
+}
+
+/// A representative color of an image and the share of pixels it stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Swatch {
+    pub color: Color,
+    /// Fraction of the counted pixels, in [0, 1]
+    pub population: f64,
+}
+
+/// Most pixels clustered by `quantize`; larger inputs are subsampled evenly
+const MAX_SAMPLES: usize = 16384;
+
+/// Reduce `pixels` to at most `k` swatches by k-means clustering in Oklab.
+///
+/// Pixels with alpha below 0.5 are ignored. Initial centers are chosen by farthest-point
+/// sampling, so the result is deterministic. Swatches are sorted by population,
+/// largest first; fewer than `k` are returned when the image has fewer distinct colors.
+pub fn quantize(pixels: &[Color], k: usize) -> Vec<Swatch> {
+    let opaque: Vec<&Color> = pixels.iter().filter(|c| c.a >= 0.5).collect();
+    if opaque.is_empty() || k == 0 {
+        return Vec::new();
+    }
+    let stride = opaque.len().div_ceil(MAX_SAMPLES);
+    let samples: Vec<Oklab> = opaque
+        .iter()
+        .step_by(stride)
+        .map(|c| Oklab::from_color(c))
+        .collect();
+
+    // Farthest-point initialization, starting from the sample nearest the mean
+    let n = samples.len() as f64;
+    let mean = samples.iter().fold(Oklab::new(0.0, 0.0, 0.0), |m, s| {
+        Oklab::new(m.l + s.l / n, m.a + s.a / n, m.b + s.b / n)
+    });
+    let first = samples
+        .iter()
+        .min_by(|x, y| oklab_distance(x, &mean).total_cmp(&oklab_distance(y, &mean)))
+        .copied()
+        .unwrap_or(mean);
+    let mut centers = vec![first];
+    let mut nearest: Vec<f64> = samples.iter().map(|s| oklab_distance(s, &first)).collect();
+    while centers.len() < k {
+        let (i, &d) = nearest
+            .iter()
+            .enumerate()
+            .max_by(|x, y| x.1.total_cmp(y.1))
+            .unwrap_or((0, &0.0));
+        if d < EPSILON {
+            break;
+        }
+        centers.push(samples[i]);
+        for (near, s) in nearest.iter_mut().zip(&samples) {
+            *near = near.min(oklab_distance(s, &samples[i]));
+        }
+    }
+
+    let mut labels = vec![0usize; samples.len()];
+    for _ in 0..16 {
+        let mut changed = false;
+        for (label, s) in labels.iter_mut().zip(&samples) {
+            let best = (0..centers.len())
+                .min_by(|&x, &y| {
+                    oklab_distance(s, &centers[x]).total_cmp(&oklab_distance(s, &centers[y]))
+                })
+                .unwrap_or(0);
+            changed |= best != *label;
+            *label = best;
+        }
+        let mut sums = vec![([0.0; 3], 0usize); centers.len()];
+        for (&label, s) in labels.iter().zip(&samples) {
+            let (sum, count) = &mut sums[label];
+            sum[0] += s.l;
+            sum[1] += s.a;
+            sum[2] += s.b;
+            *count += 1;
+        }
+        for (center, (sum, count)) in centers.iter_mut().zip(&sums) {
+            if *count > 0 {
+                let n = *count as f64;
+                *center = Oklab::new(sum[0] / n, sum[1] / n, sum[2] / n);
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut counts = vec![0usize; centers.len()];
+    for &label in &labels {
+        counts[label] += 1;
+    }
+    let mut swatches: Vec<Swatch> = centers
+        .iter()
+        .zip(&counts)
+        .filter(|(_, count)| **count > 0)
+        .map(|(center, &count)| Swatch {
+            color: center.to_color(),
+            population: count as f64 / n,
+        })
+        .collect();
+    swatches.sort_by(|x, y| y.population.total_cmp(&x.population));
+    swatches
+}
@@ -0,0 +1,373 @@
+//! Permissive parsing of user-typed color strings.
+//!
+//! `parse_lenient` accepts the kind of input people paste into color pickers and CLIs:
+//! hex with or without `#`, `rgb(...)`/`hsl(...)` with or without parentheses, bare
+//! component lists separated by commas, spaces or both (or, with a warning, semicolons),
+//! and components on either the 0-255 or 0-1 scale. Rather than failing on sloppy input
+//! it returns the chosen interpretation together with a confidence score and the
+//! warnings that lowered it.
+//!
+//! All parsed values are treated as sRGB-encoded, as in CSS.
+
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::hsl::Hsl;
+use crate::colorspaces::srgb::Srgb;
+use std::fmt;
+
+/// How the input string was interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    /// Hexadecimal digits (3, 4, 6 or 8 of them)
+    Hex,
+    /// RGB components on the 0-255 scale
+    Rgb255,
+    /// RGB components on the 0-1 scale
+    RgbUnit,
+    /// RGB components given as percentages
+    RgbPercent,
+    /// Hue in degrees, saturation and lightness
+    Hsl,
+}
+
+/// Something that was accepted but may not mean what the user intended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseWarning {
+    /// Hex digits without a leading `#`
+    MissingHash,
+    /// Hex given with a `0x` prefix instead of `#`
+    NonCssHexPrefix,
+    /// A function name without (or with unbalanced) parentheses
+    MissingParentheses,
+    /// Bare numbers without an `rgb`/`hsl` function name
+    MissingFunctionName,
+    /// Commas and whitespace both used as component separators
+    MixedSeparators,
+    /// Components separated by semicolons, which CSS does not accept
+    NonCssSeparator,
+    /// Components could be read on either the 0-1 or 0-255 scale
+    AmbiguousScale,
+    /// A component was outside its valid range and was clamped (value as written)
+    ClampedComponent { index: usize, value: f64 },
+}
+
+impl ParseWarning {
+    /// Factor applied to the confidence score for this warning.
+    fn penalty(&self) -> f64 {
+        match self {
+            ParseWarning::MissingHash => 0.9,
+            ParseWarning::NonCssHexPrefix => 0.95,
+            ParseWarning::MissingParentheses => 0.95,
+            ParseWarning::MissingFunctionName => 0.85,
+            ParseWarning::MixedSeparators => 0.9,
+            ParseWarning::NonCssSeparator => 0.9,
+            ParseWarning::AmbiguousScale => 0.6,
+            ParseWarning::ClampedComponent { .. } => 0.8,
+        }
+    }
+}
+
+/// Input that could not be interpreted as a color at all.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The input was empty or only whitespace
+    Empty,
+    /// The input matched none of the accepted notations
+    UnrecognizedFormat,
+    /// Hex input with an unsupported number of digits
+    InvalidHexLength(usize),
+    /// A component list with a count other than 3 or 4
+    WrongComponentCount(usize),
+    /// A component that is not a number
+    InvalidNumber(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty color string"),
+            ParseError::UnrecognizedFormat => write!(f, "unrecognized color format"),
+            ParseError::InvalidHexLength(n) => {
+                write!(f, "hex colors need 3, 4, 6 or 8 digits, got {n}")
+            }
+            ParseError::WrongComponentCount(n) => {
+                write!(f, "expected 3 or 4 components, got {n}")
+            }
+            ParseError::InvalidNumber(s) => write!(f, "invalid number `{s}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Result of a lenient parse.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LenientParse {
+    /// The parsed color, sRGB-encoded
+    pub color: Srgb,
+    /// How the input was interpreted
+    pub interpretation: Interpretation,
+    /// Confidence in the interpretation, 1.0 for unambiguous CSS-style input
+    pub confidence: f64,
+    /// Everything that lowered the confidence
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// A numeric component as written, before any scale is applied.
+struct Token {
+    value: f64,
+    percent: bool,
+    fractional: bool,
+}
+
+fn parse_token(raw: &str) -> Result<Token, ParseError> {
+    let (body, percent) = match raw.strip_suffix('%') {
+        Some(body) => (body, true),
+        None => (raw.strip_suffix("deg").unwrap_or(raw), false),
+    };
+    let value = body
+        .parse::<f64>()
+        .map_err(|_| ParseError::InvalidNumber(raw.to_string()))?;
+    if !value.is_finite() {
+        return Err(ParseError::InvalidNumber(raw.to_string()));
+    }
+    Ok(Token {
+        value,
+        percent,
+        fractional: body.contains('.'),
+    })
+}
+
+fn parse_hex(digits: &str) -> Result<Srgb, ParseError> {
+    let nibble = |i: usize| u8::from_str_radix(&digits[i..=i], 16).unwrap() as f64;
+    let byte = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).unwrap() as f64;
+    let (r, g, b, a) = match digits.len() {
+        3 => (nibble(0) * 17.0, nibble(1) * 17.0, nibble(2) * 17.0, 255.0),
+        4 => (
+            nibble(0) * 17.0,
+            nibble(1) * 17.0,
+            nibble(2) * 17.0,
+            nibble(3) * 17.0,
+        ),
+        6 => (byte(0), byte(2), byte(4), 255.0),
+        8 => (byte(0), byte(2), byte(4), byte(6)),
+        n => return Err(ParseError::InvalidHexLength(n)),
+    };
+    Ok(Srgb {
+        r: r / 255.0,
+        g: g / 255.0,
+        b: b / 255.0,
+        a: a / 255.0,
+    })
+}
+
+/// Clamp a scaled component to [0, 1], reporting the value as written.
+fn clamp_component(
+    scaled: f64,
+    written: f64,
+    index: usize,
+    warnings: &mut Vec<ParseWarning>,
+) -> f64 {
+    if !(0.0..=1.0).contains(&scaled) {
+        warnings.push(ParseWarning::ClampedComponent {
+            index,
+            value: written,
+        });
+    }
+    scaled.clamp(0.0, 1.0)
+}
+
+/// Choose between the 0-1 and 0-255 readings of unsuffixed components.
+fn byte_scale(tokens: &[Token], warnings: &mut Vec<ParseWarning>) -> bool {
+    let plain: Vec<&Token> = tokens.iter().filter(|t| !t.percent).collect();
+    if plain.iter().any(|t| t.value > 1.0) {
+        true
+    } else if plain.iter().any(|t| t.fractional) {
+        false
+    } else {
+        // Only integer 0s and 1s: "1 0 0" is red on the unit scale but near-black on
+        // the byte scale. Prefer the unit reading, unless everything is zero.
+        if plain.iter().any(|t| t.value != 0.0) {
+            warnings.push(ParseWarning::AmbiguousScale);
+        }
+        false
+    }
+}
+
+fn parse_alpha(token: Option<&Token>, byte: bool, warnings: &mut Vec<ParseWarning>) -> f64 {
+    let Some(t) = token else {
+        return 1.0;
+    };
+    let value = if t.percent {
+        t.value / 100.0
+    } else if t.value > 1.0 && byte {
+        t.value / 255.0
+    } else {
+        t.value
+    };
+    clamp_component(value, t.value, 3, warnings)
+}
+
+fn parse_rgb(tokens: &[Token], warnings: &mut Vec<ParseWarning>) -> (Srgb, Interpretation) {
+    let color = &tokens[..3];
+    let byte = byte_scale(color, warnings);
+    let interpretation = if color.iter().all(|t| t.percent) {
+        Interpretation::RgbPercent
+    } else if byte {
+        Interpretation::Rgb255
+    } else {
+        Interpretation::RgbUnit
+    };
+
+    let mut channel = |i: usize| {
+        let t = &color[i];
+        let value = if t.percent {
+            t.value / 100.0
+        } else if byte {
+            t.value / 255.0
+        } else {
+            t.value
+        };
+        clamp_component(value, t.value, i, warnings)
+    };
+    let (r, g, b) = (channel(0), channel(1), channel(2));
+    let a = parse_alpha(tokens.get(3), byte, warnings);
+    (Srgb { r, g, b, a }, interpretation)
+}
+
+fn parse_hsl(tokens: &[Token], warnings: &mut Vec<ParseWarning>) -> Srgb {
+    let h = tokens[0].value.rem_euclid(360.0);
+    let sl = &tokens[1..3];
+    // Saturation and lightness are percentages in CSS; accept 0-1 fractions too
+    let hundred = sl.iter().any(|t| t.percent || t.value > 1.0);
+    if !hundred && sl.iter().all(|t| !t.fractional) && sl.iter().any(|t| t.value != 0.0) {
+        warnings.push(ParseWarning::AmbiguousScale);
+    }
+    let mut unit = |i: usize| {
+        let t = &tokens[i];
+        let value = if t.percent || hundred {
+            t.value / 100.0
+        } else {
+            t.value
+        };
+        clamp_component(value, t.value, i, warnings)
+    };
+    let (s, l) = (unit(1), unit(2));
+    let a = parse_alpha(tokens.get(3), false, warnings);
+
+    // CSS HSL is defined over sRGB-encoded values, so the raw result is the encoding
+    let rgb = Hsl { h, s, l, a }.to_color();
+    Srgb {
+        r: rgb.r,
+        g: rgb.g,
+        b: rgb.b,
+        a: rgb.a,
+    }
+}
+
+fn is_hex(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Parse a color string, accepting common variations and mistakes.
+///
+/// Returns an error only when no reasonable interpretation exists; otherwise the
+/// `warnings` explain every guess that was made.
+pub fn parse_lenient(input: &str) -> Result<LenientParse, ParseError> {
+    let text = input.trim().to_ascii_lowercase();
+    if text.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut warnings = Vec::new();
+
+    // Hex notations
+    let hex = if let Some(digits) = text.strip_prefix('#') {
+        Some(digits.trim())
+    } else if let Some(digits) = text.strip_prefix("0x") {
+        warnings.push(ParseWarning::NonCssHexPrefix);
+        Some(digits)
+    } else if is_hex(&text) && matches!(text.len(), 3 | 4 | 6 | 8) {
+        warnings.push(ParseWarning::MissingHash);
+        Some(text.as_str())
+    } else {
+        None
+    };
+    if let Some(digits) = hex {
+        if !is_hex(digits) {
+            return Err(ParseError::UnrecognizedFormat);
+        }
+        return Ok(finish(parse_hex(digits)?, Interpretation::Hex, warnings));
+    }
+
+    // Functional and bare component lists
+    let name_len = text
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(text.len());
+    let (name, rest) = text.split_at(name_len);
+    let hsl = match name {
+        "rgb" | "rgba" => false,
+        "hsl" | "hsla" => true,
+        "" => {
+            warnings.push(ParseWarning::MissingFunctionName);
+            false
+        }
+        _ => return Err(ParseError::UnrecognizedFormat),
+    };
+
+    let rest = rest.trim().trim_start_matches(':').trim();
+    let args = match (rest.strip_prefix('('), rest.strip_suffix(')')) {
+        (Some(_), Some(_)) => &rest[1..rest.len() - 1],
+        (None, None) => {
+            if !name.is_empty() {
+                warnings.push(ParseWarning::MissingParentheses);
+            }
+            rest
+        }
+        _ => {
+            warnings.push(ParseWarning::MissingParentheses);
+            rest.trim_start_matches('(').trim_end_matches(')')
+        }
+    };
+
+    let raw: Vec<&str> = args
+        .split(|c: char| c == ',' || c == '/' || c == ';' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if raw.len() != 3 && raw.len() != 4 {
+        return Err(ParseError::WrongComponentCount(raw.len()));
+    }
+    let semicolons = args.matches(';').count();
+    if semicolons > 0 {
+        warnings.push(ParseWarning::NonCssSeparator);
+    }
+    // Semicolons stand in for commas
+    let commas = args.matches(',').count() + semicolons;
+    if commas > 0 && commas + args.matches('/').count() != raw.len() - 1 {
+        warnings.push(ParseWarning::MixedSeparators);
+    }
+
+    let tokens = raw
+        .iter()
+        .map(|s| parse_token(s))
+        .collect::<Result<Vec<_>, _>>()?;
+    if hsl {
+        let color = parse_hsl(&tokens, &mut warnings);
+        Ok(finish(color, Interpretation::Hsl, warnings))
+    } else {
+        let (color, interpretation) = parse_rgb(&tokens, &mut warnings);
+        Ok(finish(color, interpretation, warnings))
+    }
+}
+
+fn finish(
+    color: Srgb,
+    interpretation: Interpretation,
+    warnings: Vec<ParseWarning>,
+) -> LenientParse {
+    let confidence = warnings.iter().map(ParseWarning::penalty).product();
+    LenientParse {
+        color,
+        interpretation,
+        confidence,
+        warnings,
+    }
+}
@@ -0,0 +1,93 @@
+//! Ordered chains of per-pixel transforms.
+//!
+//! A `Pipeline` applies its steps to the RGB components of each pixel in order and
+//! never touches alpha. Steps operate on whatever encoding the previous step produced;
+//! the pipeline itself makes no assumption about linear vs. encoded values.
+
+use crate::colorspaces::color::Color;
+use crate::stream::Chunked;
+
+/// An operation on RGB triples.
+pub trait Transform {
+    /// Apply the transform to one RGB triple.
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3];
+
+    /// Apply the transform to one color, keeping its alpha.
+    fn apply_color(&self, color: &Color) -> Color {
+        let [r, g, b] = self.apply([color.r, color.g, color.b]);
+        Color::new(r, g, b, color.a)
+    }
+
+    /// Apply the transform to every pixel of a buffer in place.
+    fn apply_slice(&self, pixels: &mut [Color]) {
+        for pixel in pixels.iter_mut() {
+            *pixel = self.apply_color(pixel);
+        }
+    }
+
+    /// Lazily transform a stream of colors, `chunk_size` pixels at a time.
+    fn stream<I: IntoIterator<Item = Color>>(
+        &self,
+        input: I,
+        chunk_size: usize,
+    ) -> Chunked<'_, I::IntoIter, Self>
+    where
+        Self: Sized,
+    {
+        Chunked::new(input, self, chunk_size)
+    }
+}
+
+impl<F: Fn([f64; 3]) -> [f64; 3]> Transform for F {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        self(rgb)
+    }
+}
+
+/// A builder-style sequence of transforms.
+#[derive(Default)]
+pub struct Pipeline {
+    steps: Vec<Box<dyn Transform + Send + Sync>>,
+}
+
+impl Pipeline {
+    /// Create an empty pipeline, which leaves pixels unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a step, builder style.
+    pub fn then<T: Transform + Send + Sync + 'static>(mut self, step: T) -> Self {
+        self.push(step);
+        self
+    }
+
+    /// Append a step in place.
+    pub fn push<T: Transform + Send + Sync + 'static>(&mut self, step: T) {
+        self.steps.push(Box::new(step));
+    }
+
+    /// Number of steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the pipeline has no steps.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl Transform for Pipeline {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        self.steps.iter().fold(rgb, |rgb, step| step.apply(rgb))
+    }
+
+    /// Runs each step over the whole buffer in turn, so steps with their own
+    /// `apply_slice` keep its per-buffer setup.
+    fn apply_slice(&self, pixels: &mut [Color]) {
+        for step in &self.steps {
+            step.apply_slice(pixels);
+        }
+    }
+}
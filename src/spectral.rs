@@ -0,0 +1,507 @@
+//! Spectral colorimetry.
+//!
+//! Spectra are sampled every 10 nm from 380 nm to 780 nm, the grid of the CIE 1931 2°
+//! color matching functions below. Reflectances are integrated against an illuminant and
+//! normalized so the perfect reflecting diffuser has Y = 1.
+//!
+//! Metamers are built by adding a metameric black (a spectrum that integrates to zero
+//! XYZ under the chosen illuminant) to a reference reflectance.
+//!
+//! Colors without a measured reflectance can be upsampled to the smoothest reflectance
+//! that reproduces them, then previewed under another light with `appearance_under`.
+
+use crate::chromatic_adaptation::{M_BRADFORD, M_BRADFORD_INV};
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::lab::xyz_to_lab;
+use crate::colorspaces::xyz::{XYZ_TO_SRGB, Xyz};
+use crate::math::{Mat3, dot};
+use serde::{Deserialize, Serialize};
+
+// NOTE: Plain 10 nm summation is used rather than ASTM E308 weighting tables, so the
+// D65 white integrates to (0.9502, 1, 1.0881) instead of (0.9505, 1, 1.0888). The
+// difference is well below visual thresholds.
+
+// NOTE: Upsampling (Burns' LHTSS) keeps reflectances strictly inside (0, 1) through a
+// tanh parameterization, so pure black and white are reached only approximately;
+// inputs are pulled in by `UPSAMPLE_MARGIN` to keep the Newton iteration finite.
+
+const EPSILON: f64 = 1e-10;
+
+/// First sampled wavelength in nm
+pub const WAVELENGTH_START: f64 = 380.0;
+/// Sampling interval in nm
+pub const WAVELENGTH_STEP: f64 = 10.0;
+/// Number of samples (380-780 nm inclusive)
+pub const SAMPLES: usize = 41;
+
+/// CIE 1931 2° standard observer (x̄, ȳ, z̄), 380-780 nm at 10 nm
+const CMF: [[f64; 3]; SAMPLES] = [
+    [0.001368, 0.000039, 0.006450],
+    [0.004243, 0.000120, 0.020050],
+    [0.014310, 0.000396, 0.067850],
+    [0.043510, 0.001210, 0.207400],
+    [0.134380, 0.004000, 0.645600],
+    [0.283900, 0.011600, 1.385600],
+    [0.348280, 0.023000, 1.747060],
+    [0.336200, 0.038000, 1.772110],
+    [0.290800, 0.060000, 1.669200],
+    [0.195360, 0.090980, 1.287640],
+    [0.095640, 0.139020, 0.812950],
+    [0.032010, 0.208020, 0.465180],
+    [0.004900, 0.323000, 0.272000],
+    [0.009300, 0.503000, 0.158200],
+    [0.063270, 0.710000, 0.078250],
+    [0.165500, 0.862000, 0.042160],
+    [0.290400, 0.954000, 0.020300],
+    [0.433450, 0.994950, 0.008750],
+    [0.594500, 0.995000, 0.003900],
+    [0.762100, 0.952000, 0.002100],
+    [0.916300, 0.870000, 0.001650],
+    [1.026300, 0.757000, 0.001100],
+    [1.062200, 0.631000, 0.000800],
+    [1.002600, 0.503000, 0.000340],
+    [0.854450, 0.381000, 0.000190],
+    [0.642400, 0.265000, 0.000050],
+    [0.447900, 0.175000, 0.000020],
+    [0.283500, 0.107000, 0.000000],
+    [0.164900, 0.061000, 0.000000],
+    [0.087400, 0.032000, 0.000000],
+    [0.046770, 0.017000, 0.000000],
+    [0.022700, 0.008210, 0.000000],
+    [0.011359, 0.004102, 0.000000],
+    [0.005790, 0.002091, 0.000000],
+    [0.002899, 0.001047, 0.000000],
+    [0.001440, 0.000520, 0.000000],
+    [0.000690, 0.000249, 0.000000],
+    [0.000332, 0.000120, 0.000000],
+    [0.000166, 0.000060, 0.000000],
+    [0.000083, 0.000030, 0.000000],
+    [0.000042, 0.000015, 0.000000],
+];
+
+/// CIE standard illuminant D65 relative spectral power, 380-780 nm at 10 nm
+const D65: [f64; SAMPLES] = [
+    49.9755, 54.6482, 82.7549, 91.4860, 93.4318, 86.6823, 104.8650, 117.0080, 117.8120, 114.8610,
+    115.9230, 108.8110, 109.3540, 107.8020, 104.7900, 107.6890, 104.4050, 104.0460, 100.0000,
+    96.3342, 95.7880, 88.6856, 90.0062, 89.5991, 87.6987, 83.2886, 83.6992, 80.0268, 80.2146,
+    82.2778, 78.2842, 69.7213, 71.6091, 74.3490, 61.6040, 69.8856, 75.0870, 63.5927, 46.4182,
+    66.8054, 63.3828,
+];
+
+/// CIE daylight basis functions S0, S1, S2, 380-780 nm at 10 nm
+const DAYLIGHT: [[f64; SAMPLES]; 3] = [
+    [
+        63.4, 65.8, 94.8, 104.8, 105.9, 96.8, 113.9, 125.6, 125.5, 121.3, 121.3, 113.5, 113.1,
+        110.8, 106.5, 108.8, 105.3, 104.4, 100.0, 96.0, 95.1, 89.1, 90.5, 90.3, 88.4, 84.0, 85.1,
+        81.9, 82.6, 84.9, 81.3, 71.9, 74.3, 76.4, 63.3, 71.7, 77.0, 65.2, 47.7, 68.6, 65.0,
+    ],
+    [
+        38.5, 35.0, 43.4, 46.3, 43.9, 37.1, 36.7, 35.9, 32.6, 27.9, 24.3, 20.1, 16.2, 13.2, 8.6,
+        6.1, 4.2, 1.9, 0.0, -1.6, -3.5, -3.5, -5.8, -7.2, -8.6, -9.5, -10.9, -10.7, -12.0, -14.0,
+        -13.6, -12.0, -13.3, -12.9, -10.6, -11.6, -12.2, -10.2, -7.8, -11.2, -10.4,
+    ],
+    [
+        3.0, 1.2, -1.1, -0.5, -0.7, -1.2, -2.6, -2.9, -2.8, -2.6, -2.6, -1.8, -1.5, -1.3, -1.2,
+        -1.0, -0.5, -0.3, 0.0, 0.2, 0.5, 2.1, 3.2, 4.1, 4.7, 5.1, 6.7, 7.3, 8.6, 9.8, 10.2, 8.3,
+        9.6, 8.5, 7.0, 7.6, 8.0, 6.7, 5.2, 7.4, 6.8,
+    ],
+];
+
+/// CIE F2 (cool white fluorescent), resampled from the 5 nm table with a triangular
+/// filter so the mercury lines between grid points keep their energy
+const F2: [f64; SAMPLES] = [
+    1.25, 1.83, 6.18, 6.78, 4.19, 12.43, 16.22, 6.62, 7.18, 7.53, 7.63, 7.58, 7.29, 7.07, 7.21,
+    8.11, 13.45, 18.19, 16.12, 19.07, 21.59, 18.59, 16.5, 13.79, 10.98, 8.44, 6.34, 4.7, 3.47,
+    2.56, 1.9, 1.49, 1.11, 0.88, 0.68, 0.57, 0.51, 0.46, 0.45, 0.4, 0.29,
+];
+
+/// How far upsampling pulls colors in from the edges of the [0, 1] cube
+const UPSAMPLE_MARGIN: f64 = 1e-4;
+
+/// A spectrum sampled on the standard 380-780 nm, 10 nm grid.
+///
+/// Serializes as a plain list of samples.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(into = "Vec<f64>", try_from = "Vec<f64>")]
+pub struct Spectrum(pub [f64; SAMPLES]);
+
+impl From<Spectrum> for Vec<f64> {
+    fn from(spectrum: Spectrum) -> Self {
+        spectrum.0.to_vec()
+    }
+}
+
+impl TryFrom<Vec<f64>> for Spectrum {
+    type Error = String;
+
+    fn try_from(values: Vec<f64>) -> Result<Self, Self::Error> {
+        let len = values.len();
+        values
+            .try_into()
+            .map(Spectrum)
+            .map_err(|_| format!("expected {SAMPLES} spectral samples, got {len}"))
+    }
+}
+
+impl Spectrum {
+    /// Spectrum with the same value at every wavelength.
+    pub fn constant(value: f64) -> Self {
+        Spectrum([value; SAMPLES])
+    }
+
+    /// Spectrum sampled from a function of wavelength in nm.
+    pub fn from_fn<F: Fn(f64) -> f64>(f: F) -> Self {
+        let mut values = [0.0; SAMPLES];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = f(wavelength(i));
+        }
+        Spectrum(values)
+    }
+
+    /// CIE XYZ of this reflectance under `illuminant`, with the illuminant's white at Y = 1.
+    pub fn reflectance_xyz(&self, illuminant: &Spectrum) -> [f64; 3] {
+        let weights = weighted_cmf(illuminant);
+        let mut xyz = [0.0; 3];
+        for (w, r) in weights.iter().zip(self.0.iter()) {
+            for k in 0..3 {
+                xyz[k] += w[k] * r;
+            }
+        }
+        xyz
+    }
+
+    /// Color of this reflectance under D65.
+    pub fn to_color(&self) -> Color {
+        let [x, y, z] = self.reflectance_xyz(&Illuminant::D65.spd());
+        Xyz::new(x, y, z).to_color()
+    }
+
+    /// Smoothest reflectance in (0, 1) whose color under D65 is `color`.
+    ///
+    /// This is Burns' LHTSS method: the reflectance minimizing the squared slope between
+    /// neighboring samples, found by Newton iteration. Components are clamped to [0, 1]
+    /// first, as a reflectance cannot reproduce colors outside sRGB's unit cube reliably.
+    /// `to_color` of the result matches `color` to about 1e-3 (see the NOTE on D65).
+    pub fn from_color(color: &Color) -> Self {
+        let squeeze = |c: f64| UPSAMPLE_MARGIN + (1.0 - 2.0 * UPSAMPLE_MARGIN) * c.clamp(0.0, 1.0);
+        let target = [squeeze(color.r), squeeze(color.g), squeeze(color.b)];
+
+        // Reflectance → linear sRGB, with rows scaled so the perfect reflector maps to
+        // exactly (1, 1, 1)
+        let w = weighted_cmf(&Illuminant::D65.spd());
+        let mut t = [[0.0; SAMPLES]; 3];
+        for (i, row) in w.iter().enumerate() {
+            let rgb = XYZ_TO_SRGB.apply(*row);
+            for k in 0..3 {
+                t[k][i] = rgb[k];
+            }
+        }
+        for row in t.iter_mut() {
+            let sum: f64 = row.iter().sum();
+            row.iter_mut().for_each(|v| *v /= sum);
+        }
+
+        const N: usize = SAMPLES + 3;
+        let mut z = [0.0f64; SAMPLES];
+        let mut lambda = [0.0; 3];
+        let mut rho = [0.5; SAMPLES];
+        for _ in 0..50 {
+            let mut d1 = [0.0; SAMPLES];
+            let mut d2 = [0.0; SAMPLES];
+            for i in 0..SAMPLES {
+                let th = z[i].tanh();
+                rho[i] = 0.5 * th + 0.5;
+                d1[i] = 0.5 * (1.0 - th * th);
+                d2[i] = -2.0 * th * d1[i];
+            }
+            let t_lambda: Vec<f64> = (0..SAMPLES)
+                .map(|i| (0..3).map(|k| t[k][i] * lambda[k]).sum())
+                .collect();
+
+            // Residual of the Lagrangian's gradient and of the color constraint
+            let mut f = vec![0.0; N];
+            for i in 0..SAMPLES {
+                let left = if i > 0 { z[i] - z[i - 1] } else { 0.0 };
+                let right = if i + 1 < SAMPLES {
+                    z[i] - z[i + 1]
+                } else {
+                    0.0
+                };
+                f[i] = left + right + d1[i] * t_lambda[i];
+            }
+            for k in 0..3 {
+                f[SAMPLES + k] = dot_samples(&t[k], &rho) - target[k];
+            }
+            if f.iter().all(|v| v.abs() < EPSILON) {
+                break;
+            }
+
+            let mut j = vec![vec![0.0; N]; N];
+            for i in 0..SAMPLES {
+                let neighbors = usize::from(i > 0) + usize::from(i + 1 < SAMPLES);
+                j[i][i] = neighbors as f64 + d2[i] * t_lambda[i];
+                if i > 0 {
+                    j[i][i - 1] = -1.0;
+                }
+                if i + 1 < SAMPLES {
+                    j[i][i + 1] = -1.0;
+                }
+                for k in 0..3 {
+                    j[i][SAMPLES + k] = d1[i] * t[k][i];
+                    j[SAMPLES + k][i] = d1[i] * t[k][i];
+                }
+            }
+            let step = solve(j, f.iter().map(|v| -v).collect());
+            for i in 0..SAMPLES {
+                z[i] += step[i];
+            }
+            for k in 0..3 {
+                lambda[k] += step[SAMPLES + k];
+            }
+        }
+        Spectrum(rho)
+    }
+
+    /// Color of this reflectance lit by `illuminant`, seen by an observer adapted to D65.
+    ///
+    /// The illuminant's own tint is kept, as in a photograph white-balanced for daylight.
+    pub fn color_under(&self, illuminant: &Illuminant) -> Color {
+        let [x, y, z] = self.reflectance_xyz(&illuminant.spd());
+        Xyz::new(x, y, z).to_color()
+    }
+
+    /// Color of this reflectance lit by `illuminant`, seen by an observer adapted to it.
+    ///
+    /// Bradford adaptation maps the illuminant's white to D65 white, so only the shifts a
+    /// viewer would notice after adapting remain (the sample's illuminant sensitivity).
+    pub fn adapted_color_under(&self, illuminant: &Illuminant) -> Color {
+        let spd = illuminant.spd();
+        let from = M_BRADFORD.apply(Spectrum::constant(1.0).reflectance_xyz(&spd));
+        let to = M_BRADFORD.apply(Spectrum::constant(1.0).reflectance_xyz(&Illuminant::D65.spd()));
+        let lms = M_BRADFORD.apply(self.reflectance_xyz(&spd));
+        let [x, y, z] = M_BRADFORD_INV.apply([0, 1, 2].map(|k| lms[k] * to[k] / from[k]));
+        Xyz::new(x, y, z).to_color()
+    }
+}
+
+fn dot_samples(a: &[f64; SAMPLES], b: &[f64; SAMPLES]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Solve `a · x = b` by Gaussian elimination with partial pivoting.
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Vec<f64> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&x, &y| a[x][col].abs().total_cmp(&a[y][col].abs()))
+            .unwrap_or(col);
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        for row in col + 1..n {
+            let factor = a[row][col] / pivot_row[col];
+            if factor != 0.0 {
+                for (v, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                    *v -= factor * p;
+                }
+                b[row] -= factor * b[col];
+            }
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    x
+}
+
+/// How `color` would look under `illuminant` to a viewer adapted to it.
+///
+/// The color is upsampled to its smoothest reflectance (see `Spectrum::from_color`);
+/// pass a measured `Spectrum` to `Spectrum::adapted_color_under` when one is available.
+pub fn appearance_under(color: &Color, illuminant: &Illuminant) -> Color {
+    let mut out = Spectrum::from_color(color).adapted_color_under(illuminant);
+    out.a = color.a;
+    out
+}
+
+/// Wavelength in nm of sample `i`.
+pub fn wavelength(i: usize) -> f64 {
+    WAVELENGTH_START + WAVELENGTH_STEP * i as f64
+}
+
+/// Standard and parametric light sources.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum Illuminant {
+    /// CIE D65 average daylight
+    #[default]
+    D65,
+    /// CIE D50 horizon daylight, the print viewing standard
+    D50,
+    /// CIE daylight at the given correlated color temperature in kelvin (4000-25000 K)
+    Daylight(f64),
+    /// CIE illuminant A (incandescent, 2856 K)
+    A,
+    /// CIE F2 cool white fluorescent
+    F2,
+    /// Equal-energy illuminant E
+    E,
+    /// Planckian radiator at the given temperature in kelvin
+    Blackbody(f64),
+    /// A measured distribution, e.g. from an LED datasheet or spectrometer
+    Custom(Box<Spectrum>),
+}
+
+impl Illuminant {
+    /// Relative spectral power distribution.
+    ///
+    /// Daylight, A and blackbody distributions are 100 at 560 nm; F2 and custom ones keep
+    /// their own scale, which does not affect any result.
+    pub fn spd(&self) -> Spectrum {
+        match self {
+            Illuminant::D65 => Spectrum(D65),
+            Illuminant::D50 => daylight(5000.0),
+            Illuminant::Daylight(t) => daylight(*t),
+            Illuminant::F2 => Spectrum(F2),
+            Illuminant::Custom(spd) => **spd,
+            Illuminant::E => Spectrum::constant(100.0),
+            // Illuminant A is defined by this formula with c2 = 1.435e7 nm·K
+            Illuminant::A => Spectrum::from_fn(|l| {
+                let c: f64 = 1.435e7 / 2848.0;
+                100.0 * (560.0 / l).powi(5) * ((c / 560.0).exp() - 1.0) / ((c / l).exp() - 1.0)
+            }),
+            Illuminant::Blackbody(t) => {
+                let planck = |l: f64| {
+                    let m = l * 1e-9;
+                    1.0 / (m.powi(5) * ((1.438_776_877e-2 / (m * t)).exp() - 1.0))
+                };
+                let scale = 100.0 / planck(560.0);
+                Spectrum::from_fn(|l| planck(l) * scale)
+            }
+        }
+    }
+
+    /// XYZ of the illuminant's white, with Y = 1.
+    pub fn white(&self) -> [f64; 3] {
+        Spectrum::constant(1.0).reflectance_xyz(&self.spd())
+    }
+}
+
+/// xy chromaticity of the CIE daylight locus at correlated color temperature `t`.
+pub(crate) fn daylight_chromaticity(t: f64) -> [f64; 2] {
+    // The daylight locus is defined on the pre-1968 temperature scale (c2 = 1.4380e-2)
+    let t = (t * 1.4388 / 1.4380).clamp(4000.0, 25000.0);
+    let (t2, t3) = (t * t, t * t * t);
+    let x = if t <= 7000.0 {
+        -4.6070e9 / t3 + 2.9678e6 / t2 + 0.09911e3 / t + 0.244063
+    } else {
+        -2.0064e9 / t3 + 1.9018e6 / t2 + 0.24748e3 / t + 0.237040
+    };
+    [x, -3.0 * x * x + 2.870 * x - 0.275]
+}
+
+/// CIE daylight distribution at correlated color temperature `t`.
+fn daylight(t: f64) -> Spectrum {
+    let [x, y] = daylight_chromaticity(t);
+    let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+    let m1 = (-1.3515 - 1.7703 * x + 5.9114 * y) / m;
+    let m2 = (0.0300 - 31.4424 * x + 30.0717 * y) / m;
+    let mut values = [0.0; SAMPLES];
+    for (i, v) in values.iter_mut().enumerate() {
+        *v = DAYLIGHT[0][i] + m1 * DAYLIGHT[1][i] + m2 * DAYLIGHT[2][i];
+    }
+    Spectrum(values)
+}
+
+/// Color matching functions weighted by an illuminant and normalized to white Y = 1.
+fn weighted_cmf(illuminant: &Spectrum) -> [[f64; 3]; SAMPLES] {
+    let norm: f64 = CMF
+        .iter()
+        .zip(illuminant.0.iter())
+        .map(|(c, s)| c[1] * s)
+        .sum();
+    let mut weights = [[0.0; 3]; SAMPLES];
+    for (i, w) in weights.iter_mut().enumerate() {
+        for k in 0..3 {
+            w[k] = CMF[i][k] * illuminant.0[i] / norm;
+        }
+    }
+    weights
+}
+
+/// Project a spectrum onto the metameric blacks of `illuminant`: the part of it that
+/// contributes nothing to XYZ.
+pub fn metameric_black(spectrum: &Spectrum, illuminant: &Spectrum) -> Spectrum {
+    let w = weighted_cmf(illuminant);
+    // A·Aᵀ for the 3×N matrix A whose rows are the weighted color matching functions
+    let mut gram = [[0.0; 3]; 3];
+    for row in w.iter() {
+        for j in 0..3 {
+            for k in 0..3 {
+                gram[j][k] += row[j] * row[k];
+            }
+        }
+    }
+    let coeffs = Mat3(gram)
+        .inverse()
+        .apply(spectrum.reflectance_xyz(illuminant));
+    let mut black = spectrum.0;
+    for (v, row) in black.iter_mut().zip(w.iter()) {
+        *v -= dot(*row, coeffs);
+    }
+    Spectrum(black)
+}
+
+/// A metamer of `reference` under `illuminant`, shaped by `perturbation`.
+///
+/// The metameric-black part of `perturbation` is added to `reference`, scaled down if
+/// needed so every sample stays a valid reflectance in [0, 1]. The result has the same
+/// XYZ as `reference` under `illuminant` but generally differs under other light.
+pub fn metamer(reference: &Spectrum, illuminant: &Spectrum, perturbation: &Spectrum) -> Spectrum {
+    let black = metameric_black(perturbation, illuminant);
+    let mut scale: f64 = 1.0;
+    for (r, b) in reference.0.iter().zip(black.0.iter()) {
+        if *b > EPSILON {
+            scale = scale.min(((1.0 - r) / b).max(0.0));
+        } else if *b < -EPSILON {
+            scale = scale.min((r / -b).max(0.0));
+        }
+    }
+    let mut values = reference.0;
+    for (v, b) in values.iter_mut().zip(black.0.iter()) {
+        *v += scale * b;
+    }
+    Spectrum(values)
+}
+
+/// `count` distinct metamers of `reference` under `illuminant`, perturbed by sinusoids
+/// of increasing frequency across the visible range.
+pub fn metamers(reference: &Spectrum, illuminant: &Spectrum, count: usize) -> Vec<Spectrum> {
+    (1..=count)
+        .map(|n| {
+            let freq = n as f64 * std::f64::consts::PI / (WAVELENGTH_STEP * (SAMPLES - 1) as f64);
+            let perturbation = Spectrum::from_fn(|l| (freq * (l - WAVELENGTH_START)).sin());
+            metamer(reference, illuminant, &perturbation)
+        })
+        .collect()
+}
+
+/// CIE metamerism index: ΔE*ab between two reflectances under `test`, after an additive
+/// correction for any mismatch they already have under `reference`.
+///
+/// Lab is computed relative to each illuminant's own white. For an exact metameric pair
+/// the correction is zero and this is the plain color difference under `test`.
+pub fn metamerism_index(a: &Spectrum, b: &Spectrum, reference: &Spectrum, test: &Spectrum) -> f64 {
+    let white_ref = Spectrum::constant(1.0).reflectance_xyz(reference);
+    let white_test = Spectrum::constant(1.0).reflectance_xyz(test);
+    let a_ref = xyz_to_lab(a.reflectance_xyz(reference), white_ref);
+    let b_ref = xyz_to_lab(b.reflectance_xyz(reference), white_ref);
+    let a_test = xyz_to_lab(a.reflectance_xyz(test), white_test);
+    let b_test = xyz_to_lab(b.reflectance_xyz(test), white_test);
+    (0..3)
+        .map(|k| ((b_test[k] - (b_ref[k] - a_ref[k])) - a_test[k]).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
@@ -0,0 +1,184 @@
+//! Streaming conversion in fixed-size chunks.
+//!
+//! `Chunked` adapts any iterator of colors, and `process_stream` any byte reader, so
+//! that a `Transform` (typically a `Pipeline`) runs over arbitrarily long input while
+//! holding only one chunk in memory.
+
+use crate::colorspaces::color::Color;
+use crate::pipeline::Transform;
+use std::io::{self, Read, Write};
+
+/// Iterator adapter that applies a transform to its input one chunk at a time.
+pub struct Chunked<'a, I, T: ?Sized> {
+    input: I,
+    transform: &'a T,
+    chunk: Vec<Color>,
+    pos: usize,
+    chunk_size: usize,
+}
+
+impl<'a, I: Iterator<Item = Color>, T: Transform + ?Sized> Chunked<'a, I, T> {
+    /// Wrap `input` so it is transformed `chunk_size` pixels at a time.
+    pub fn new<J: IntoIterator<IntoIter = I>>(
+        input: J,
+        transform: &'a T,
+        chunk_size: usize,
+    ) -> Self {
+        let chunk_size = chunk_size.max(1);
+        Self {
+            input: input.into_iter(),
+            transform,
+            chunk: Vec::with_capacity(chunk_size),
+            pos: 0,
+            chunk_size,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Color>, T: Transform + ?Sized> Iterator for Chunked<'_, I, T> {
+    type Item = Color;
+
+    fn next(&mut self) -> Option<Color> {
+        if self.pos == self.chunk.len() {
+            self.chunk.clear();
+            self.chunk.extend(self.input.by_ref().take(self.chunk_size));
+            self.transform.apply_slice(&mut self.chunk);
+            self.pos = 0;
+        }
+        let color = self.chunk.get(self.pos).copied();
+        self.pos += 1;
+        color
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.chunk.len().saturating_sub(self.pos);
+        let (lo, hi) = self.input.size_hint();
+        (lo + buffered, hi.map(|hi| hi + buffered))
+    }
+}
+
+/// Interleaved pixel layouts for byte streams.
+///
+/// Components are passed to the transform as stored (8-bit values divided by 255), with
+/// no transfer function applied. 8-bit output is clamped and rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// 3 bytes per pixel
+    Rgb8,
+    /// 4 bytes per pixel, alpha last
+    Rgba8,
+    /// 16 bytes per pixel, little-endian f32 components, alpha last
+    RgbaF32,
+}
+
+impl PixelFormat {
+    /// Bytes per pixel.
+    pub fn pixel_size(&self) -> usize {
+        match self {
+            PixelFormat::Rgb8 => 3,
+            PixelFormat::Rgba8 => 4,
+            PixelFormat::RgbaF32 => 16,
+        }
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Color {
+        let unit = |b: u8| b as f64 / 255.0;
+        match self {
+            PixelFormat::Rgb8 => Color::opaque(unit(bytes[0]), unit(bytes[1]), unit(bytes[2])),
+            PixelFormat::Rgba8 => Color::new(
+                unit(bytes[0]),
+                unit(bytes[1]),
+                unit(bytes[2]),
+                unit(bytes[3]),
+            ),
+            PixelFormat::RgbaF32 => {
+                let f = |i: usize| {
+                    f32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]) as f64
+                };
+                Color::new(f(0), f(4), f(8), f(12))
+            }
+        }
+    }
+
+    fn encode(&self, color: &Color, out: &mut Vec<u8>) {
+        let byte = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        match self {
+            PixelFormat::Rgb8 => out.extend([byte(color.r), byte(color.g), byte(color.b)]),
+            PixelFormat::Rgba8 => {
+                out.extend([byte(color.r), byte(color.g), byte(color.b), byte(color.a)])
+            }
+            PixelFormat::RgbaF32 => {
+                for v in [color.r, color.g, color.b, color.a] {
+                    out.extend((v as f32).to_le_bytes());
+                }
+            }
+        }
+    }
+}
+
+/// Fill `buf` from `reader`, stopping early only at end of input. Returns bytes read.
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// Read pixels from `reader`, transform them and write them to `writer`, `chunk_pixels`
+/// pixels at a time. Returns the number of pixels processed.
+///
+/// Input that ends partway through a pixel fails with `UnexpectedEof` after every
+/// complete pixel has been written.
+pub fn process_stream<T, R, W>(
+    transform: &T,
+    format: PixelFormat,
+    chunk_pixels: usize,
+    mut reader: R,
+    mut writer: W,
+) -> io::Result<u64>
+where
+    T: Transform + ?Sized,
+    R: Read,
+    W: Write,
+{
+    let size = format.pixel_size();
+    let chunk_pixels = chunk_pixels.max(1);
+    let mut input = vec![0u8; size * chunk_pixels];
+    let mut output = Vec::with_capacity(size * chunk_pixels);
+    let mut pixels = Vec::with_capacity(chunk_pixels);
+    let mut total = 0u64;
+    loop {
+        let read = read_full(&mut reader, &mut input)?;
+        pixels.clear();
+        pixels.extend(
+            input[..read - read % size]
+                .chunks_exact(size)
+                .map(|p| format.decode(p)),
+        );
+        transform.apply_slice(&mut pixels);
+        output.clear();
+        for pixel in &pixels {
+            format.encode(pixel, &mut output);
+        }
+        writer.write_all(&output)?;
+        total += pixels.len() as u64;
+        if read % size != 0 {
+            writer.flush()?;
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "stream ended partway through a pixel",
+            ));
+        }
+        if read < input.len() {
+            break;
+        }
+    }
+    writer.flush()?;
+    Ok(total)
+}
@@ -0,0 +1,257 @@
+//! Theme extraction from images.
+//!
+//! `extract_theme` reduces an image to a handful of swatches, picks a background from
+//! the dominant one and primary, secondary and accent colors from the vivid ones, then
+//! adjusts lightness until each role contrasts with the background. It is meant for
+//! album-art and wallpaper theming, where a plausible theme matters more than fidelity.
+//!
+//! `interactive_states` derives hover, active, focus, disabled and visited variants of
+//! a control color from fixed Oklch deltas, so every control in a design system gets
+//! the same state ramp.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::ColorSpace;
+use crate::colorspaces::oklch::Oklch;
+use crate::contrast::{ContrastMetric, ensure_contrast};
+use crate::palette::{in_gamut_oklch, quantize};
+use serde::{Deserialize, Serialize};
+
+/// Colors picked from an image for each role of a theme.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Theme {
+    pub primary: Color,
+    pub secondary: Color,
+    pub accent: Color,
+    pub background: Color,
+}
+
+/// Tuning for `extract_theme_with`. Lightness and chroma are in Oklch units.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ThemeOptions {
+    /// Number of swatches the image is quantized to
+    pub colors: usize,
+    /// Swatches less chromatic than this are not used for the colored roles
+    pub min_chroma: f64,
+    /// Lightness band (min, max) a swatch must fall in to be used for a colored role
+    pub lightness: (f64, f64),
+    /// Minimum WCAG contrast ratio of each colored role against the background
+    pub min_contrast: f64,
+}
+
+impl Default for ThemeOptions {
+    fn default() -> Self {
+        Self {
+            colors: 12,
+            min_chroma: 0.03,
+            lightness: (0.3, 0.85),
+            min_contrast: 3.0,
+        }
+    }
+}
+
+/// Hue separation below which two colors count as the same hue family, in degrees
+const MIN_HUE_SEPARATION: f64 = 30.0;
+
+fn hue_distance(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
+}
+
+/// `color` in sRGB with its alpha, its lightness changed as little as needed to reach
+/// `min_contrast` against the background. If no lightness does, the result is as far
+/// from a `dark` (or light) background as the hue allows.
+fn with_contrast(color: &Oklch, background: &Color, dark: bool, min_contrast: f64) -> Color {
+    let at = |l: f64| Color {
+        a: color.alpha,
+        ..in_gamut_oklch(l, color.c, color.h)
+    };
+    ensure_contrast(
+        &at(color.l.clamp(0.0, 1.0)),
+        background,
+        min_contrast,
+        ContrastMetric::Wcag,
+    )
+    .unwrap_or_else(|| at(if dark { 1.0 } else { 0.0 }))
+}
+
+/// Extract a theme from an image with the default options.
+///
+/// Returns `None` when the image has no opaque pixels.
+pub fn extract_theme(pixels: &[Color]) -> Option<Theme> {
+    extract_theme_with(pixels, &ThemeOptions::default())
+}
+
+/// Extract a theme from an image.
+///
+/// The background is the dominant swatch, desaturated and pushed dark or light
+/// depending on which side of mid-gray it falls. Vivid swatches are ranked by chroma
+/// weighted by the square root of the population of their hue family; the best becomes
+/// primary, the best of a different hue secondary, and the most chromatic remaining one
+/// of a third hue accent. Missing roles are derived from the primary by hue rotation.
+pub fn extract_theme_with(pixels: &[Color], options: &ThemeOptions) -> Option<Theme> {
+    let swatches = quantize(pixels, options.colors.max(1));
+    let dominant = Oklch::from_color(&swatches.first()?.color);
+    let dark = dominant.l < 0.5;
+    let background = in_gamut_oklch(
+        if dark {
+            dominant.l.min(0.2)
+        } else {
+            dominant.l.max(0.94)
+        },
+        dominant.c.min(0.04),
+        dominant.h,
+    );
+
+    let (l0, l1) = options.lightness;
+    let mut candidates: Vec<(Oklch, f64)> = swatches
+        .iter()
+        .map(|s| (Oklch::from_color(&s.color), s.population))
+        .filter(|(c, _)| c.c >= options.min_chroma && (l0..=l1).contains(&c.l))
+        .collect();
+    if candidates.is_empty() {
+        // Muted image: rank every swatch instead
+        candidates = swatches
+            .iter()
+            .map(|s| (Oklch::from_color(&s.color), s.population))
+            .collect();
+    }
+    // Quantization splits large areas into several similar swatches, so weight each
+    // candidate by the population of its whole hue family
+    let scores: Vec<f64> = candidates
+        .iter()
+        .map(|(c, _)| {
+            let family: f64 = candidates
+                .iter()
+                .filter(|(o, _)| hue_distance(o.h, c.h) < MIN_HUE_SEPARATION)
+                .map(|(_, population)| population)
+                .sum();
+            c.c * family.sqrt()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..candidates.len()).collect();
+    order.sort_by(|&x, &y| scores[y].total_cmp(&scores[x]));
+    let candidates: Vec<Oklch> = order.into_iter().map(|i| candidates[i].0).collect();
+
+    let primary = candidates[0];
+    let rotated = |dh: f64| Oklch {
+        h: (primary.h + dh).rem_euclid(360.0),
+        ..primary
+    };
+    let secondary_index = (1..candidates.len())
+        .find(|&i| hue_distance(candidates[i].h, primary.h) >= MIN_HUE_SEPARATION);
+    let secondary = secondary_index.map_or_else(|| rotated(MIN_HUE_SEPARATION), |i| candidates[i]);
+    let accent = (1..candidates.len())
+        .filter(|&i| Some(i) != secondary_index)
+        .map(|i| candidates[i])
+        .filter(|c| {
+            hue_distance(c.h, primary.h) >= MIN_HUE_SEPARATION
+                && hue_distance(c.h, secondary.h) >= MIN_HUE_SEPARATION
+        })
+        .max_by(|x, y| x.c.total_cmp(&y.c))
+        .unwrap_or_else(|| rotated(180.0));
+
+    let finish = |c: &Oklch| with_contrast(c, &background, dark, options.min_contrast);
+    Some(Theme {
+        primary: finish(&primary),
+        secondary: finish(&secondary),
+        accent: finish(&accent),
+        background,
+    })
+}
+
+/// Variants of an interactive color for each UI state.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StateColors {
+    pub base: Color,
+    pub hover: Color,
+    pub active: Color,
+    pub focus: Color,
+    pub disabled: Color,
+    pub visited: Color,
+}
+
+/// Oklch deltas for `interactive_states_with`.
+///
+/// Lightness deltas are magnitudes: states move away from the surface's lightness, so
+/// hover and active get lighter on dark surfaces and darker on light ones.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StateOptions {
+    /// Lightness change for hover
+    pub hover_lightness: f64,
+    /// Lightness change for active (pressed)
+    pub active_lightness: f64,
+    /// Chroma added for focus
+    pub focus_chroma: f64,
+    /// Chroma multiplier for disabled
+    pub disabled_chroma: f64,
+    /// Fraction of the way disabled moves toward the surface's lightness
+    pub disabled_fade: f64,
+    /// Hue rotation for visited, in degrees (the default turns blue links purple)
+    pub visited_hue: f64,
+    /// Minimum WCAG contrast ratio against the surface; disabled is exempt
+    pub min_contrast: f64,
+}
+
+impl Default for StateOptions {
+    fn default() -> Self {
+        Self {
+            hover_lightness: 0.05,
+            active_lightness: 0.10,
+            focus_chroma: 0.04,
+            disabled_chroma: 0.25,
+            disabled_fade: 0.5,
+            visited_hue: 50.0,
+            min_contrast: 3.0,
+        }
+    }
+}
+
+/// State variants of `base` on `surface` with the default options.
+pub fn interactive_states(base: &Color, surface: &Color) -> StateColors {
+    interactive_states_with(base, surface, &StateOptions::default())
+}
+
+/// State variants of `base` on `surface`.
+///
+/// Every variant except disabled is darkened or lightened further if needed to reach
+/// `min_contrast` against the surface, and all are kept inside sRGB by reducing chroma.
+/// All variants keep the alpha of `base`.
+pub fn interactive_states_with(
+    base: &Color,
+    surface: &Color,
+    options: &StateOptions,
+) -> StateColors {
+    let b = Oklch::from_color(base);
+    let surface_l = Oklch::from_color(surface).l;
+    let dark = surface_l < 0.5;
+    let away = if dark { 1.0 } else { -1.0 };
+    let finish = |c: Oklch| with_contrast(&c, surface, dark, options.min_contrast);
+    let disabled = Oklch {
+        l: b.l + (surface_l - b.l) * options.disabled_fade,
+        c: b.c * options.disabled_chroma,
+        ..b
+    };
+    StateColors {
+        base: finish(b),
+        hover: finish(Oklch {
+            l: b.l + away * options.hover_lightness,
+            ..b
+        }),
+        active: finish(Oklch {
+            l: b.l + away * options.active_lightness,
+            ..b
+        }),
+        focus: finish(Oklch {
+            c: b.c + options.focus_chroma,
+            ..b
+        }),
+        disabled: Color {
+            a: b.alpha,
+            ..in_gamut_oklch(disabled.l, disabled.c, disabled.h)
+        },
+        visited: finish(Oklch {
+            h: (b.h + options.visited_hue).rem_euclid(360.0),
+            ..b
+        }),
+    }
+}
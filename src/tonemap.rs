@@ -0,0 +1,222 @@
+//! Tone mapping from scene-linear to display-linear values.
+//!
+//! `FilmicCurve` is a parametric operator: contrast is a power around middle gray, the
+//! shoulder rolls highlights off from `shoulder_start` so that `white_point` lands on
+//! display 1.0, and the toe darkens shadows without moving black or white.
+//!
+//! `SoftClip` is a simpler roll-off toward 1, for HDR highlights per channel or for
+//! chroma relative to a gamut's boundary.
+//!
+//! Scene values are linear with 1.0 at diffuse white, as in `hdr`. `eval` maps onto a
+//! display whose white is that same diffuse white (`LuminanceContext::SDR`). `eval_with`
+//! takes a `LuminanceContext` and maps onto its display instead: the output is linear in
+//! the context and tops out at its peak (`peak_linear`), so midtones keep their nits and
+//! only highlights go into the headroom. `transform_with` does the same for buffers.
+
+use crate::colorspaces::color::Color;
+use crate::colorspaces::colorspace::{ColorSpace, Gamut};
+use crate::colorspaces::oklch::Oklch;
+use crate::gamut::max_chroma_in;
+use crate::hdr::LuminanceContext;
+use crate::pipeline::Transform;
+use serde::{Deserialize, Serialize};
+
+// NOTE: Every stage is monotonic for non-negative parameters. Negative input is mapped
+// to 0, and inputs beyond the white point clip to 1.
+
+const EPSILON: f64 = 1e-10;
+/// Scene middle gray, relative to diffuse white
+const MIDDLE_GRAY: f64 = 0.18;
+// Toe strength 1.0 corresponds to this rational-toe constant
+const MAX_TOE: f64 = 0.2;
+
+/// Parametric filmic tone curve, applied per channel.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FilmicCurve {
+    /// Shadow compression in [0, 1]; 0 leaves shadows untouched
+    pub toe_strength: f64,
+    /// Display value in [0, 1) where highlight roll-off begins; scaled down with the white
+    /// point's display level when that is below 1, so the shoulder never becomes a step
+    pub shoulder_start: f64,
+    /// Scene-linear value (diffuse white = 1.0) mapped to display white, or to the peak
+    /// with `eval_with`
+    pub white_point: f64,
+    /// Power around middle gray; 1 is neutral, larger is punchier
+    pub contrast: f64,
+}
+
+impl Default for FilmicCurve {
+    fn default() -> Self {
+        Self::FILMIC
+    }
+}
+
+impl FilmicCurve {
+    /// Identity below 0.8 with a gentle shoulder up to scene 4.0
+    pub const NEUTRAL: FilmicCurve = FilmicCurve {
+        toe_strength: 0.0,
+        shoulder_start: 0.8,
+        white_point: 4.0,
+        contrast: 1.0,
+    };
+    /// Classic film look: soft toe, long shoulder, slight contrast boost
+    pub const FILMIC: FilmicCurve = FilmicCurve {
+        toe_strength: 0.3,
+        shoulder_start: 0.6,
+        white_point: 11.2,
+        contrast: 1.1,
+    };
+    /// Deep shadows and early highlight roll-off
+    pub const HIGH_CONTRAST: FilmicCurve = FilmicCurve {
+        toe_strength: 0.5,
+        shoulder_start: 0.5,
+        white_point: 8.0,
+        contrast: 1.3,
+    };
+    /// Flat, low-contrast look that keeps shadow and highlight detail
+    pub const LOW_CONTRAST: FilmicCurve = FilmicCurve {
+        toe_strength: 0.1,
+        shoulder_start: 0.7,
+        white_point: 16.0,
+        contrast: 0.9,
+    };
+
+    /// Map a scene-linear value to a display-linear value in [0, 1].
+    pub fn eval(&self, x: f64) -> f64 {
+        self.eval_with(x, &LuminanceContext::SDR)
+    }
+
+    /// Map a scene-linear value in `context` to a display-linear value in the same
+    /// context, in [0, `context.peak_linear()`].
+    ///
+    /// Contrast pivots on middle gray (0.18 of diffuse white) and the toe acts on
+    /// diffuse-relative values as in `eval`, so midtones map much as they do in SDR;
+    /// `white_point` lands on the peak. The shoulder starts at `shoulder_start` of the
+    /// peak, or of the white point's level when that is lower (as when the peak is beyond
+    /// the white point), so the roll-off always has room. With `LuminanceContext::SDR`
+    /// this is `eval`.
+    pub fn eval_with(&self, x: f64, context: &LuminanceContext) -> f64 {
+        let headroom = context.peak_linear().max(EPSILON);
+        let contrast = |x: f64| MIDDLE_GRAY * (x / MIDDLE_GRAY).powf(self.contrast);
+        // The shoulder works in units of the peak
+        let x = contrast(x.max(0.0)) / headroom;
+        let white = contrast(self.white_point) / headroom;
+        // A start at or past the white point would make the shoulder a step
+        let start = self.shoulder_start.clamp(0.0, 1.0 - EPSILON);
+        let y = headroom * shoulder(x, start.min(start * white), white);
+        let toe = self.toe_strength.clamp(0.0, 1.0) * MAX_TOE;
+        if toe < EPSILON {
+            return y;
+        }
+        // Rescaled so the peak stays put; the scale is 1 without headroom
+        let toe_curve = |y: f64| y * y / (toe + y * (1.0 - toe));
+        toe_curve(y) * headroom / toe_curve(headroom)
+    }
+
+    /// The curve as a per-channel transform in `context`, for buffers.
+    pub fn transform_with(self, context: LuminanceContext) -> impl Transform {
+        move |rgb: [f64; 3]| rgb.map(|c| self.eval_with(c, &context))
+    }
+}
+
+/// Identity up to `start`, then an extended-Reinhard roll-off whose slope is 1 at
+/// `start` and which reaches exactly 1 at `white`.
+fn shoulder(x: f64, start: f64, white: f64) -> f64 {
+    if x <= start {
+        return x;
+    }
+    if x >= white {
+        return 1.0;
+    }
+    let span = 1.0 - start;
+    let t = (x - start) / span;
+    let w = (white - start) / span;
+    start + span * t * (1.0 + t / (w * w)) / (1.0 + t)
+}
+
+impl Transform for FilmicCurve {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        rgb.map(|c| self.eval(c))
+    }
+}
+
+/// Soft clip: identity up to `knee`, then a roll-off with unit slope at the knee that
+/// approaches 1 without reaching it.
+///
+/// Above the knee, with t the distance past it as a fraction of 1 - knee, the output is
+/// knee + (1 - knee) · t / (1 + t^p)^(1/p), where p = 1 / `strength`. Small strengths
+/// stay close to a hard clip; strength 1 is a Reinhard curve.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SoftClip {
+    /// Value in [0, 1) where the roll-off begins
+    pub knee: f64,
+    /// How gradual the roll-off is (> 0)
+    pub strength: f64,
+}
+
+impl Default for SoftClip {
+    fn default() -> Self {
+        Self {
+            knee: 0.8,
+            strength: 0.5,
+        }
+    }
+}
+
+impl SoftClip {
+    /// Soft clip starting at `knee` with the given strength.
+    pub fn new(knee: f64, strength: f64) -> Self {
+        Self { knee, strength }
+    }
+
+    /// Roll a value off toward 1. Values up to the knee, negatives included, pass through.
+    pub fn eval(&self, x: f64) -> f64 {
+        let knee = self.knee.clamp(0.0, 1.0 - EPSILON);
+        if x <= knee {
+            return x;
+        }
+        let p = 1.0 / self.strength.max(EPSILON);
+        let span = 1.0 - knee;
+        let t = (x - knee) / span;
+        // The same curve either way; dividing through by t avoids overflow when t is large
+        let rolled = if t <= 1.0 {
+            t / (1.0 + t.powf(p)).powf(1.0 / p)
+        } else {
+            (1.0 + t.powf(-p)).powf(-1.0 / p)
+        };
+        knee + span * rolled
+    }
+
+    /// Roll a linear value in `context` off toward its peak (`context.peak_linear()`),
+    /// with the knee as a fraction of the peak. With `LuminanceContext::SDR` this is
+    /// `eval`.
+    pub fn eval_with(&self, x: f64, context: &LuminanceContext) -> f64 {
+        let headroom = context.peak_linear().max(EPSILON);
+        headroom * self.eval(x / headroom)
+    }
+
+    /// The soft clip as a per-channel transform in `context`, for buffers.
+    pub fn transform_with(self, context: LuminanceContext) -> impl Transform {
+        move |rgb: [f64; 3]| rgb.map(|c| self.eval_with(c, &context))
+    }
+
+    /// Soft clip Oklch chroma as a fraction of `T`'s boundary at the color's lightness and
+    /// hue, keeping lightness and hue, then clip what lies beyond the boundary's ends.
+    pub fn apply_chroma<T: Gamut>(&self, color: &Color) -> Color {
+        let origin = Oklch::from_color(color);
+        let l = origin.l.clamp(0.0, 1.0);
+        let limit = max_chroma_in::<T>(l, origin.h);
+        let c = if limit > EPSILON {
+            limit * self.eval(origin.c / limit)
+        } else {
+            0.0
+        };
+        T::clip(&Oklch { l, c, ..origin }.to_color())
+    }
+}
+
+impl Transform for SoftClip {
+    fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        rgb.map(|c| self.eval(c))
+    }
+}
@@ -0,0 +1,137 @@
+//! Standard illuminant white points.
+//!
+//! Chromaticities are the CIE 15 / ASTM E308 values for the CIE 1931 2° and CIE 1964 10°
+//! standard observers. `xyz` gives the white as XYZ with Y = 1, the form
+//! `chromatic_adaptation` takes, and `chromaticity` the xy that `CustomRgb` takes.
+//!
+//! Other whites, such as one measured by a camera, are `WhitePoint::Custom` chromaticities,
+//! made directly with `from_xy` or from a correlated color temperature on the Planckian
+//! locus (`planckian`) or the CIE daylight locus (`daylight`).
+
+use crate::colorspaces::gamma_rgb::xy_to_xyz;
+use crate::spectral::{Illuminant, daylight_chromaticity};
+use serde::{Deserialize, Serialize};
+
+// NOTE: D65 for the 2° observer is the rounded (0.3127, 0.3290) that sRGB and BT.709
+// specify, which is the white of the `Color` hub; CIE 15 gives (0.31271, 0.32902). ICC
+// profiles use their own D50 XYZ (0.9642, 1, 0.8249), about 4e-5 from the CIE value in
+// xy.
+
+const MIN_KELVIN: f64 = 1000.0;
+const MAX_KELVIN: f64 = 100000.0;
+
+/// CIE standard colorimetric observer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Observer {
+    /// CIE 1931 2° observer, the usual choice for colorimetry
+    #[default]
+    Cie1931,
+    /// CIE 1964 10° observer, for fields of view over about 4°
+    Cie1964,
+}
+
+/// Named CIE standard illuminants, or a white given by its chromaticity.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum WhitePoint {
+    /// Incandescent, 2856 K
+    A,
+    /// Direct noon sunlight (obsolete)
+    B,
+    /// Average daylight (obsolete)
+    C,
+    /// Horizon daylight, 5003 K, the print and ICC standard
+    D50,
+    /// Mid-morning daylight, 5503 K
+    D55,
+    /// Noon daylight, 6504 K, the sRGB and video standard
+    #[default]
+    D65,
+    /// North sky daylight, 7504 K
+    D75,
+    /// Equal energy
+    E,
+    /// Daylight fluorescent
+    F1,
+    /// Cool white fluorescent
+    F2,
+    /// White fluorescent
+    F3,
+    /// Warm white fluorescent
+    F4,
+    /// Daylight fluorescent
+    F5,
+    /// Light white fluorescent
+    F6,
+    /// Broadband D65 simulator
+    F7,
+    /// Broadband D50 simulator
+    F8,
+    /// Broadband cool white deluxe fluorescent
+    F9,
+    /// Narrow tri-band, 5000 K
+    F10,
+    /// Narrow tri-band, 4000 K
+    F11,
+    /// Narrow tri-band, 3000 K
+    F12,
+    /// Any other white, by its xy chromaticity, used as given for either observer
+    Custom([f64; 2]),
+}
+
+impl WhitePoint {
+    /// A white with xy chromaticity (`x`, `y`).
+    pub fn from_xy(x: f64, y: f64) -> Self {
+        WhitePoint::Custom([x, y])
+    }
+
+    /// The Planckian radiator at `kelvin` (clamped to 1000-100000 K), for the 2° observer.
+    pub fn planckian(kelvin: f64) -> Self {
+        let [x, y, z] = Illuminant::Blackbody(kelvin.clamp(MIN_KELVIN, MAX_KELVIN)).white();
+        let sum = x + y + z;
+        WhitePoint::Custom([x / sum, y / sum])
+    }
+
+    /// CIE daylight at the nominal temperature `kelvin` (clamped to 4000-25000 K), as in
+    /// the names of the D illuminants: 6500 K and 5000 K give D65 and D50 to within 1e-4
+    /// in xy. Their correlated color temperatures are about 0.06% higher, 6504 K and
+    /// 5003 K, since the locus predates the 1968 change to c2.
+    pub fn daylight(kelvin: f64) -> Self {
+        WhitePoint::Custom(daylight_chromaticity(kelvin))
+    }
+
+    /// xy chromaticity for the given observer.
+    pub fn chromaticity(&self, observer: Observer) -> [f64; 2] {
+        let (two, ten) = match self {
+            WhitePoint::A => ([0.44757, 0.40745], [0.45117, 0.40594]),
+            WhitePoint::B => ([0.34842, 0.35161], [0.34980, 0.35270]),
+            WhitePoint::C => ([0.31006, 0.31616], [0.31039, 0.31905]),
+            WhitePoint::D50 => ([0.34567, 0.35850], [0.34773, 0.35952]),
+            WhitePoint::D55 => ([0.33242, 0.34743], [0.33411, 0.34877]),
+            WhitePoint::D65 => ([0.3127, 0.3290], [0.31382, 0.33100]),
+            WhitePoint::D75 => ([0.29902, 0.31485], [0.29968, 0.31740]),
+            WhitePoint::E => ([1.0 / 3.0, 1.0 / 3.0], [1.0 / 3.0, 1.0 / 3.0]),
+            WhitePoint::F1 => ([0.31310, 0.33727], [0.31811, 0.33559]),
+            WhitePoint::F2 => ([0.37208, 0.37529], [0.37925, 0.36733]),
+            WhitePoint::F3 => ([0.40910, 0.39430], [0.41761, 0.38324]),
+            WhitePoint::F4 => ([0.44018, 0.40329], [0.44920, 0.39074]),
+            WhitePoint::F5 => ([0.31379, 0.34531], [0.31975, 0.34246]),
+            WhitePoint::F6 => ([0.37790, 0.38835], [0.38660, 0.37847]),
+            WhitePoint::F7 => ([0.31292, 0.32933], [0.31569, 0.32960]),
+            WhitePoint::F8 => ([0.34588, 0.35875], [0.34902, 0.35939]),
+            WhitePoint::F9 => ([0.37417, 0.37281], [0.37829, 0.37045]),
+            WhitePoint::F10 => ([0.34609, 0.35986], [0.35090, 0.35444]),
+            WhitePoint::F11 => ([0.38052, 0.37713], [0.38541, 0.37123]),
+            WhitePoint::F12 => ([0.43695, 0.40441], [0.44256, 0.39717]),
+            WhitePoint::Custom(xy) => return *xy,
+        };
+        match observer {
+            Observer::Cie1931 => two,
+            Observer::Cie1964 => ten,
+        }
+    }
+
+    /// XYZ with Y = 1 for the given observer.
+    pub fn xyz(&self, observer: Observer) -> [f64; 3] {
+        xy_to_xyz(self.chromaticity(observer))
+    }
+}
@@ -0,0 +1,92 @@
+use colorlab::{Aces2065_1, AcesCc, AcesCct, AcesCg, Color, ColorSpace, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn acescg_reference_values() {
+    // sRGB red in AP1, the first column of the published sRGB to ACEScg matrix
+    let red = AcesCg::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    for (got, want) in [(red.r, 0.6131), (red.g, 0.0702), (red.b, 0.0206)] {
+        assert!((got - want).abs() < 1e-4, "{red:?}");
+    }
+    let white = AcesCg::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    for v in [white.r, white.g, white.b] {
+        assert!((v - 1.0).abs() < 1e-9, "{white:?}");
+    }
+}
+
+#[test]
+fn acescg_round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&AcesCg::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
+
+#[test]
+fn aces2065_1_reference_values() {
+    // sRGB red in AP0, the first column of the published sRGB to ACES2065-1 matrix
+    let red = Aces2065_1::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    for (got, want) in [(red.r, 0.4397), (red.g, 0.0898), (red.b, 0.0175)] {
+        assert!((got - want).abs() < 1e-4, "{red:?}");
+    }
+}
+
+#[test]
+fn aces2065_1_round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Aces2065_1::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
+
+/// An AP1 gray `v`, as ACEScc and ACEScct values.
+fn log_gray(v: f64) -> (f64, f64) {
+    let cg = AcesCg::new(v, v, v);
+    (AcesCc::from(cg).g, AcesCct::from(cg).g)
+}
+
+#[test]
+fn log_encodings_reference_values() {
+    // S-2014-003 and S-2016-001: 18% gray is 0.4135884 in both encodings
+    let (cc, cct) = log_gray(0.18);
+    assert!((cc - 0.4135884).abs() < 1e-7 && (cct - 0.4135884).abs() < 1e-7);
+    let (cc, _) = log_gray(1.0);
+    assert!((cc - 0.5547945).abs() < 1e-7);
+    // Zero and below all encode to the value of 2^-16 in ACEScc; ACEScct's linear toe
+    // puts zero at 0.0729055
+    for v in [0.0, -0.1] {
+        assert!((log_gray(v).0 - -0.3584475).abs() < 1e-7);
+    }
+    assert!((log_gray(0.0).1 - 0.0729055).abs() < 1e-7);
+    // ACEScct's toe meets the log segment at 0.0078125
+    let (below, above) = (log_gray(0.0078125 - 1e-9).1, log_gray(0.0078125 + 1e-9).1);
+    assert!((below - 0.1552511).abs() < 1e-7 && (above - 0.1552511).abs() < 1e-7);
+}
+
+#[test]
+fn log_encodings_round_trip_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&AcesCc::from_color(&color).to_color(), &color, 1e-9);
+        assert_same_color(&AcesCct::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
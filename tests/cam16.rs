@@ -0,0 +1,100 @@
+use colorlab::{Cam16, Cam16Ucs, Color, ColorSpace, Srgb, Surround, ViewingConditions, Xyz};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn white_has_full_lightness() {
+    let white = Cam16::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    assert!((white.j - 100.0).abs() < 1e-3, "{white:?}");
+    // Incomplete adaptation under the default conditions leaves a trace of chroma
+    assert!(white.c < 5.0, "{white:?}");
+}
+
+#[test]
+fn red_hue_and_chroma() {
+    // Under the default sRGB reference conditions
+    let red = Cam16::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    assert!((red.h - 27.39).abs() < 0.01, "{red:?}");
+    assert!((red.c - 112.39).abs() < 0.01, "{red:?}");
+    assert!((red.j - 46.03).abs() < 0.01, "{red:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Cam16::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
+
+#[test]
+fn round_trips_other_viewing_conditions() {
+    let vc = ViewingConditions::new(200.0, 10.0, Surround::Dim);
+    for color in srgb_sample() {
+        let back = Cam16::from_color_with(&color, &vc).to_color_with(&vc);
+        assert_same_color(&back, &color, 1e-9);
+    }
+}
+
+#[test]
+fn ucs_compresses_lightness_and_colorfulness() {
+    // J' = 1.7 J / (1 + 0.007 J) and M' = ln(1 + 0.0228 M) / 0.0228 (Li et al. 2017)
+    let vc = ViewingConditions::default();
+    for color in srgb_sample() {
+        let cam = Cam16::from_color(&color);
+        let ucs = Cam16Ucs::from_color(&color);
+        assert!((ucs.j - 1.7 * cam.j / (1.0 + 0.007 * cam.j)).abs() < 1e-9);
+        let m = (1.0 + 0.0228 * cam.colorfulness(&vc)).ln() / 0.0228;
+        assert!((ucs.a.hypot(ucs.b) - m).abs() < 1e-9, "{color:?}");
+    }
+}
+
+#[test]
+fn ucs_delta_e_is_euclidean() {
+    let a = Cam16Ucs::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    let b = Cam16Ucs::from_color(&Color::opaque(0.9, 0.1, 0.0));
+    assert_eq!(a.delta_e(&a), 0.0);
+    assert_eq!(a.delta_e(&b), b.delta_e(&a));
+    let d = ((a.j - b.j).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt();
+    assert!((a.delta_e(&b) - d).abs() < 1e-12);
+}
+
+#[test]
+fn ucs_round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Cam16Ucs::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
+
+#[test]
+fn matches_the_reference_implementation() {
+    // The colour-science CAM16 example: a D65 white at 318.31 cd/m²
+    let vc = ViewingConditions {
+        white: [0.9505, 1.0, 1.0888],
+        ..ViewingConditions::new(318.31, 20.0, Surround::Average)
+    };
+    let cam = Cam16::from_xyz(&Xyz::new(0.1901, 0.2, 0.2178), &vc);
+    assert!((cam.j - 41.731208).abs() < 1e-4, "{cam:?}");
+    assert!((cam.c - 0.103356).abs() < 1e-4, "{cam:?}");
+    assert!((cam.h - 217.067960).abs() < 1e-3, "{cam:?}");
+}
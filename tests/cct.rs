@@ -0,0 +1,47 @@
+use colorlab::{Cct, ColorSpace, Uv1960, UvPrime, Xyy};
+
+fn cct_of(x: f64, y: f64) -> Cct {
+    Cct::from(Uv1960::from(UvPrime::from(Xyy::new(x, y, 1.0))))
+}
+
+#[test]
+fn standard_illuminants_have_their_published_cct() {
+    // D65 6504 K, D50 5003 K (5002 by Robertson's method) and A 2856 K
+    for ((x, y), kelvin) in [
+        ((0.3127, 0.3290), 6504.0),
+        ((0.34567, 0.35850), 5002.0),
+        ((0.44757, 0.40745), 2856.0),
+    ] {
+        let cct = cct_of(x, y);
+        assert!((cct.kelvin - kelvin).abs() < 1.0, "{x}, {y}: {cct:?}");
+        assert!(cct.duv.abs() < 0.01, "{cct:?}");
+    }
+    // A sits on the locus, D65 above it
+    assert!(cct_of(0.44757, 0.40745).duv.abs() < 2e-4);
+    assert!((cct_of(0.3127, 0.3290).duv - 0.0032).abs() < 2e-4);
+}
+
+#[test]
+fn round_trips_across_the_range() {
+    for kelvin in [
+        1000.0, 1200.0, 1666.0, 1667.0, 1668.0, 2700.0, 6504.0, 20000.0,
+    ] {
+        for duv in [-0.02, 0.0, 0.01] {
+            let cct = Cct::new(kelvin, duv, 0.5);
+            let back = Cct::from_color(&cct.to_color());
+            assert!(
+                (back.kelvin - kelvin).abs() < 1e-3 * kelvin,
+                "{cct:?} gave {back:?}"
+            );
+            assert!((back.duv - duv).abs() < 1e-6, "{cct:?} gave {back:?}");
+        }
+    }
+}
+
+#[test]
+fn locus_is_continuous_where_the_table_ends() {
+    // Without the shift, the integrated locus would start about 4e-5 away
+    let uv = |kelvin: f64| Uv1960::from(Cct::new(kelvin, 0.0, 1.0));
+    let (below, above) = (uv(1666.66), uv(1666.67));
+    assert!((below.u - above.u).hypot(below.v - above.v) < 1e-5);
+}
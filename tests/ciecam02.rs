@@ -0,0 +1,64 @@
+use colorlab::{Cam02Ucs, Ciecam02, Color, ColorSpace, Srgb, Surround, ViewingConditions, Xyz};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+/// The conditions of the colour-science CIECAM02 example: a D65 white at 318.31 cd/m²
+fn example_conditions() -> ViewingConditions {
+    ViewingConditions {
+        white: [0.9505, 1.0, 1.0888],
+        ..ViewingConditions::new(318.31, 20.0, Surround::Average)
+    }
+}
+
+#[test]
+fn matches_the_reference_implementation() {
+    let cam = Ciecam02::from_xyz(&Xyz::new(0.1901, 0.2, 0.2178), &example_conditions());
+    assert!((cam.j - 41.731091).abs() < 1e-4, "{cam:?}");
+    assert!((cam.c - 0.104707).abs() < 1e-4, "{cam:?}");
+    assert!((cam.h - 219.048432).abs() < 1e-3, "{cam:?}");
+}
+
+#[test]
+fn white_has_full_lightness() {
+    let white = Ciecam02::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    assert!((white.j - 100.0).abs() < 1e-3, "{white:?}");
+    assert!(white.c < 5.0, "{white:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    let vc = ViewingConditions::new(200.0, 10.0, Surround::Dim);
+    for color in srgb_sample() {
+        assert_same_color(&Ciecam02::from_color(&color).to_color(), &color, 1e-9);
+        let back = Ciecam02::from_color_with(&color, &vc).to_color_with(&vc);
+        assert_same_color(&back, &color, 1e-9);
+    }
+}
+
+#[test]
+fn ucs_round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Cam02Ucs::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,247 @@
+#![cfg(feature = "clf")]
+
+use colorlab::clf::{ClfError, ClfNode, ProcessList};
+use colorlab::pipeline::Transform;
+
+/// A `ProcessList` document around `nodes`.
+fn document(nodes: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ProcessList id="test" name="Test" compCLFversion="3.0">
+    <Description>test</Description>
+    {nodes}
+</ProcessList>"#
+    )
+}
+
+fn parse(nodes: &str) -> ProcessList {
+    ProcessList::parse(&document(nodes)).unwrap()
+}
+
+fn assert_close(actual: [f64; 3], expected: [f64; 3]) {
+    for (a, e) in actual.iter().zip(expected) {
+        assert!(
+            (a - e).abs() < 1e-9,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+}
+
+#[test]
+fn reads_id_and_name() {
+    let list = parse("");
+    assert_eq!(list.id.as_deref(), Some("test"));
+    assert_eq!(list.name.as_deref(), Some("Test"));
+    assert!(list.nodes.is_empty());
+}
+
+#[test]
+fn applies_matrix_with_offset() {
+    let list = parse(
+        r#"<Matrix inBitDepth="32f" outBitDepth="32f">
+            <Array dim="3 4">
+                2 0 0 0.1
+                0 1 0 0
+                0.5 0 0.5 -0.1
+            </Array>
+        </Matrix>"#,
+    );
+    assert_close(list.apply([0.25, 0.5, 0.75]), [0.6, 0.5, 0.4]);
+}
+
+#[test]
+fn normalizes_matrix_bit_depths() {
+    // 10-bit in, 8-bit out: the identity matrix scales by 255 / 1023 in code values,
+    // which is the identity on normalized values
+    let list = parse(
+        r#"<Matrix inBitDepth="10i" outBitDepth="8i">
+            <Array dim="3 3">
+                0.24926686217008798 0 0
+                0 0.24926686217008798 0
+                0 0 0.24926686217008798
+            </Array>
+        </Matrix>"#,
+    );
+    assert_close(list.apply([0.1, 0.5, 0.9]), [0.1, 0.5, 0.9]);
+}
+
+#[test]
+fn interpolates_lut1d() {
+    let list = parse(
+        r#"<LUT1D inBitDepth="32f" outBitDepth="10i">
+            <Array dim="3 1">0 255.75 1023</Array>
+        </LUT1D>"#,
+    );
+    assert_close(list.apply([0.0, 0.25, 0.75]), [0.0, 0.125, 0.625]);
+    // Inputs are clamped to the table
+    assert_close(list.apply([-1.0, 1.0, 2.0]), [0.0, 1.0, 1.0]);
+}
+
+#[test]
+fn interpolates_lut3d() {
+    // A 2³ table whose red output is r·g·b and other channels are identity; blue varies
+    // fastest
+    let lut = |interpolation: &str| {
+        let mut values = String::new();
+        for r in 0..2 {
+            for g in 0..2 {
+                for b in 0..2 {
+                    values += &format!("{} {g} {b} ", r * g * b);
+                }
+            }
+        }
+        parse(&format!(
+            r#"<LUT3D inBitDepth="32f" outBitDepth="32f" interpolation="{interpolation}">
+                <Array dim="2 2 2 3">{values}</Array>
+            </LUT3D>"#
+        ))
+    };
+    let trilinear = lut("trilinear");
+    let tetrahedral = lut("tetrahedral");
+    assert!(matches!(trilinear.nodes[0], ClfNode::Lut3d { size: 2, .. }));
+    assert_close(trilinear.apply([0.5, 0.5, 0.5]), [0.125, 0.5, 0.5]);
+    // Along the main diagonal tetrahedral interpolation is linear between the corners
+    assert_close(tetrahedral.apply([0.5, 0.5, 0.5]), [0.5, 0.5, 0.5]);
+    assert_close(tetrahedral.apply([0.2, 0.6, 0.9]), [0.2, 0.6, 0.9]);
+}
+
+#[test]
+fn clamps_range() {
+    let list = parse(
+        r#"<Range inBitDepth="32f" outBitDepth="32f">
+            <minInValue>0</minInValue>
+            <maxInValue>1</maxInValue>
+            <minOutValue>0.1</minOutValue>
+            <maxOutValue>0.9</maxOutValue>
+        </Range>"#,
+    );
+    assert_close(list.apply([-1.0, 0.5, 2.0]), [0.1, 0.5, 0.9]);
+}
+
+#[test]
+fn extrapolates_unclamped_range() {
+    let list = parse(
+        r#"<Range inBitDepth="32f" outBitDepth="32f" style="noClamp">
+            <minInValue>0</minInValue>
+            <maxInValue>1</maxInValue>
+            <minOutValue>0.1</minOutValue>
+            <maxOutValue>0.9</maxOutValue>
+        </Range>"#,
+    );
+    assert_close(list.apply([-1.0, 0.5, 2.0]), [-0.7, 0.5, 1.7]);
+}
+
+#[test]
+fn applies_inverted_range() {
+    let list = parse(
+        r#"<Range inBitDepth="32f" outBitDepth="32f">
+            <minInValue>0</minInValue>
+            <maxInValue>1</maxInValue>
+            <minOutValue>1</minOutValue>
+            <maxOutValue>0</maxOutValue>
+        </Range>"#,
+    );
+    assert_close(list.apply([-1.0, 0.25, 2.0]), [1.0, 0.75, 0.0]);
+}
+
+#[test]
+fn applies_one_sided_range() {
+    let list = parse(
+        r#"<Range inBitDepth="10i" outBitDepth="10i">
+            <minInValue>64</minInValue>
+            <minOutValue>0</minOutValue>
+        </Range>"#,
+    );
+    let offset = 64.0 / 1023.0;
+    assert_close(
+        list.apply([0.0, 0.5, 2.0]),
+        [0.0, 0.5 - offset, 2.0 - offset],
+    );
+}
+
+#[test]
+fn rejects_non_finite_range_values() {
+    for value in ["NaN", "inf", "-inf"] {
+        let xml = document(&format!(
+            r#"<Range inBitDepth="32f" outBitDepth="32f">
+                <minInValue>0</minInValue>
+                <maxInValue>{value}</maxInValue>
+                <minOutValue>0</minOutValue>
+                <maxOutValue>1</maxOutValue>
+            </Range>"#
+        ));
+        assert!(matches!(
+            ProcessList::parse(&xml),
+            Err(ClfError::Invalid(_))
+        ));
+    }
+}
+
+#[test]
+fn rejects_empty_input_range() {
+    let xml = document(
+        r#"<Range inBitDepth="32f" outBitDepth="32f">
+            <minInValue>0.5</minInValue>
+            <maxInValue>0.5</maxInValue>
+            <minOutValue>0</minOutValue>
+            <maxOutValue>1</maxOutValue>
+        </Range>"#,
+    );
+    assert!(matches!(
+        ProcessList::parse(&xml),
+        Err(ClfError::Invalid(_))
+    ));
+}
+
+#[test]
+fn applies_nodes_in_order() {
+    let list = parse(
+        r#"<Matrix inBitDepth="32f" outBitDepth="32f">
+            <Array dim="3 3">2 0 0 0 2 0 0 0 2</Array>
+        </Matrix>
+        <Range inBitDepth="32f" outBitDepth="32f">
+            <maxInValue>1</maxInValue>
+            <maxOutValue>1</maxOutValue>
+        </Range>"#,
+    );
+    assert_close(list.apply([0.25, 0.5, 0.75]), [0.5, 1.0, 1.0]);
+}
+
+#[test]
+fn rejects_unsupported_nodes() {
+    let xml = document(r#"<Log inBitDepth="32f" outBitDepth="32f" style="log10"/>"#);
+    assert!(matches!(
+        ProcessList::parse(&xml),
+        Err(ClfError::Unsupported(_))
+    ));
+    let xml = document(
+        r#"<LUT1D inBitDepth="32f" outBitDepth="32f" halfDomain="true">
+            <Array dim="2 1">0 1</Array>
+        </LUT1D>"#,
+    );
+    assert!(matches!(
+        ProcessList::parse(&xml),
+        Err(ClfError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn rejects_malformed_documents() {
+    assert!(matches!(
+        ProcessList::parse("<ProcessList>"),
+        Err(ClfError::Xml(_))
+    ));
+    assert!(matches!(
+        ProcessList::parse("<Other/>"),
+        Err(ClfError::Invalid(_))
+    ));
+    let xml = document(
+        r#"<LUT1D inBitDepth="32f" outBitDepth="32f">
+            <Array dim="3 1">0 1</Array>
+        </LUT1D>"#,
+    );
+    assert!(matches!(
+        ProcessList::parse(&xml),
+        Err(ClfError::Invalid(_))
+    ));
+}
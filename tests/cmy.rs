@@ -0,0 +1,41 @@
+use colorlab::{Cmy, Color, ColorSpace, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn is_the_complement_of_srgb() {
+    let red = Cmy::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    assert!(red.c.abs() < 1e-12 && (red.m - 1.0).abs() < 1e-12 && (red.y - 1.0).abs() < 1e-12);
+    let cmy = Cmy::from_color(&Srgb::new(0.2, 0.4, 0.8).to_color());
+    assert!((cmy.c - 0.8).abs() < 1e-12, "{cmy:?}");
+    assert!((cmy.m - 0.6).abs() < 1e-12, "{cmy:?}");
+    assert!((cmy.y - 0.2).abs() < 1e-12, "{cmy:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Cmy::from_color(&color).to_color(), &color, 1e-12);
+    }
+}
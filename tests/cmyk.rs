@@ -0,0 +1,66 @@
+use colorlab::{Cmy, Cmyk, Color, ColorSpace, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+fn assert_cmyk(cmyk: Cmyk, [c, m, y, k]: [f64; 4]) {
+    for (got, want) in [(cmyk.c, c), (cmyk.m, m), (cmyk.y, y), (cmyk.k, k)] {
+        assert!((got - want).abs() < 1e-9, "{cmyk:?}");
+    }
+}
+
+#[test]
+fn reference_values() {
+    assert_cmyk(
+        Cmyk::from_color(&Color::opaque(1.0, 0.0, 0.0)),
+        [0.0, 1.0, 1.0, 0.0],
+    );
+    assert_cmyk(
+        Cmyk::from_color(&Color::opaque(1.0, 1.0, 1.0)),
+        [0.0, 0.0, 0.0, 0.0],
+    );
+    assert_cmyk(
+        Cmyk::from_color(&Color::opaque(0.0, 0.0, 0.0)),
+        [0.0, 0.0, 0.0, 1.0],
+    );
+    // Grays are all black ink
+    let gray = Srgb::new(0.5, 0.5, 0.5).to_color();
+    assert_cmyk(Cmyk::from_color(&gray), [0.0, 0.0, 0.0, 0.5]);
+    // sRGB (0.2, 0.4, 0.8): K = 0.2, then C = (1 - 0.2 - 0.2) / 0.8
+    let blue = Srgb::new(0.2, 0.4, 0.8).to_color();
+    assert_cmyk(Cmyk::from_color(&blue), [0.75, 0.5, 0.0, 0.2]);
+}
+
+#[test]
+fn converts_to_cmy() {
+    let cmy = Cmy::from(Cmyk::new(0.75, 0.5, 0.0, 0.2));
+    assert!((cmy.c - 0.8).abs() < 1e-12 && (cmy.m - 0.6).abs() < 1e-12);
+    assert!((cmy.y - 0.2).abs() < 1e-12, "{cmy:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Cmyk::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
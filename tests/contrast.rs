@@ -0,0 +1,65 @@
+use colorlab::contrast::{ContrastMetric, ensure_contrast};
+use colorlab::{Color, ColorSpace, Oklch};
+
+const METRICS: [(ContrastMetric, f64); 2] =
+    [(ContrastMetric::Wcag, 4.5), (ContrastMetric::Apca, 60.0)];
+
+#[test]
+fn ensure_contrast_meets_the_target() {
+    let backgrounds = [
+        Color::opaque(1.0, 1.0, 1.0),
+        Color::opaque(0.0, 0.0, 0.0),
+        Color::opaque(0.2, 0.2, 0.2),
+        Color::opaque(0.05, 0.2, 0.6),
+    ];
+    let texts = [
+        Color::opaque(0.3, 0.3, 0.3),
+        Color::new(0.8, 0.1, 0.1, 0.6),
+        Color::opaque(0.1, 0.5, 0.1),
+    ];
+    for (metric, target) in METRICS {
+        for background in &backgrounds {
+            for text in &texts {
+                // Every pair here can reach the target
+                let adjusted = ensure_contrast(text, background, target, metric).unwrap();
+                assert!(
+                    metric.contrast(&adjusted, background) >= target,
+                    "{metric:?}: {text:?} on {background:?} gave {adjusted:?}"
+                );
+                assert_eq!(adjusted.a, text.a);
+                // Hue is kept wherever the adjusted color still has one
+                let (from, to) = (Oklch::from_color(text), Oklch::from_color(&adjusted));
+                if to.c > 0.01 {
+                    let dh = (to.h - from.h + 180.0).rem_euclid(360.0) - 180.0;
+                    assert!(dh.abs() < 1.0, "{metric:?}: hue moved by {dh}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn ensure_contrast_keeps_readable_text() {
+    let text = Color::opaque(0.0, 0.0, 0.0);
+    let background = Color::opaque(1.0, 1.0, 1.0);
+    for (metric, target) in METRICS {
+        assert_eq!(
+            ensure_contrast(&text, &background, target, metric),
+            Some(text)
+        );
+    }
+}
+
+#[test]
+fn ensure_contrast_fails_beyond_black_and_white() {
+    let text = Color::opaque(0.5, 0.5, 0.5);
+    let background = Color::opaque(0.5, 0.5, 0.5);
+    assert_eq!(
+        ensure_contrast(&text, &background, 22.0, ContrastMetric::Wcag),
+        None
+    );
+    assert_eq!(
+        ensure_contrast(&text, &background, 200.0, ContrastMetric::Apca),
+        None
+    );
+}
@@ -0,0 +1,101 @@
+use colorlab::{Color, ColorSpace, CustomRgb, CustomRgbSpace, DisplayP3, Srgb, TransferFunction};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+const D65: [f64; 2] = [0.3127, 0.3290];
+
+#[test]
+fn srgb_primaries_give_srgb() {
+    let space = CustomRgbSpace::new(
+        [0.64, 0.33],
+        [0.30, 0.60],
+        [0.15, 0.06],
+        D65,
+        TransferFunction::Srgb,
+    );
+    for color in srgb_sample() {
+        let custom = CustomRgb::from_color_with(&color, &space);
+        let srgb = Srgb::from_color(&color);
+        for (x, y) in [(custom.r, srgb.r), (custom.g, srgb.g), (custom.b, srgb.b)] {
+            assert!((x - y).abs() < 1e-9, "{custom:?} vs {srgb:?}");
+        }
+    }
+}
+
+#[test]
+fn display_p3_primaries_give_display_p3() {
+    let space = CustomRgbSpace::new(
+        [0.680, 0.320],
+        [0.265, 0.690],
+        [0.150, 0.060],
+        D65,
+        TransferFunction::Srgb,
+    );
+    let red = CustomRgb::from_color_with(&Color::opaque(1.0, 0.0, 0.0), &space);
+    let p3 = DisplayP3::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    for (x, y) in [(red.r, p3.r), (red.g, p3.g), (red.b, p3.b)] {
+        assert!((x - y).abs() < 1e-9, "{red:?} vs {p3:?}");
+    }
+}
+
+#[test]
+fn white_maps_to_white() {
+    // A D50 white is adapted to the hub's D65
+    let space = CustomRgbSpace::new(
+        [0.7347, 0.2653],
+        [0.1596, 0.8404],
+        [0.0366, 0.0001],
+        [0.3457, 0.3585],
+        TransferFunction::Gamma(1.8),
+    );
+    let white = CustomRgb::new(1.0, 1.0, 1.0).space(space).to_color();
+    assert_same_color(&white, &Color::opaque(1.0, 1.0, 1.0), 1e-9);
+}
+
+#[test]
+fn round_trips_srgb() {
+    let spaces = [
+        CustomRgbSpace::default(),
+        CustomRgbSpace::new(
+            [0.708, 0.292],
+            [0.170, 0.797],
+            [0.131, 0.046],
+            D65,
+            TransferFunction::Rec709,
+        ),
+        CustomRgbSpace::new(
+            [0.64, 0.33],
+            [0.21, 0.71],
+            [0.15, 0.06],
+            D65,
+            TransferFunction::Gamma(2.2),
+        ),
+    ];
+    for space in &spaces {
+        for color in srgb_sample() {
+            let back = CustomRgb::from_color_with(&color, space).to_color();
+            assert_same_color(&back, &color, 1e-9);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use colorlab::{Color, ColorSpace, DciP3, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn dci_white_maps_to_srgb_white() {
+    let white = DciP3::new(1.0, 1.0, 1.0).to_color();
+    assert_same_color(&white, &Color::opaque(1.0, 1.0, 1.0), 1e-9);
+    // Grays stay gray, through the pure 2.6 power
+    let gray = DciP3::new(0.5, 0.5, 0.5).to_color();
+    assert_same_color(
+        &gray,
+        &Color::opaque(0.5f64.powf(2.6), 0.5f64.powf(2.6), 0.5f64.powf(2.6)),
+        1e-9,
+    );
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&DciP3::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,75 @@
+use colorlab::Color;
+use colorlab::filters::{PhotoFilter, WrattenFilter};
+use colorlab::pipeline::{Pipeline, Transform};
+
+fn pixels() -> Vec<Color> {
+    (0..64)
+        .map(|i| {
+            Color::new(
+                (i % 4) as f64 / 3.0,
+                (i / 4 % 4) as f64 / 3.0,
+                (i / 16) as f64 / 3.0,
+                0.5,
+            )
+        })
+        .collect()
+}
+
+fn assert_same(a: &[Color], b: &[Color]) {
+    for (x, y) in a.iter().zip(b) {
+        for (p, q) in [(x.r, y.r), (x.g, y.g), (x.b, y.b), (x.a, y.a)] {
+            assert!((p - q).abs() < 1e-12, "{x:?} vs {y:?}");
+        }
+    }
+}
+
+#[test]
+fn every_path_applies_the_same_filter() {
+    let filter = PhotoFilter::from(WrattenFilter::Wratten85).with_strength(0.7);
+    let expected: Vec<Color> = pixels().iter().map(|c| filter.apply_color(c)).collect();
+
+    let mut direct = pixels();
+    filter.apply_slice(&mut direct);
+    assert_same(&direct, &expected);
+
+    let transform = filter.transform();
+    let prepared: Vec<Color> = pixels().iter().map(|c| transform.apply_color(c)).collect();
+    assert_same(&prepared, &expected);
+
+    let mut piped = pixels();
+    Pipeline::new().then(filter).apply_slice(&mut piped);
+    assert_same(&piped, &expected);
+}
+
+#[test]
+fn pipeline_slices_match_per_pixel_steps() {
+    let pipeline = Pipeline::new()
+        .then(|rgb: [f64; 3]| rgb.map(|c| c * 0.5))
+        .then(PhotoFilter::from(WrattenFilter::Wratten80A))
+        .then(|rgb: [f64; 3]| [rgb[2], rgb[1], rgb[0]]);
+    let expected: Vec<Color> = pixels().iter().map(|c| pipeline.apply_color(c)).collect();
+    let mut piped = pixels();
+    pipeline.apply_slice(&mut piped);
+    assert_same(&piped, &expected);
+}
+
+#[test]
+fn zero_strength_is_identity() {
+    let filter = PhotoFilter::new(-131.0).with_strength(0.0);
+    let mut filtered = pixels();
+    filter.apply_slice(&mut filtered);
+    for (a, b) in filtered.iter().zip(pixels()) {
+        assert!(
+            (a.r - b.r).abs() < 1e-12 && (a.g - b.g).abs() < 1e-12 && (a.b - b.b).abs() < 1e-12
+        );
+    }
+}
+
+#[test]
+fn warming_filters_warm() {
+    let gray = Color::opaque(0.5, 0.5, 0.5);
+    let warm = PhotoFilter::from(WrattenFilter::Wratten85).apply_color(&gray);
+    let cool = PhotoFilter::from(WrattenFilter::Wratten80A).apply_color(&gray);
+    assert!(warm.r > warm.b);
+    assert!(cool.b > cool.r);
+}
@@ -0,0 +1,51 @@
+use colorlab::{Color, ColorSpace, GammaRgb, RgbPrimaries, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn custom_primaries_round_trip() {
+    // Adobe RGB (1998)
+    let primaries = RgbPrimaries::from_chromaticities(
+        [0.64, 0.33],
+        [0.21, 0.71],
+        [0.15, 0.06],
+        [0.3127, 0.3290],
+    );
+    for color in srgb_sample() {
+        let rgb = GammaRgb::from_color_with(&color, GammaRgb::GAMMA_22, primaries);
+        assert_same_color(&rgb.to_color(), &color, 1e-9);
+        let adobe = GammaRgb::from_color_with(&color, GammaRgb::GAMMA_22, RgbPrimaries::AdobeRgb);
+        assert!((rgb.r - adobe.r).abs() < 1e-4 && (rgb.b - adobe.b).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn matrix_primaries_round_trip() {
+    let primaries =
+        RgbPrimaries::from_matrix([[0.8, 0.2, 0.0], [0.1, 0.9, 0.0], [0.0, 0.05, 0.95]]);
+    for color in srgb_sample() {
+        let rgb = GammaRgb::from_color_with(&color, 1.8, primaries);
+        assert_same_color(&rgb.to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,114 @@
+use colorlab::colorspaces::colorspace::{ColorSpace, Gamut};
+use colorlab::colorspaces::oklch::Oklch;
+use colorlab::colorspaces::srgb::Srgb;
+use colorlab::gamut::{gamut_map, max_chroma, max_chroma_in};
+use colorlab::{Color, DisplayP3, Oklab};
+
+/// Colors on an Oklch grid reaching well outside sRGB and Display P3.
+fn oklch_grid() -> Vec<Color> {
+    let mut colors = Vec::new();
+    for l in 1..20 {
+        for c in 0..8 {
+            for h in (0..360).step_by(15) {
+                let color = Oklch::new(l as f64 / 20.0, c as f64 * 0.06, h as f64).to_color();
+                colors.push(Color { a: 0.5, ..color });
+            }
+        }
+    }
+    colors
+}
+
+#[test]
+fn max_chroma_matches_search() {
+    for l in 1..100 {
+        for h in (0..360).step_by(3) {
+            let (l, h) = (l as f64 / 100.0, h as f64);
+            let exact = max_chroma_in::<Srgb>(l, h);
+            let c = max_chroma(l, h);
+            assert!((c - exact).abs() < 1e-9, "l = {l}, h = {h}: {c} vs {exact}");
+        }
+    }
+}
+
+#[test]
+fn max_chroma_matches_search_near_blue() {
+    // Ottosson's analytic fit is furthest off here
+    for l in 40..60 {
+        for h in 255..275 {
+            let (l, h) = (l as f64 / 100.0, h as f64);
+            let exact = max_chroma_in::<Srgb>(l, h);
+            assert!((max_chroma(l, h) - exact).abs() < 1e-9, "l = {l}, h = {h}");
+        }
+    }
+}
+
+#[test]
+fn max_chroma_is_on_the_boundary() {
+    for (l, h) in [(0.3, 30.0), (0.49, 264.0), (0.7, 140.0), (0.9, 110.0)] {
+        let c = max_chroma(l, h);
+        assert!(Srgb::is_in_gamut(&Oklch::new(l, c, h).to_color()));
+        assert!(!Srgb::is_in_gamut(&Oklch::new(l, c + 1e-6, h).to_color()));
+    }
+}
+
+#[test]
+fn max_chroma_is_zero_at_black_and_white() {
+    for l in [-0.5, 0.0, 1.0, 1.5] {
+        assert_eq!(max_chroma(l, 120.0), 0.0);
+    }
+}
+
+#[test]
+fn gamut_map_lands_in_gamut() {
+    for color in oklch_grid() {
+        let srgb = gamut_map::<Srgb>(&color);
+        assert!(Srgb::is_in_gamut(&srgb), "{color:?} gave {srgb:?}");
+        assert_eq!(srgb.a, color.a);
+        let p3 = gamut_map::<DisplayP3>(&color);
+        assert!(DisplayP3::is_in_gamut(&p3), "{color:?} gave {p3:?}");
+    }
+}
+
+#[test]
+fn gamut_map_stays_near_the_chroma_line() {
+    // The result is within a just-noticeable ΔEOK (0.02) of a color with the original
+    // lightness and hue and no more chroma
+    for color in oklch_grid() {
+        let from = Oklab::from_color(&color);
+        let to = Oklab::from_color(&gamut_map::<Srgb>(&color));
+        if from.l <= 0.0 || from.l >= 1.0 {
+            continue;
+        }
+        let chroma = from.a.hypot(from.b);
+        let along = if chroma > 0.0 {
+            ((to.a * from.a + to.b * from.b) / (chroma * chroma)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let d = ((to.l - from.l).powi(2)
+            + (to.a - along * from.a).powi(2)
+            + (to.b - along * from.b).powi(2))
+        .sqrt();
+        assert!(d <= 0.02 + 1e-6, "{from:?} gave {to:?}, {d} from the line");
+    }
+}
+
+#[test]
+fn gamut_map_leaves_in_gamut_colors() {
+    for color in oklch_grid() {
+        if Srgb::is_in_gamut(&color) {
+            assert_eq!(gamut_map::<Srgb>(&color), color);
+        }
+    }
+}
+
+#[test]
+fn gamut_map_ends_at_black_and_white() {
+    let white = gamut_map::<Srgb>(&Oklch::new(1.2, 0.1, 30.0).to_color());
+    let black = gamut_map::<Srgb>(&Oklch::new(-0.1, 0.1, 30.0).to_color());
+    for (c, v) in [(white, 1.0), (black, 0.0)] {
+        for channel in [c.r, c.g, c.b] {
+            assert!((channel - v).abs() < 1e-9, "{c:?}");
+        }
+    }
+}
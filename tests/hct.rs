@@ -0,0 +1,55 @@
+use colorlab::{Color, ColorSpace, Hct, Lab, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn reference_values() {
+    // Material Color Utilities gives red (27.41, 113.36, 53.23) and blue
+    // (282.79, 87.23, 32.30); its viewing conditions and matrices are rounded
+    // differently, so the hue and chroma only agree to a few hundredths
+    for (color, [h, c, t]) in [
+        (Color::opaque(1.0, 0.0, 0.0), [27.41, 113.36, 53.23]),
+        (Color::opaque(0.0, 0.0, 1.0), [282.79, 87.23, 32.30]),
+    ] {
+        let hct = Hct::from_color(&color);
+        assert!((hct.h - h).abs() < 0.05, "{hct:?}");
+        assert!((hct.c - c).abs() < 0.05, "{hct:?}");
+        assert!((hct.t - t).abs() < 0.01, "{hct:?}");
+    }
+}
+
+#[test]
+fn tone_is_cielab_lightness() {
+    for color in srgb_sample() {
+        let (hct, lab) = (Hct::from_color(&color), Lab::from_color(&color));
+        assert!((hct.t - lab.l).abs() < 1e-9, "{color:?}");
+    }
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Hct::from_color(&color).to_color(), &color, 1e-7);
+    }
+}
@@ -0,0 +1,41 @@
+use colorlab::{Color, ColorSpace, Hpluv, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn reference_values() {
+    // The HSLuv reference implementation's snapshot for #ff0000; saturated colors
+    // exceed 100 since HPLuv only keeps pastels within range
+    let hpluv = Hpluv::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    assert!((hpluv.h - 12.177050630061776).abs() < 1e-9, "{hpluv:?}");
+    assert!((hpluv.p - 426.7467891831338).abs() < 1e-9, "{hpluv:?}");
+    assert!((hpluv.l - 53.23711559542933).abs() < 1e-9, "{hpluv:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Hpluv::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,48 @@
+use colorlab::{Color, ColorSpace, HunterLab, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn reference_values() {
+    let white = HunterLab::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    assert!((white.l - 100.0).abs() < 1e-9, "{white:?}");
+    assert!(white.a.abs() < 1e-9 && white.b.abs() < 1e-9, "{white:?}");
+    // L = 100 √Y, with Y = 0.2126 for sRGB red
+    let red = HunterLab::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    assert!((red.l - 46.11).abs() < 0.01, "{red:?}");
+    assert!((red.a - 82.70).abs() < 0.01, "{red:?}");
+    assert!((red.b - 28.34).abs() < 0.01, "{red:?}");
+    let black = HunterLab::from_color(&Color::opaque(0.0, 0.0, 0.0));
+    assert_eq!((black.l, black.a, black.b), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        if color.r + color.g + color.b == 0.0 {
+            continue;
+        }
+        assert_same_color(&HunterLab::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,54 @@
+use colorlab::hdr::LuminanceContext;
+use colorlab::{Color, ColorSpace, Ictcp, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn reference_white_is_neutral() {
+    // BT.2408 reference white (203 nits) has a PQ signal of about 58%
+    let white = Ictcp::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    assert!((white.i - 0.5807).abs() < 1e-4, "{white:?}");
+    assert!(
+        white.ct.abs() < 1e-12 && white.cp.abs() < 1e-12,
+        "{white:?}"
+    );
+}
+
+#[test]
+fn grays_are_neutral() {
+    for v in [0.01, 0.2, 0.5, 2.0] {
+        let gray = Ictcp::from_color(&Color::opaque(v, v, v));
+        assert!(gray.ct.abs() < 1e-12 && gray.cp.abs() < 1e-12, "{gray:?}");
+    }
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Ictcp::from_color(&color).to_color(), &color, 1e-9);
+        let sdr = LuminanceContext::SDR;
+        let back = Ictcp::from_color_with(&color, &sdr).to_color_with(&sdr);
+        assert_same_color(&back, &color, 1e-9);
+    }
+}
@@ -0,0 +1,77 @@
+use colorlab::mix::mix;
+use colorlab::{Color, ColorSpace, Interpolate, Lab, LabD50, Lch, LinearAdobeRgb, LuvD50, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn srgb_grays_are_neutral() {
+    for v in [0.0, 0.2, 0.5, 1.0, 2.0] {
+        let lab = Lab::from_color(&Color::opaque(v, v, v));
+        assert!(lab.a.abs() < 1e-12 && lab.b.abs() < 1e-12, "{v}: {lab:?}");
+        assert!(Lch::from_color(&Color::opaque(v, v, v)).hue_is_powerless());
+    }
+    let white = Lab::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    assert!((white.l - 100.0).abs() < 1e-12);
+}
+
+#[test]
+fn faint_colors_keep_their_hue() {
+    assert!(!Lch::new(50.0, 0.01, 30.0).hue_is_powerless());
+}
+
+#[test]
+fn linear_adobe_rgb_mixes_linearly() {
+    let black = Color::opaque(0.0, 0.0, 0.0);
+    let red = LinearAdobeRgb::new(1.0, 0.0, 0.0).to_color();
+    let mixed = LinearAdobeRgb::from_color(&mix::<LinearAdobeRgb>(&black, &red, 0.25));
+    assert!((mixed.r - 0.25).abs() < 1e-12, "{mixed:?}");
+    assert!(mixed.g.abs() < 1e-12 && mixed.b.abs() < 1e-12, "{mixed:?}");
+}
+
+#[test]
+fn d50_reference_values() {
+    // CSS Color 4: rgb(100% 0% 0%) is lab(54.29% 80.82 69.89)
+    let red = Color::opaque(1.0, 0.0, 0.0);
+    let lab = LabD50::from_color(&red);
+    assert!((lab.l - 54.29).abs() < 0.01, "{lab:?}");
+    assert!((lab.a - 80.82).abs() < 0.01, "{lab:?}");
+    assert!((lab.b - 69.89).abs() < 0.01, "{lab:?}");
+    // L* is the same in both spaces
+    let luv = LuvD50::from_color(&red);
+    assert!((luv.l - lab.l).abs() < 1e-9, "{luv:?}");
+    let white = Color::opaque(1.0, 1.0, 1.0);
+    let (lab, luv) = (LabD50::from_color(&white), LuvD50::from_color(&white));
+    assert!((lab.l - 100.0).abs() < 1e-9 && lab.a.abs() < 1e-9 && lab.b.abs() < 1e-9);
+    assert!((luv.l - 100.0).abs() < 1e-9 && luv.u.abs() < 1e-9 && luv.v.abs() < 1e-9);
+}
+
+#[test]
+fn d50_round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&LabD50::from_color(&color).to_color(), &color, 1e-9);
+        if color.r + color.g + color.b > 0.0 {
+            assert_same_color(&LuvD50::from_color(&color).to_color(), &color, 1e-9);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use colorlab::{Color, ColorSpace, LinearSrgb, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn matches_the_hub() {
+    let color = Color::new(-0.5, 0.25, 2.0, 0.5);
+    let linear = LinearSrgb::from_color(&color);
+    assert_eq!(
+        (linear.r, linear.g, linear.b, linear.a),
+        (-0.5, 0.25, 2.0, 0.5)
+    );
+    assert_eq!(linear.to_color(), color);
+}
+
+#[test]
+fn converts_with_srgb() {
+    // sRGB 0.5 decodes to 0.2140 and the toe stays linear
+    let linear = LinearSrgb::from(Srgb::new(0.5, 0.02, 1.0));
+    assert!((linear.r - 0.21404114048223255).abs() < 1e-12, "{linear:?}");
+    assert!((linear.g - 0.02 / 12.92).abs() < 1e-12, "{linear:?}");
+    assert!((linear.b - 1.0).abs() < 1e-12, "{linear:?}");
+    let srgb = Srgb::from(linear);
+    assert!((srgb.r - 0.5).abs() < 1e-12 && (srgb.g - 0.02).abs() < 1e-12);
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&LinearSrgb::from_color(&color).to_color(), &color, 1e-12);
+        let srgb = Srgb::from_color(&color);
+        assert_same_color(
+            &Srgb::from(LinearSrgb::from(srgb)).to_color(),
+            &color,
+            1e-12,
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use colorlab::{Color, ColorSpace, ConeFundamentals, Lms, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+const FUNDAMENTALS: [ConeFundamentals; 4] = [
+    ConeFundamentals::HuntPointerEstevez,
+    ConeFundamentals::Cat02,
+    ConeFundamentals::Cat16,
+    ConeFundamentals::StockmanSharpe,
+];
+
+fn assert_lms(lms: Lms, [l, m, s]: [f64; 3], tolerance: f64) {
+    for (got, want) in [(lms.l, l), (lms.m, m), (lms.s, s)] {
+        assert!((got - want).abs() < tolerance, "{lms:?}");
+    }
+}
+
+#[test]
+fn d65_white_reference_values() {
+    // The CIECAM02 and CAM16 matrices applied to the hub white, D65 (0.95046, 1, 1.08906)
+    let white = Color::opaque(1.0, 1.0, 1.0);
+    let hpe = Lms::from_color_with(&white, ConeFundamentals::HuntPointerEstevez);
+    assert_lms(hpe, [0.97370, 1.01552, 1.08906], 1e-4);
+    let cat02 = Lms::from_color_with(&white, ConeFundamentals::Cat02);
+    assert_lms(cat02, [0.94923, 1.03540, 1.08743], 1e-4);
+}
+
+#[test]
+fn converts_between_fundamentals() {
+    let color = Color::opaque(0.2, 0.6, 0.1);
+    for from in FUNDAMENTALS {
+        for to in FUNDAMENTALS {
+            let lms = Lms::from_color_with(&color, from).to_fundamentals(to);
+            assert_eq!(lms.fundamentals, to);
+            assert_same_color(&lms.to_color(), &color, 1e-6);
+        }
+    }
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        for fundamentals in FUNDAMENTALS {
+            let lms = Lms::from_color_with(&color, fundamentals);
+            assert_same_color(&lms.to_color(), &color, 1e-6);
+        }
+    }
+}
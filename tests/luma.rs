@@ -0,0 +1,74 @@
+use colorlab::{Color, ColorSpace, Luma, LumaDerivation, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+const DERIVATIONS: [LumaDerivation; 4] = [
+    LumaDerivation::Rec709,
+    LumaDerivation::Rec601,
+    LumaDerivation::CieY,
+    LumaDerivation::Average,
+];
+
+#[test]
+fn reference_values() {
+    let red = Color::opaque(1.0, 0.0, 0.0);
+    for (derivation, l) in [
+        (LumaDerivation::Rec709, 0.2126),
+        (LumaDerivation::Rec601, 0.299),
+        (LumaDerivation::CieY, 0.2126),
+        (LumaDerivation::Average, 1.0 / 3.0),
+    ] {
+        let luma = Luma::from_color_with(&red, derivation);
+        assert!((luma.l - l).abs() < 1e-4, "{luma:?}");
+        assert_eq!(luma.derivation, derivation);
+    }
+    // Rec.601 works on encoded values, so an sRGB gray keeps its encoded level
+    let gray = Srgb::new(0.5, 0.5, 0.5).to_color();
+    let luma = Luma::from_color_with(&gray, LumaDerivation::Rec601);
+    assert!((luma.l - 0.5).abs() < 1e-12, "{luma:?}");
+}
+
+#[test]
+fn grays_round_trip() {
+    for color in srgb_sample() {
+        if color.r != color.g || color.g != color.b {
+            continue;
+        }
+        for derivation in DERIVATIONS {
+            let luma = Luma::from_color_with(&color, derivation);
+            assert_same_color(&luma.to_color(), &color, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn reduces_colors_to_gray() {
+    for color in srgb_sample() {
+        for derivation in DERIVATIONS {
+            let gray = Luma::from_color_with(&color, derivation).to_color();
+            assert!(gray.r == gray.g && gray.g == gray.b, "{gray:?}");
+            assert_eq!(gray.a, color.a);
+        }
+    }
+}
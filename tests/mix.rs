@@ -0,0 +1,48 @@
+use colorlab::mix::HueInterpolation;
+
+#[test]
+fn shorter_takes_the_arc_of_at_most_180() {
+    let hue = HueInterpolation::Shorter;
+    assert_eq!(hue.delta(30.0, 60.0), 30.0);
+    assert_eq!(hue.delta(60.0, 30.0), -30.0);
+    assert_eq!(hue.delta(10.0, 300.0), -70.0);
+    assert_eq!(hue.delta(300.0, 10.0), 70.0);
+    assert_eq!(hue.delta(0.0, 180.0), 180.0);
+    assert_eq!(hue.delta(50.0, 50.0), 0.0);
+}
+
+#[test]
+fn longer_takes_the_arc_of_at_least_180() {
+    let hue = HueInterpolation::Longer;
+    assert_eq!(hue.delta(30.0, 60.0), -330.0);
+    assert_eq!(hue.delta(60.0, 30.0), 330.0);
+    assert_eq!(hue.delta(10.0, 300.0), 290.0);
+    assert_eq!(hue.delta(0.0, 180.0), 180.0);
+    // Equal hues go once around
+    assert_eq!(hue.delta(50.0, 50.0), 360.0);
+}
+
+#[test]
+fn increasing_and_decreasing_keep_their_direction() {
+    let (up, down) = (HueInterpolation::Increasing, HueInterpolation::Decreasing);
+    assert_eq!(up.delta(30.0, 60.0), 30.0);
+    assert_eq!(up.delta(60.0, 30.0), 330.0);
+    assert_eq!(up.delta(50.0, 50.0), 0.0);
+    assert_eq!(down.delta(30.0, 60.0), -330.0);
+    assert_eq!(down.delta(60.0, 30.0), -30.0);
+    assert_eq!(down.delta(0.0, 180.0), -180.0);
+    assert_eq!(down.delta(50.0, 50.0), 0.0);
+}
+
+#[test]
+fn delta_ignores_whole_turns() {
+    for hue in [
+        HueInterpolation::Shorter,
+        HueInterpolation::Longer,
+        HueInterpolation::Increasing,
+        HueInterpolation::Decreasing,
+    ] {
+        assert_eq!(hue.delta(30.0, 60.0), hue.delta(390.0, -300.0), "{hue:?}");
+        assert_eq!(hue.delta(10.0, 300.0), hue.delta(-350.0, 660.0), "{hue:?}");
+    }
+}
@@ -0,0 +1,164 @@
+use colorlab::colorspaces::xyy::Xyy;
+use colorlab::colorspaces::xyz::Xyz;
+use colorlab::white_point::WhitePoint;
+use colorlab::{ColorSpace, Munsell, MunsellRenotation, RenotationError};
+
+const FAMILIES: [&str; 10] = ["R", "YR", "Y", "GY", "G", "BG", "B", "PB", "P", "RP"];
+
+/// xyY (Illuminant C) of a Munsell color by the crate's approximation.
+fn approximate_xyy(hue: f64, value: f64, chroma: f64) -> Xyy {
+    let color = Munsell::new(hue, value, chroma).to_color();
+    let Xyz { x, y, z, .. } = Xyz::from_color_with(&color, WhitePoint::C);
+    Xyy::new(x / (x + y + z), y / (x + y + z), y)
+}
+
+/// A synthetic table in the renotation file format, generated from the approximation on
+/// a 2.5-step hue grid, values 1 to 9 and even chromas from 2 to twice the value, at
+/// most 12. Like the real-color renotation data, darker hues stop at lower chromas.
+fn synthetic_table() -> String {
+    let mut text = String::from("h V C x y Y\n");
+    for (i, family) in FAMILIES.iter().enumerate() {
+        for step in [2.5, 5.0, 7.5, 10.0] {
+            let hue = i as f64 * 10.0 + step;
+            for value in 1..=9 {
+                for chroma in (2..=(2 * value).min(12)).step_by(2) {
+                    let xyy = approximate_xyy(hue, value as f64, chroma as f64);
+                    text += &format!(
+                        "{step}{family} {value} {chroma} {} {} {}\n",
+                        xyy.x,
+                        xyy.y,
+                        xyy.luminance * 100.0
+                    );
+                }
+            }
+        }
+    }
+    text
+}
+
+fn table() -> MunsellRenotation {
+    MunsellRenotation::parse(&synthetic_table()).unwrap()
+}
+
+#[test]
+fn parses_every_row() {
+    assert_eq!(table().len(), 40 * (1 + 2 + 3 + 4 + 5 + 6 * 4));
+}
+
+#[test]
+fn reproduces_table_entries() {
+    let table = table();
+    for (hue, value, chroma) in [(2.5, 1.0, 2.0), (45.0, 5.0, 8.0), (100.0, 9.0, 12.0)] {
+        let expected = approximate_xyy(hue, value, chroma);
+        let xyy = table.xyy(&Munsell::new(hue, value, chroma)).unwrap();
+        assert!((xyy.x - expected.x).abs() < 1e-12);
+        assert!((xyy.y - expected.y).abs() < 1e-12);
+        // Y follows the value alone, as for the neutral of that value
+        let gray = Munsell::new(0.0, value, 0.0).to_color();
+        assert!((xyy.luminance - gray.luminance()).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn interpolates_between_entries() {
+    // Close to the smooth model the table was sampled from
+    let table = table();
+    for (hue, value, chroma) in [(3.7, 4.5, 5.0), (61.2, 5.3, 7.1), (98.9, 7.6, 3.3)] {
+        let expected = approximate_xyy(hue, value, chroma);
+        let xyy = table.xyy(&Munsell::new(hue, value, chroma)).unwrap();
+        assert!((xyy.x - expected.x).abs() < 0.01, "{hue} {value}/{chroma}");
+        assert!((xyy.y - expected.y).abs() < 0.01, "{hue} {value}/{chroma}");
+    }
+}
+
+#[test]
+fn neutrals_have_the_illuminant_c_chromaticity() {
+    let xyy = table().xyy(&Munsell::new(30.0, 5.0, 0.0)).unwrap();
+    assert!((xyy.x - 0.31006).abs() < 1e-12);
+    assert!((xyy.y - 0.31616).abs() < 1e-12);
+    let munsell = table().from_xyy(&xyy).unwrap();
+    assert_eq!(munsell.chroma, 0.0);
+    assert!((munsell.value - 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn inverts_interpolated_colors() {
+    let table = table();
+    for (hue, value, chroma) in [
+        (3.7, 4.5, 5.0),
+        (61.2, 5.3, 7.1),
+        (98.9, 7.6, 3.3),
+        (0.4, 6.0, 11.5),
+        (27.5, 8.0, 0.7),
+    ] {
+        let xyy = table.xyy(&Munsell::new(hue, value, chroma)).unwrap();
+        let munsell = table.from_xyy(&xyy).unwrap();
+        let dh = (munsell.hue - hue + 50.0).rem_euclid(100.0) - 50.0;
+        assert!(dh.abs() < 1e-6, "{hue} {value}/{chroma} gave {munsell:?}");
+        assert!((munsell.value - value).abs() < 1e-9);
+        assert!((munsell.chroma - chroma).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn round_trips_colors() {
+    let table = table();
+    let munsell = Munsell::with_alpha(52.0, 6.5, 4.0, 0.5);
+    let color = munsell.to_color_with(&table).unwrap();
+    let back = Munsell::from_color_with(&color, &table).unwrap();
+    assert!((back.hue - 52.0).abs() < 1e-6);
+    assert!((back.value - 6.5).abs() < 1e-9);
+    assert!((back.chroma - 4.0).abs() < 1e-6);
+    assert_eq!(back.alpha, 0.5);
+}
+
+#[test]
+fn is_undefined_outside_the_table() {
+    let table = table();
+    assert!(table.xyy(&Munsell::new(5.0, 5.0, 14.0)).is_none());
+    // The value 1 plane stops at chroma 2
+    assert!(table.xyy(&Munsell::new(5.0, 1.5, 3.0)).is_none());
+    assert!(table.xyy(&Munsell::new(5.0, 0.5, 2.0)).is_none());
+    assert!(table.xyy(&Munsell::new(5.0, 9.5, 2.0)).is_none());
+    let beyond = approximate_xyy(5.0, 5.0, 16.0);
+    assert!(table.from_xyy(&beyond).is_none());
+}
+
+#[test]
+fn rejects_malformed_rows() {
+    assert!(matches!(
+        MunsellRenotation::parse("h V C x y Y\n"),
+        Err(RenotationError::Empty)
+    ));
+    for row in [
+        "5Q 5 2 0.3 0.3 19.8",
+        "12R 5 2 0.3 0.3 19.8",
+        "5R 5 2 0.8 0.3 19.8",
+        "5R 5 0 0.3 0.3 19.8",
+        "5R 5 2 0.3",
+    ] {
+        let text = format!("5R 4 2 0.33 0.31 12.0\n{row}\n");
+        assert!(
+            matches!(
+                MunsellRenotation::parse(&text),
+                Err(RenotationError::InvalidRow { line: 2, .. })
+            ),
+            "{row}"
+        );
+    }
+    assert!(matches!(
+        MunsellRenotation::parse("5R 4 2 0.33 0.31 12.0\n5R 5 two 0.3 0.3 19.8\n"),
+        Err(RenotationError::InvalidRow { line: 2, .. })
+    ));
+}
+
+#[test]
+fn table_colors_are_adapted_from_illuminant_c() {
+    // A neutral chip lands on the hub's D65 gray axis, not at the Illuminant C white
+    let color = Munsell::new(0.0, 5.0, 0.0).to_color_with(&table()).unwrap();
+    assert!((color.r - color.g).abs() < 1e-9, "{color:?}");
+    assert!((color.b - color.g).abs() < 1e-9, "{color:?}");
+    let back = Munsell::from_color_with(&color, &table()).unwrap();
+    assert_eq!(back.chroma, 0.0);
+    assert!((back.value - 5.0).abs() < 1e-9);
+}
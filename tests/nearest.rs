@@ -0,0 +1,83 @@
+use colorlab::Color;
+use colorlab::distance::{Cie94Application, DistanceMetric};
+use colorlab::nearest::{NearestIndex, nearest};
+
+const METRICS: [DistanceMetric; 8] = [
+    DistanceMetric::DeltaE76,
+    DistanceMetric::DeltaE94(Cie94Application::GraphicArts),
+    DistanceMetric::DeltaE94(Cie94Application::Textiles),
+    DistanceMetric::DeltaE2000,
+    DistanceMetric::DeltaEOk,
+    DistanceMetric::HyAb,
+    DistanceMetric::Din99,
+    DistanceMetric::DeltaEItp,
+];
+
+/// `n` opaque colors from a fixed linear congruential sequence.
+fn colors(n: usize, seed: u64) -> Vec<Color> {
+    let mut state = seed;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    };
+    (0..n)
+        .map(|_| Color::opaque(next(), next(), next()))
+        .collect()
+}
+
+#[test]
+fn index_matches_scan() {
+    let candidates = colors(200, 1);
+    let queries = colors(300, 2);
+    for metric in METRICS {
+        let index = NearestIndex::new(&candidates, metric);
+        for query in &queries {
+            assert_eq!(
+                index.nearest(query),
+                nearest(query, &candidates, metric),
+                "{metric:?}, query {query:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn index_matches_scan_on_small_palettes() {
+    // Few, widely spaced candidates leave the nearest one far from most queries
+    let candidates = colors(6, 3);
+    let queries = colors(300, 4);
+    for metric in METRICS {
+        let index = NearestIndex::new(&candidates, metric);
+        for query in &queries {
+            assert_eq!(
+                index.nearest(query),
+                nearest(query, &candidates, metric),
+                "{metric:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn ties_go_to_the_earlier_candidate() {
+    let red = Color::opaque(1.0, 0.0, 0.0);
+    let candidates = [Color::opaque(0.0, 0.0, 1.0), red, red];
+    for metric in METRICS {
+        let index = NearestIndex::new(&candidates, metric);
+        assert_eq!(index.nearest(&red), Some(1), "{metric:?}");
+        assert_eq!(nearest(&red, &candidates, metric), Some(1), "{metric:?}");
+    }
+}
+
+#[test]
+fn empty_candidates_have_no_nearest() {
+    let query = Color::opaque(0.5, 0.5, 0.5);
+    for metric in METRICS {
+        let index = NearestIndex::new(&[], metric);
+        assert!(index.is_empty());
+        assert_eq!(index.nearest(&query), None);
+        assert_eq!(nearest(&query, &[], metric), None);
+    }
+}
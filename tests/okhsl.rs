@@ -0,0 +1,67 @@
+use colorlab::{Color, ColorSpace, Okhsl, Okhsv, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Srgb::new(r, g, b).to_color());
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn okhsl_grays_have_no_saturation() {
+    for v in [0.0, 0.01, 0.2, 0.5, 0.9, 0.99, 1.0] {
+        let hsl = Okhsl::from_color(&Srgb::new(v, v, v).to_color());
+        assert_eq!(hsl.s, 0.0, "gray {v}: {hsl:?}");
+        assert_eq!(hsl.h, 0.0);
+    }
+    let white = Okhsl::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    assert!((white.l - 1.0).abs() < 1e-7);
+}
+
+#[test]
+fn okhsl_reference_values() {
+    // Ottosson's reference implementation for sRGB red, with hue in degrees
+    let hsl = Okhsl::from_color(&Srgb::new(1.0, 0.0, 0.0).to_color());
+    assert!((hsl.h - 29.2338851923426).abs() < 1e-6, "{hsl:?}");
+    assert!((hsl.s - 1.0).abs() < 1e-6, "{hsl:?}");
+    assert!((hsl.l - 0.5680846525040862).abs() < 1e-6, "{hsl:?}");
+}
+
+#[test]
+fn okhsv_grays_have_no_saturation() {
+    for v in [0.0, 0.01, 0.2, 0.5, 0.9, 0.99, 1.0] {
+        let hsv = Okhsv::from_color(&Srgb::new(v, v, v).to_color());
+        assert_eq!(hsv.s, 0.0, "gray {v}: {hsv:?}");
+    }
+}
+
+#[test]
+fn okhsv_reference_values() {
+    let hsv = Okhsv::from_color(&Srgb::new(1.0, 0.0, 0.0).to_color());
+    assert!((hsv.h - 29.2338851923426).abs() < 1e-6);
+    assert!((hsv.s - 0.9995219692256989).abs() < 1e-6);
+    assert!((hsv.v - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn round_trips_srgb() {
+    // Grays snap to zero saturation and come back on Oklab's a = b = 0 axis, which misses
+    // the sRGB neutral axis by up to about 1e-7 because of Oklab's published matrices
+    for color in srgb_sample() {
+        assert_same_color(&Okhsl::from_color(&color).to_color(), &color, 1e-6);
+        assert_same_color(&Okhsv::from_color(&color).to_color(), &color, 1e-6);
+    }
+}
@@ -0,0 +1,22 @@
+use colorlab::{Color, ColorSpace, Oklab};
+
+fn assert_same_rgb(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn round_trips_linear_srgb() {
+    // The LMS to blue row once had +0.0041960863 for L, which brought gray 0.5 back with
+    // b = 0.5042
+    let steps = [0.0, 0.25, 0.5, 0.75, 1.0];
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                let color = Color::opaque(r, g, b);
+                assert_same_rgb(&Oklab::from_color(&color).to_color(), &color, 1e-6);
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use colorlab::parse::{Interpretation, ParseError, ParseWarning, parse_lenient};
+
+fn assert_rgb(input: &str, expected: [f64; 4]) {
+    let c = parse_lenient(input).unwrap().color;
+    for (got, want) in [c.r, c.g, c.b, c.a].into_iter().zip(expected) {
+        assert!((got - want).abs() < 1e-9, "{input}: {c:?}");
+    }
+}
+
+#[test]
+fn css_input_is_fully_confident() {
+    for (input, interpretation) in [
+        ("#ff8000", Interpretation::Hex),
+        ("rgb(255, 128, 0)", Interpretation::Rgb255),
+        ("rgb(1.0 0.5 0.0)", Interpretation::RgbUnit),
+        ("rgb(100%, 50%, 0%)", Interpretation::RgbPercent),
+        ("hsl(30, 100%, 50%)", Interpretation::Hsl),
+    ] {
+        let parsed = parse_lenient(input).unwrap();
+        assert_eq!(parsed.interpretation, interpretation, "{input}");
+        assert_eq!(parsed.confidence, 1.0, "{input}");
+        assert!(parsed.warnings.is_empty(), "{input}");
+    }
+    assert_rgb("#ff8000", [1.0, 128.0 / 255.0, 0.0, 1.0]);
+    assert_rgb("#f80c", [1.0, 136.0 / 255.0, 0.0, 0.8]);
+    assert_rgb("hsl(30, 100%, 50%)", [1.0, 0.5, 0.0, 1.0]);
+    assert_rgb("rgb(255 0 0 / 50%)", [1.0, 0.0, 0.0, 0.5]);
+}
+
+#[test]
+fn each_guess_gives_its_warning() {
+    for (input, warning) in [
+        ("ff8000", ParseWarning::MissingHash),
+        ("0xff8000", ParseWarning::NonCssHexPrefix),
+        ("rgb 255, 128, 0", ParseWarning::MissingParentheses),
+        ("rgb(255, 128, 0", ParseWarning::MissingParentheses),
+        ("255, 128, 0", ParseWarning::MissingFunctionName),
+        ("rgb(255, 128 0)", ParseWarning::MixedSeparators),
+        ("rgb(255;128;0)", ParseWarning::NonCssSeparator),
+        ("rgb(1 0 0)", ParseWarning::AmbiguousScale),
+        (
+            "rgb(300, 0, 0)",
+            ParseWarning::ClampedComponent {
+                index: 0,
+                value: 300.0,
+            },
+        ),
+    ] {
+        let parsed = parse_lenient(input).unwrap();
+        assert_eq!(parsed.warnings, vec![warning], "{input}");
+        assert!(parsed.confidence < 1.0, "{input}");
+    }
+}
+
+#[test]
+fn semicolons_separate_like_commas() {
+    let parsed = parse_lenient("255; 128; 0").unwrap();
+    assert_eq!(
+        parsed.warnings,
+        vec![
+            ParseWarning::MissingFunctionName,
+            ParseWarning::NonCssSeparator
+        ]
+    );
+    assert_eq!(parsed.interpretation, Interpretation::Rgb255);
+    assert_rgb("255; 128; 0", [1.0, 128.0 / 255.0, 0.0, 1.0]);
+    // Mixing them with commas is still consistent; mixing with bare spaces is not
+    assert!(
+        !parse_lenient("rgb(255, 128; 0)")
+            .unwrap()
+            .warnings
+            .contains(&ParseWarning::MixedSeparators)
+    );
+    assert!(
+        parse_lenient("rgb(255; 128 0)")
+            .unwrap()
+            .warnings
+            .contains(&ParseWarning::MixedSeparators)
+    );
+}
+
+#[test]
+fn warnings_multiply_the_confidence() {
+    let one = parse_lenient("ff8000").unwrap().confidence;
+    let two = parse_lenient("255 128 0").unwrap().confidence;
+    let both = parse_lenient("255; 128 0").unwrap();
+    assert!(one < 1.0 && two < 1.0);
+    assert_eq!(both.warnings.len(), 3);
+    assert!(both.confidence < two);
+}
+
+#[test]
+fn integer_zeros_and_ones_read_on_the_unit_scale() {
+    let parsed = parse_lenient("1 0 0").unwrap();
+    assert_eq!(parsed.interpretation, Interpretation::RgbUnit);
+    assert!(parsed.warnings.contains(&ParseWarning::AmbiguousScale));
+    assert_rgb("1 0 0", [1.0, 0.0, 0.0, 1.0]);
+    // All zeros read the same on either scale
+    let black = parse_lenient("rgb(0, 0, 0)").unwrap();
+    assert!(black.warnings.is_empty());
+}
+
+#[test]
+fn values_above_one_read_on_the_byte_scale() {
+    let parsed = parse_lenient("100 100 100").unwrap();
+    assert_eq!(parsed.interpretation, Interpretation::Rgb255);
+    assert_eq!(parsed.warnings, vec![ParseWarning::MissingFunctionName]);
+    assert_rgb(
+        "100 100 100",
+        [100.0 / 255.0, 100.0 / 255.0, 100.0 / 255.0, 1.0],
+    );
+    // Fractions read on the unit scale without a warning
+    assert_eq!(
+        parse_lenient("rgb(0.5, 1, 0)").unwrap().interpretation,
+        Interpretation::RgbUnit
+    );
+}
+
+#[test]
+fn rejects_what_cannot_be_a_color() {
+    for (input, error) in [
+        ("", ParseError::Empty),
+        ("   ", ParseError::Empty),
+        ("cmyk(0, 0, 0, 0)", ParseError::UnrecognizedFormat),
+        ("#ggg", ParseError::UnrecognizedFormat),
+        ("#12345", ParseError::InvalidHexLength(5)),
+        ("rgb(1, 2)", ParseError::WrongComponentCount(2)),
+        ("rgb(1, 2, 3, 4, 5)", ParseError::WrongComponentCount(5)),
+        ("rgb(1, x, 3)", ParseError::InvalidNumber("x".into())),
+        ("rgb(1, inf, 3)", ParseError::InvalidNumber("inf".into())),
+    ] {
+        assert_eq!(parse_lenient(input), Err(error), "{input:?}");
+    }
+}
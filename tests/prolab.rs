@@ -0,0 +1,24 @@
+use colorlab::{Color, ColorSpace, ProLab};
+
+#[test]
+fn white_is_neutral() {
+    let white = ProLab::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    assert!((white.l - 100.0).abs() < 1e-12, "{white:?}");
+    assert!(white.a.abs() < 1e-12 && white.b.abs() < 1e-12, "{white:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    for r in 0..=5 {
+        for g in 0..=5 {
+            for b in 0..=5 {
+                let color = Color::new(r as f64 / 5.0, g as f64 / 5.0, b as f64 / 5.0, 0.5);
+                let back = ProLab::from_color(&color).to_color();
+                for (x, y) in [(back.r, color.r), (back.g, color.g), (back.b, color.b)] {
+                    assert!((x - y).abs() < 1e-12, "{color:?} gave {back:?}");
+                }
+                assert_eq!(back.a, 0.5);
+            }
+        }
+    }
+}
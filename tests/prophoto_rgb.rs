@@ -0,0 +1,45 @@
+use colorlab::{Color, ColorSpace, ProPhotoRgb, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn reference_values() {
+    // CSS Color 4 gives sRGB red as color(prophoto-rgb 0.70225 0.27572 0.10355)
+    let red = ProPhotoRgb::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    for (got, want) in [(red.r, 0.70225), (red.g, 0.27572), (red.b, 0.10355)] {
+        assert!((got - want).abs() < 1e-5, "{red:?}");
+    }
+    // D65 white adapts to the D50 white
+    let white = ProPhotoRgb::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    for v in [white.r, white.g, white.b] {
+        assert!((v - 1.0).abs() < 1e-9, "{white:?}");
+    }
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&ProPhotoRgb::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
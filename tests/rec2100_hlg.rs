@@ -0,0 +1,56 @@
+use colorlab::hdr::{LuminanceContext, hlg_inverse_oetf, hlg_oetf, hlg_system_gamma};
+use colorlab::{Color, ColorSpace, Rec2100Hlg, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn oetf_reference_values() {
+    // BT.2100: sqrt(3 E) up to 1/12, then a ln(12 E - b) + c
+    assert!((hlg_oetf(1.0 / 12.0) - 0.5).abs() < 1e-12);
+    assert!((hlg_oetf(1.0) - 1.0).abs() < 1e-7);
+    for e in [0.0, 0.01, 1.0 / 12.0, 0.5, 1.0] {
+        assert!((hlg_inverse_oetf(hlg_oetf(e)) - e).abs() < 1e-12);
+    }
+    // System gamma is 1.2 on the 1000 nit reference display
+    assert!((hlg_system_gamma(1000.0) - 1.2).abs() < 1e-12);
+}
+
+#[test]
+fn reference_white_is_the_75_percent_level() {
+    // BT.2408: 203 nit diffuse white on a 1000 nit display encodes to 75%
+    let white = Rec2100Hlg::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    for v in [white.r, white.g, white.b] {
+        assert!((v - 0.75).abs() < 1e-3, "{white:?}");
+    }
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Rec2100Hlg::from_color(&color).to_color(), &color, 1e-9);
+        let sdr = LuminanceContext::new(100.0, 400.0);
+        let back = Rec2100Hlg::from_color_with(&color, &sdr).to_color_with(&sdr);
+        assert_same_color(&back, &color, 1e-9);
+    }
+}
@@ -0,0 +1,51 @@
+use colorlab::{Color, ColorSpace, Rec601, Rec709, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn shares_the_bt709_oetf() {
+    // Grays do not depend on the primaries, so only the transfer function shows
+    for v in [0.01, 0.18, 0.5, 1.0] {
+        let gray = Color::opaque(v, v, v);
+        let (sd, hd) = (Rec601::from_color(&gray), Rec709::from_color(&gray));
+        assert!(
+            (sd.r - hd.r).abs() < 1e-9 && (sd.b - hd.b).abs() < 1e-9,
+            "{v}"
+        );
+    }
+}
+
+#[test]
+fn smpte_c_primaries_are_narrower_than_srgb() {
+    // sRGB red lies outside SMPTE-C
+    let red = Rec601::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    assert!(red.r > 1.0 && red.g < 0.0, "{red:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Rec601::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,42 @@
+use colorlab::{Color, ColorSpace, Rec709, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn oetf_reference_values() {
+    // BT.709: 4.5 L below 0.018, 1.099 L^0.45 - 0.099 above
+    let signal = Rec709::from_color(&Color::opaque(0.018, 0.5, 1.0));
+    assert!((signal.r - 0.081).abs() < 1e-9, "{signal:?}");
+    assert!((signal.g - 0.7054355530556183).abs() < 1e-9, "{signal:?}");
+    assert!((signal.b - 1.0).abs() < 1e-9, "{signal:?}");
+    let toe = Rec709::from_color(&Color::opaque(0.01, 0.01, 0.01));
+    assert!((toe.r - 0.045).abs() < 1e-12);
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Rec709::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,56 @@
+use colorlab::{Color, ColorSpace, Ryb, Srgb};
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+fn assert_srgb(color: &Color, [r, g, b]: [f64; 3]) {
+    let srgb = Srgb::from_color(color);
+    for (got, want) in [(srgb.r, r), (srgb.g, g), (srgb.b, b)] {
+        assert!((got - want).abs() < 1e-9, "{srgb:?}");
+    }
+}
+
+#[test]
+fn corners_match_gossett_and_chen() {
+    assert_srgb(&Ryb::new(0.0, 0.0, 0.0).to_color(), [1.0, 1.0, 1.0]);
+    assert_srgb(&Ryb::new(1.0, 0.0, 0.0).to_color(), [1.0, 0.0, 0.0]);
+    assert_srgb(&Ryb::new(0.0, 1.0, 0.0).to_color(), [1.0, 1.0, 0.0]);
+    assert_srgb(&Ryb::new(0.0, 0.0, 1.0).to_color(), [0.163, 0.373, 0.6]);
+    assert_srgb(&Ryb::new(1.0, 1.0, 0.0).to_color(), [1.0, 0.5, 0.0]);
+    assert_srgb(&Ryb::new(0.0, 1.0, 1.0).to_color(), [0.0, 0.66, 0.2]);
+    assert_srgb(&Ryb::new(1.0, 0.0, 1.0).to_color(), [0.5, 0.0, 0.5]);
+    assert_srgb(&Ryb::new(1.0, 1.0, 1.0).to_color(), [0.2, 0.094, 0.0]);
+}
+
+#[test]
+fn round_trips_the_cube_image() {
+    // Colors the cube can produce come back as the same color
+    let steps = [0.0, 0.25, 0.5, 0.75, 1.0];
+    for r in steps {
+        for y in steps {
+            for b in steps {
+                let color = Ryb::with_alpha(r, y, b, 0.5).to_color();
+                let back = Ryb::from_color(&color);
+                assert_eq!(back.alpha, 0.5);
+                assert_same_color(&back.to_color(), &color, 1e-6);
+            }
+        }
+    }
+}
+
+#[test]
+fn primaries_invert_exactly() {
+    for (srgb, ryb) in [
+        ([1.0, 1.0, 1.0], [0.0, 0.0, 0.0]),
+        ([1.0, 0.0, 0.0], [1.0, 0.0, 0.0]),
+        ([1.0, 1.0, 0.0], [0.0, 1.0, 0.0]),
+    ] {
+        let back = Ryb::from_color(&Srgb::new(srgb[0], srgb[1], srgb[2]).to_color());
+        for (got, want) in [(back.r, ryb[0]), (back.y, ryb[1]), (back.b, ryb[2])] {
+            assert!((got - want).abs() < 1e-6, "{srgb:?} gave {back:?}");
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use colorlab::hdr::LuminanceContext;
+use colorlab::{Color, ColorSpace, ScRgb, Srgb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn extended_values_pass_through() {
+    let hdr = Color::new(-0.25, 3.0, 7.5, 0.5);
+    let scrgb = ScRgb::from_color(&hdr);
+    assert_eq!((scrgb.r, scrgb.g, scrgb.b, scrgb.a), (-0.25, 3.0, 7.5, 0.5));
+    assert_eq!(scrgb.to_color(), hdr);
+}
+
+#[test]
+fn unit_is_80_nits() {
+    // With diffuse white at 203 nits, scRGB 1.0 is 80 / 203 of it
+    let context = LuminanceContext::new(203.0, 1000.0);
+    let unit = ScRgb::new(1.0, 1.0, 1.0).to_color_with(&context);
+    assert!((unit.g - 80.0 / 203.0).abs() < 1e-12, "{unit:?}");
+    let white = ScRgb::from_color_with(&Color::opaque(1.0, 1.0, 1.0), &context);
+    assert!((white.g - 203.0 / 80.0).abs() < 1e-12, "{white:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    let context = LuminanceContext::new(203.0, 1000.0);
+    for color in srgb_sample() {
+        assert_same_color(&ScRgb::from_color(&color).to_color(), &color, 1e-12);
+        let back = ScRgb::from_color_with(&color, &context).to_color_with(&context);
+        assert_same_color(&back, &color, 1e-12);
+    }
+}
@@ -0,0 +1,29 @@
+use colorlab::spectral::Illuminant;
+use colorlab::white_point::{Observer, WhitePoint};
+
+fn chromaticity([x, y, z]: [f64; 3]) -> [f64; 2] {
+    [x / (x + y + z), y / (x + y + z)]
+}
+
+#[test]
+fn daylight_whites_match_the_named_illuminants() {
+    for (kelvin, white) in [(5000.0, WhitePoint::D50), (6500.0, WhitePoint::D65)] {
+        let [x, y] = WhitePoint::daylight(kelvin).chromaticity(Observer::Cie1931);
+        let [wx, wy] = white.chromaticity(Observer::Cie1931);
+        // 3 K off moves x by about 1e-4
+        assert!((x - wx).abs() < 3e-5 && (y - wy).abs() < 2e-4, "{kelvin} K");
+    }
+}
+
+#[test]
+fn daylight_spectra_have_their_white_chromaticity() {
+    // Integrating at 10 nm leaves y about 1e-4 off, as for the tabulated D65
+    for (illuminant, white) in [
+        (Illuminant::D50, WhitePoint::D50),
+        (Illuminant::D65, WhitePoint::D65),
+    ] {
+        let [x, y] = chromaticity(illuminant.white());
+        let [wx, wy] = white.chromaticity(Observer::Cie1931);
+        assert!((x - wx).abs() < 5e-5 && (y - wy).abs() < 2e-4, "{x}, {y}");
+    }
+}
@@ -0,0 +1,92 @@
+use colorlab::hdr::LuminanceContext;
+use colorlab::tonemap::{FilmicCurve, SoftClip};
+
+const PRESETS: [FilmicCurve; 4] = [
+    FilmicCurve::NEUTRAL,
+    FilmicCurve::FILMIC,
+    FilmicCurve::HIGH_CONTRAST,
+    FilmicCurve::LOW_CONTRAST,
+];
+
+const CONTEXTS: [LuminanceContext; 2] = [LuminanceContext::SDR, LuminanceContext::BT2408];
+
+/// Scene values from 0 to past every preset's white point, 1e-3 apart.
+fn scene() -> impl Iterator<Item = f64> {
+    (0..=20_000).map(|i| i as f64 * 1e-3)
+}
+
+#[test]
+fn filmic_curves_are_monotonic_and_continuous() {
+    for curve in PRESETS {
+        for context in CONTEXTS {
+            let mut last = curve.eval_with(0.0, &context);
+            for x in scene().skip(1) {
+                let y = curve.eval_with(x, &context);
+                assert!(y >= last, "{curve:?} in {context:?} falls at {x}");
+                // The steepest preset rises by well under 0.01 per step
+                assert!(y - last < 0.01, "{curve:?} in {context:?} jumps at {x}");
+                last = y;
+            }
+        }
+    }
+}
+
+#[test]
+fn filmic_curves_span_black_to_peak() {
+    for curve in PRESETS {
+        for context in CONTEXTS {
+            assert_eq!(curve.eval_with(0.0, &context), 0.0);
+            assert_eq!(curve.eval_with(-1.0, &context), 0.0);
+            let peak = context.peak_linear();
+            let white = curve.eval_with(curve.white_point, &context);
+            assert!((white - peak).abs() < 1e-9, "{curve:?} in {context:?}");
+            assert!((curve.eval_with(1e6, &context) - peak).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn filmic_eval_is_the_sdr_curve() {
+    for curve in PRESETS {
+        for x in scene().step_by(97) {
+            assert_eq!(curve.eval(x), curve.eval_with(x, &LuminanceContext::SDR));
+        }
+    }
+}
+
+#[test]
+fn filmic_neutral_is_identity_below_the_shoulder() {
+    for x in [0.0, 0.1, 0.18, 0.5, 0.8] {
+        assert!((FilmicCurve::NEUTRAL.eval(x) - x).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn filmic_shoulder_survives_a_white_point_below_the_start() {
+    let curve = FilmicCurve {
+        white_point: 0.5,
+        ..FilmicCurve::NEUTRAL
+    };
+    // Steep, since the roll-off reaches display white by scene 0.5, but not a step
+    let mut last = 0.0;
+    for x in scene().take(1000) {
+        let y = curve.eval(x);
+        assert!(y >= last && y - last < 0.02, "jumps at {x}");
+        last = y;
+    }
+    assert_eq!(curve.eval(0.5), 1.0);
+}
+
+#[test]
+fn soft_clip_is_monotonic_and_below_the_peak() {
+    let clip = SoftClip::default();
+    for context in CONTEXTS {
+        let peak = context.peak_linear();
+        let mut last = clip.eval_with(0.0, &context);
+        for x in scene().skip(1) {
+            let y = clip.eval_with(x, &context);
+            assert!(y >= last && y < peak, "{context:?} at {x}");
+            last = y;
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use colorlab::{Color, ColorSpace, Srgb, Tsl};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn reference_values() {
+    // Red: r' = 2/3, g' = -1/3, so S = 1 and T = atan2(r', g') / 2π + 1/4
+    let red = Tsl::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    let t = (2.0f64).atan2(-1.0) / std::f64::consts::TAU + 0.25;
+    assert!(
+        (red.t - t).abs() < 1e-12 && (red.t - 0.57379).abs() < 1e-5,
+        "{red:?}"
+    );
+    assert!((red.s - 1.0).abs() < 1e-12, "{red:?}");
+    assert!((red.l - 0.299).abs() < 1e-12, "{red:?}");
+    let green = Tsl::from_color(&Color::opaque(0.0, 1.0, 0.0));
+    assert!((green.s - 1.0).abs() < 1e-12 && (green.l - 0.587).abs() < 1e-12);
+    let gray = Tsl::from_color(&Color::opaque(0.5, 0.5, 0.5));
+    assert!(
+        gray.s.abs() < 1e-12 && (gray.l - 0.5).abs() < 1e-12,
+        "{gray:?}"
+    );
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Tsl::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,63 @@
+use colorlab::{Color, ColorSpace, Srgb, Uv1960, UvPrime, Xyy};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn d65_white_reference_values() {
+    // u = 4x / (-2x + 12y + 3), v = 6y / (...), v' = 1.5 v
+    let white = Color::opaque(1.0, 1.0, 1.0);
+    let uv = Uv1960::from_color(&white);
+    assert!(
+        (uv.u - 0.19783).abs() < 1e-4 && (uv.v - 0.31221).abs() < 1e-4,
+        "{uv:?}"
+    );
+    let prime = UvPrime::from_color(&white);
+    assert!((prime.u - 0.19783).abs() < 1e-4, "{prime:?}");
+    assert!((prime.v - 0.46832).abs() < 1e-4, "{prime:?}");
+    assert!((prime.v - 1.5 * uv.v).abs() < 1e-12);
+}
+
+#[test]
+fn converts_between_diagrams() {
+    let prime = UvPrime::from(Xyy::new(0.64, 0.33, 0.2126));
+    let uv = Uv1960::from(prime);
+    assert!((uv.u - prime.u).abs() < 1e-12 && (uv.v - prime.v / 1.5).abs() < 1e-12);
+    let back = Xyy::from(UvPrime::from(uv));
+    assert!(
+        (back.x - 0.64).abs() < 1e-12 && (back.y - 0.33).abs() < 1e-12,
+        "{back:?}"
+    );
+    assert!(prime.distance(&UvPrime::from(uv)) < 1e-12);
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        if color.r + color.g + color.b == 0.0 {
+            continue;
+        }
+        assert_same_color(&Uv1960::from_color(&color).to_color(), &color, 1e-9);
+        assert_same_color(&UvPrime::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,62 @@
+use colorlab::{Color, ColorSpace, Srgb, Xyb};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+/// libjxl's kOpsinAbsorbanceBias
+const BIAS: f64 = 0.0037930732552754493;
+
+#[test]
+fn white_and_grays_have_no_opponent_signal() {
+    // Every opsin row sums to one, so grays give L = M = S
+    let white = Xyb::from_color(&Color::opaque(1.0, 1.0, 1.0));
+    let expected = (1.0 + BIAS).cbrt() - BIAS.cbrt();
+    assert!(white.x.abs() < 1e-12, "{white:?}");
+    assert!((white.y - expected).abs() < 1e-12, "{white:?}");
+    assert!((white.b - expected).abs() < 1e-12, "{white:?}");
+    for v in [0.01, 0.18, 0.5] {
+        let gray = Xyb::from_color(&Color::opaque(v, v, v));
+        assert!(
+            gray.x.abs() < 1e-12 && (gray.y - gray.b).abs() < 1e-12,
+            "{gray:?}"
+        );
+    }
+    let black = Xyb::from_color(&Color::opaque(0.0, 0.0, 0.0));
+    assert!(black.x.abs() < 1e-12 && black.y.abs() < 1e-12 && black.b.abs() < 1e-12);
+}
+
+#[test]
+fn red_leans_to_positive_x() {
+    let red = Xyb::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    let green = Xyb::from_color(&Color::opaque(0.0, 1.0, 0.0));
+    assert!(red.x > 0.0 && green.x < 0.0, "{red:?} {green:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Xyb::from_color(&color).to_color(), &color, 1e-9);
+    }
+    let wide = Color::new(-0.2, 1.4, 0.3, 1.0);
+    assert_same_color(&Xyb::from_color(&wide).to_color(), &wide, 1e-9);
+}
@@ -0,0 +1,58 @@
+use colorlab::{Color, ColorSpace, Srgb, Xyy};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn srgb_primaries_have_their_chromaticities() {
+    // IEC 61966-2-1
+    for (color, x, y) in [
+        (Color::opaque(1.0, 1.0, 1.0), 0.3127, 0.3290),
+        (Color::opaque(1.0, 0.0, 0.0), 0.64, 0.33),
+        (Color::opaque(0.0, 1.0, 0.0), 0.30, 0.60),
+        (Color::opaque(0.0, 0.0, 1.0), 0.15, 0.06),
+    ] {
+        let xyy = Xyy::from_color(&color);
+        assert!(
+            (xyy.x - x).abs() < 1e-4 && (xyy.y - y).abs() < 1e-4,
+            "{xyy:?}"
+        );
+    }
+    let red = Xyy::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    assert!((red.luminance - 0.2126).abs() < 1e-4, "{red:?}");
+}
+
+#[test]
+fn black_is_on_the_neutral_axis() {
+    let black = Xyy::from_color(&Color::opaque(0.0, 0.0, 0.0));
+    assert_eq!((black.x, black.y, black.luminance), (0.3127, 0.3290, 0.0));
+    let zero_y = Xyy::new(0.3, 0.0, 0.5).to_color();
+    assert_eq!((zero_y.r, zero_y.g, zero_y.b), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Xyy::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
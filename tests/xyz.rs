@@ -0,0 +1,48 @@
+use colorlab::white_point::WhitePoint;
+use colorlab::{Cam16, Ciecam02, Color, ColorSpace, ViewingConditions, Xyy, Xyz};
+
+fn colors() -> [Color; 4] {
+    [
+        Color::opaque(1.0, 0.0, 0.0),
+        Color::opaque(0.2, 0.6, 0.1),
+        Color::opaque(0.1, 0.2, 0.8),
+        Color::opaque(0.5, 0.5, 0.5),
+    ]
+}
+
+#[test]
+fn xyy_adapts_other_whites_to_d65() {
+    for color in colors() {
+        let d65 = Xyy::from(Xyz::from_color(&color));
+        let d50 = Xyy::from(Xyz::from_color_with(&color, WhitePoint::D50));
+        assert!((d50.x - d65.x).abs() < 1e-12, "{color:?}");
+        assert!((d50.y - d65.y).abs() < 1e-12, "{color:?}");
+        assert!((d50.luminance - d65.luminance).abs() < 1e-12, "{color:?}");
+        // The round trip comes back as the same color
+        let back = Xyz::from(d50).to_color();
+        for (a, b) in [(back.r, color.r), (back.g, color.g), (back.b, color.b)] {
+            assert!((a - b).abs() < 1e-12, "{color:?} gave {back:?}");
+        }
+    }
+}
+
+#[test]
+fn appearance_models_adapt_other_whites_to_d65() {
+    let vc = ViewingConditions::default();
+    for color in colors() {
+        let (d65, d50) = (
+            Xyz::from_color(&color),
+            Xyz::from_color_with(&color, WhitePoint::D50),
+        );
+        let (a, b) = (Cam16::from_xyz(&d65, &vc), Cam16::from_xyz(&d50, &vc));
+        assert!(
+            (a.j - b.j).abs() < 1e-9 && (a.c - b.c).abs() < 1e-9,
+            "{a:?} vs {b:?}"
+        );
+        let (a, b) = (Ciecam02::from_xyz(&d65, &vc), Ciecam02::from_xyz(&d50, &vc));
+        assert!(
+            (a.j - b.j).abs() < 1e-9 && (a.c - b.c).abs() < 1e-9,
+            "{a:?} vs {b:?}"
+        );
+    }
+}
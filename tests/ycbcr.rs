@@ -0,0 +1,168 @@
+use colorlab::colorspaces::srgb::Srgb;
+use colorlab::{
+    Color, ColorSpace, LinearRec2020, LuminanceMode, QuantizationRange, Rec709, YCbCr601, YCbCr709,
+    YCbCr2020,
+};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+fn srgb(r: f64, g: f64, b: f64) -> Color {
+    Srgb::new(r / 255.0, g / 255.0, b / 255.0).to_color()
+}
+
+#[test]
+fn jpeg_matches_jfif() {
+    // JFIF: Y = 0.299 R + 0.587 G + 0.114 B, Cb = 128 - 0.168736 R - 0.331264 G + 0.5 B,
+    // Cr = 128 + 0.5 R - 0.418688 G - 0.081312 B, on 8-bit sRGB values
+    for (rgb, expected) in [
+        ([255.0, 255.0, 255.0], [255.0, 128.0, 128.0]),
+        ([0.0, 0.0, 0.0], [0.0, 128.0, 128.0]),
+        ([255.0, 0.0, 0.0], [76.245, 84.972, 255.5]),
+        ([0.0, 255.0, 0.0], [149.685, 43.528, 21.234]),
+        ([0.0, 0.0, 255.0], [29.07, 255.5, 107.266]),
+        ([128.0, 64.0, 32.0], [79.488, 101.201, 162.602]),
+    ] {
+        let ycbcr = YCbCr601::from_jpeg(&srgb(rgb[0], rgb[1], rgb[2]));
+        for (got, want) in [ycbcr.y, ycbcr.cb, ycbcr.cr].into_iter().zip(expected) {
+            assert!((got - want).abs() < 1e-3, "{rgb:?} gave {ycbcr:?}");
+        }
+    }
+}
+
+#[test]
+fn jpeg_round_trips() {
+    for rgb in [
+        [255.0, 0.0, 0.0],
+        [12.0, 200.0, 99.0],
+        [128.0, 128.0, 128.0],
+    ] {
+        let color = srgb(rgb[0], rgb[1], rgb[2]);
+        let back = YCbCr601::from_jpeg(&color).to_jpeg();
+        for (x, y) in [(back.r, color.r), (back.g, color.g), (back.b, color.b)] {
+            assert!((x - y).abs() < 1e-12, "{rgb:?} gave {back:?}");
+        }
+    }
+}
+
+#[test]
+fn bt601_limited_range_reference_values() {
+    // BT.601: Y = 16 + 219 E'y, Cb/Cr = 128 + 224 E'pb/pr
+    let red = YCbCr601::from_rgb_signal([1.0, 0.0, 0.0], 1.0, QuantizationRange::Limited);
+    assert!((red.y - 81.481).abs() < 1e-9, "{red:?}");
+    assert!(
+        (red.cb - (128.0 - 224.0 * 0.299 / 1.772)).abs() < 1e-9,
+        "{red:?}"
+    );
+    assert!((red.cr - 240.0).abs() < 1e-9, "{red:?}");
+    let white =
+        YCbCr601::from_color_with(&Color::opaque(1.0, 1.0, 1.0), QuantizationRange::Limited);
+    assert!((white.y - 235.0).abs() < 1e-9, "{white:?}");
+    assert!((white.cb - 128.0).abs() < 1e-9 && (white.cr - 128.0).abs() < 1e-9);
+}
+
+#[test]
+fn bt601_round_trips_srgb() {
+    for color in srgb_sample() {
+        for range in [QuantizationRange::Full, QuantizationRange::Limited] {
+            let ycbcr = YCbCr601::from_color_with(&color, range);
+            assert_same_color(&ycbcr.to_color(), &color, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn bt709_reference_values() {
+    // sRGB red is Rec.709 R'G'B' (1, 0, 0)
+    let red = Color::opaque(1.0, 0.0, 0.0);
+    let limited = YCbCr709::from_color_with(&red, QuantizationRange::Limited);
+    assert!(
+        (limited.y - (16.0 + 219.0 * 0.2126)).abs() < 1e-9,
+        "{limited:?}"
+    );
+    assert!(
+        (limited.cb - (128.0 - 224.0 * 0.2126 / 1.8556)).abs() < 1e-9,
+        "{limited:?}"
+    );
+    assert!((limited.cr - 240.0).abs() < 1e-9, "{limited:?}");
+    let full = YCbCr709::from_color(&red);
+    assert!((full.y - 255.0 * 0.2126).abs() < 1e-9, "{full:?}");
+    assert!((full.cr - 255.5).abs() < 1e-9, "{full:?}");
+    let white =
+        YCbCr709::from_color_with(&Color::opaque(1.0, 1.0, 1.0), QuantizationRange::Limited);
+    assert!((white.y - 235.0).abs() < 1e-9 && (white.cb - 128.0).abs() < 1e-9);
+}
+
+#[test]
+fn bt709_round_trips_srgb() {
+    for color in srgb_sample() {
+        for range in [QuantizationRange::Full, QuantizationRange::Limited] {
+            let ycbcr = YCbCr709::from_color_with(&color, range);
+            assert_same_color(&ycbcr.to_color(), &color, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn bt2020_reference_values() {
+    let white = Color::opaque(1.0, 1.0, 1.0);
+    for mode in [LuminanceMode::NonConstant, LuminanceMode::Constant] {
+        let full = YCbCr2020::from_color_with(&white, QuantizationRange::Full, mode);
+        assert!(
+            (full.y - 255.0).abs() < 1e-9 && (full.cb - 128.0).abs() < 1e-9,
+            "{full:?}"
+        );
+        assert!((full.cr - 128.0).abs() < 1e-9, "{full:?}");
+        let limited = YCbCr2020::from_color_with(&white, QuantizationRange::Limited, mode);
+        assert!((limited.y - 235.0).abs() < 1e-9, "{limited:?}");
+    }
+    // Rec.2020 red: non-constant luma weights the encoded signal, constant luminance
+    // encodes the linear luminance KR = 0.2627 with the BT.709 curve
+    let red = LinearRec2020::new(1.0, 0.0, 0.0).to_color();
+    let ncl = YCbCr2020::from_color_with(&red, QuantizationRange::Full, LuminanceMode::NonConstant);
+    assert!((ncl.y - 255.0 * 0.2627).abs() < 1e-9, "{ncl:?}");
+    assert!((ncl.cr - 255.5).abs() < 1e-9, "{ncl:?}");
+    let cl = YCbCr2020::from_color_with(&red, QuantizationRange::Full, LuminanceMode::Constant);
+    let y = Rec709::from_color(&Color::opaque(0.2627, 0.2627, 0.2627)).g;
+    assert!((cl.y - 255.0 * y).abs() < 1e-9, "{cl:?}");
+    assert!(
+        (cl.cr - (128.0 + 255.0 * (1.0 - y) / 0.9936)).abs() < 1e-9,
+        "{cl:?}"
+    );
+    assert!(
+        (cl.cb - (128.0 - 255.0 * y / 1.9404)).abs() < 1e-9,
+        "{cl:?}"
+    );
+}
+
+#[test]
+fn bt2020_round_trips_srgb() {
+    for color in srgb_sample() {
+        for range in [QuantizationRange::Full, QuantizationRange::Limited] {
+            for mode in [LuminanceMode::NonConstant, LuminanceMode::Constant] {
+                let ycbcr = YCbCr2020::from_color_with(&color, range, mode);
+                assert_same_color(&ycbcr.to_color(), &color, 1e-9);
+            }
+        }
+    }
+}
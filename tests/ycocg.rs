@@ -0,0 +1,62 @@
+use colorlab::{Color, ColorSpace, Srgb, YCoCg, YCoCgR};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn reference_values() {
+    let red = YCoCg::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    assert!((red.y - 0.25).abs() < 1e-12, "{red:?}");
+    assert!((red.co - 0.5).abs() < 1e-12, "{red:?}");
+    assert!((red.cg + 0.25).abs() < 1e-12, "{red:?}");
+    let green = YCoCg::from_color(&Color::opaque(0.0, 1.0, 0.0));
+    assert!((green.y - 0.5).abs() < 1e-12 && green.co.abs() < 1e-12);
+    assert!((green.cg - 0.5).abs() < 1e-12, "{green:?}");
+}
+
+#[test]
+fn lifting_reference_values() {
+    assert_eq!(YCoCgR::from_rgb([255, 0, 0]), YCoCgR::new(63, 255, -127));
+    assert_eq!(YCoCgR::from_rgb([128, 128, 128]), YCoCgR::new(128, 0, 0));
+}
+
+#[test]
+fn lifting_is_lossless() {
+    for r in (0..256).step_by(5) {
+        for g in (0..256).step_by(3) {
+            for b in (0..256).step_by(7) {
+                assert_eq!(YCoCgR::from_rgb([r, g, b]).to_rgb(), [r, g, b]);
+            }
+        }
+    }
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&YCoCg::from_color(&color).to_color(), &color, 1e-12);
+        // The sample is on a 0.2 grid, which 8-bit code values hit exactly
+        let back = YCoCgR::from_color(&color).to_color(color.a);
+        assert_same_color(&back, &color, 1e-12);
+    }
+}
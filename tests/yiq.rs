@@ -0,0 +1,52 @@
+use colorlab::{Color, ColorSpace, Rec601, Srgb, Yiq};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn fcc_reference_values() {
+    let red = Yiq::from_rgb_signal([1.0, 0.0, 0.0], 1.0);
+    assert_eq!((red.y, red.i, red.q), (0.299, 0.5959, 0.2115));
+    let white = Yiq::from_rgb_signal([1.0, 1.0, 1.0], 1.0);
+    assert!((white.y - 1.0).abs() < 1e-12 && white.i.abs() < 1e-12 && white.q.abs() < 1e-12);
+    // The color conversions go through Rec.601 R'G'B'
+    let yiq = Yiq::from_color(&Rec601::new(1.0, 0.0, 0.0).to_color());
+    assert!((yiq.y - 0.299).abs() < 1e-9 && (yiq.i - 0.5959).abs() < 1e-9);
+}
+
+#[test]
+fn signal_round_trips() {
+    for rgb in [[1.0, 0.0, 0.0], [0.2, 0.6, 0.9], [0.5, 0.5, 0.5]] {
+        let back = Yiq::from_rgb_signal(rgb, 1.0).to_rgb_signal();
+        for (x, y) in back.into_iter().zip(rgb) {
+            assert!((x - y).abs() < 1e-12, "{rgb:?} gave {back:?}");
+        }
+    }
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        assert_same_color(&Yiq::from_color(&color).to_color(), &color, 1e-9);
+    }
+}
@@ -0,0 +1,53 @@
+use colorlab::{Color, ColorSpace, Rec601, Srgb, YPbPr, YPbPrStandard};
+
+/// sRGB colors on a 6-step grid per channel.
+fn srgb_sample() -> Vec<Color> {
+    let steps = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+    let mut colors = Vec::new();
+    for r in steps {
+        for g in steps {
+            for b in steps {
+                colors.push(Color {
+                    a: 0.5,
+                    ..Srgb::new(r, g, b).to_color()
+                });
+            }
+        }
+    }
+    colors
+}
+
+fn assert_same_color(a: &Color, b: &Color, tolerance: f64) {
+    for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+        assert!((x - y).abs() < tolerance, "{a:?} vs {b:?}");
+    }
+}
+
+#[test]
+fn bt709_reference_values() {
+    // sRGB red is Rec.709 R'G'B' (1, 0, 0): Pb = -KR / (2 (1 - KB))
+    let red = YPbPr::from_color(&Color::opaque(1.0, 0.0, 0.0));
+    assert!((red.y - 0.2126).abs() < 1e-9, "{red:?}");
+    assert!((red.pb + 0.2126 / 1.8556).abs() < 1e-9, "{red:?}");
+    assert!((red.pr - 0.5).abs() < 1e-9, "{red:?}");
+    assert_eq!(red.standard, YPbPrStandard::Bt709);
+}
+
+#[test]
+fn bt601_reference_values() {
+    let color = Rec601::new(1.0, 0.0, 0.0).to_color();
+    let red = YPbPr::from_color_with(&color, YPbPrStandard::Bt601);
+    assert!((red.y - 0.299).abs() < 1e-9, "{red:?}");
+    assert!((red.pb + 0.168736).abs() < 1e-6, "{red:?}");
+    assert!((red.pr - 0.5).abs() < 1e-9, "{red:?}");
+}
+
+#[test]
+fn round_trips_srgb() {
+    for color in srgb_sample() {
+        for standard in [YPbPrStandard::Bt601, YPbPrStandard::Bt709] {
+            let ypbpr = YPbPr::from_color_with(&color, standard);
+            assert_same_color(&ypbpr.to_color(), &color, 1e-9);
+        }
+    }
+}